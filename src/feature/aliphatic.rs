@@ -4,7 +4,7 @@ use std::fmt;
 use super::Element;
 
 /// Atomic symbols that can be aliphatic.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum Aliphatic {
     B, C, N, O, S, P, F, Cl, Br, I, At, Ts
 }
@@ -50,6 +50,25 @@ impl TryFrom<&Element> for Aliphatic {
     }
 }
 
+impl From<&Aliphatic> for Element {
+    fn from(aliphatic: &Aliphatic) -> Self {
+        match aliphatic {
+            Aliphatic::B => Element::B,
+            Aliphatic::C => Element::C,
+            Aliphatic::N => Element::N,
+            Aliphatic::O => Element::O,
+            Aliphatic::S => Element::S,
+            Aliphatic::P => Element::P,
+            Aliphatic::F => Element::F,
+            Aliphatic::Cl => Element::Cl,
+            Aliphatic::Br => Element::Br,
+            Aliphatic::I => Element::I,
+            Aliphatic::At => Element::At,
+            Aliphatic::Ts => Element::Ts
+        }
+    }
+}
+
 impl fmt::Display for Aliphatic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -4,7 +4,7 @@ use std::convert::TryFrom;
 use super::{ Aliphatic, BracketAromatic };
 
 /// Atomic symbols that can be aromatic.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum Aromatic {
     B, C, N, O, P, S
 }
@@ -39,9 +39,9 @@ impl TryFrom<&BracketAromatic> for Aromatic {
     }
 }
 
-impl Into<Aliphatic> for &Aromatic {
-    fn into(self) -> Aliphatic {
-        match self {
+impl From<&Aromatic> for Aliphatic {
+    fn from(aromatic: &Aromatic) -> Self {
+        match aromatic {
             Aromatic::B => Aliphatic::B,
             Aromatic::C => Aliphatic::C,
             Aromatic::N => Aliphatic::N,
@@ -52,6 +52,19 @@ impl Into<Aliphatic> for &Aromatic {
     }
 }
 
+impl From<&Aromatic> for BracketAromatic {
+    fn from(aromatic: &Aromatic) -> Self {
+        match aromatic {
+            Aromatic::B => BracketAromatic::B,
+            Aromatic::C => BracketAromatic::C,
+            Aromatic::N => BracketAromatic::N,
+            Aromatic::O => BracketAromatic::O,
+            Aromatic::P => BracketAromatic::P,
+            Aromatic::S => BracketAromatic::S
+        }
+    }
+}
+
 impl fmt::Display for Aromatic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
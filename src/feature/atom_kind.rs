@@ -7,7 +7,7 @@ use super::{
 };
 
 /// Minimal context-sensitive representation of an atom kind.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum AtomKind {
     Star,
     Aliphatic(Aliphatic),
@@ -32,50 +32,65 @@ impl AtomKind {
     /// to be constructed and debracketed, if possible. The logic to decide
     /// debracketability is encapsulated here.
     pub fn debracket(self, bond_order_sum: u8) -> AtomKind {
-        let (isotope, symbol, configuration, hcount ,charge, map) = match &self {
-            AtomKind::Star => return self,
-            AtomKind::Aliphatic(_) => return self,
-            AtomKind::Aromatic(_) => return self,
+        match self.unbracketed(bond_order_sum) {
+            Some(kind) => kind,
+            None => self
+        }
+    }
+
+    /// Returns true if this is a bracket kind that `debracket` would
+    /// unbracket given `bond_order_sum`, meaning it can be written without
+    /// brackets.
+    pub fn is_debracketable(&self, bond_order_sum: u8) -> bool {
+        self.unbracketed(bond_order_sum).is_some()
+    }
+
+    fn unbracketed(&self, bond_order_sum: u8) -> Option<AtomKind> {
+        let (isotope, symbol, configuration, hcount ,charge, map) = match self {
+            AtomKind::Star => return None,
+            AtomKind::Aliphatic(_) => return None,
+            AtomKind::Aromatic(_) => return None,
             AtomKind::Bracket {
                 isotope, symbol, configuration, hcount, charge, map
             } => (isotope, symbol, configuration, hcount, charge, map)
         };
 
         if any(isotope, configuration, charge, map) {
-            return self
+            return None
         }
 
         match symbol {
             BracketSymbol::Star => match hcount {
                 Some(hcount) => {
                     if hcount.is_zero() {
-                        AtomKind::Star
+                        Some(AtomKind::Star)
                     } else {
-                        self
+                        None
                     }
                 },
-                None => AtomKind::Star
+                None => Some(AtomKind::Star)
             },
             BracketSymbol::Aromatic(aromatic) => {
-                let hcount = match hcount {
+                let hcount: u8 = match hcount {
                     Some(hcount) => hcount.into(),
                     None => 0
                 };
-                let valence = bond_order_sum.checked_add(hcount)
-                    .expect("valence");
-                let allowance = if hcount == 0 { 0 } else { 1 };
                 let aromatic = match Aromatic::try_from(aromatic) {
                     Ok(aromatic) => aromatic,
-                    Err(_) => return self
+                    Err(_) => return None
                 };
+                let subvalence = match aromatic.targets().iter()
+                    .find(|&&target| target >= bond_order_sum) {
+                    Some(target) => target - bond_order_sum,
+                    None => 0
+                };
+                let implicit = subvalence.saturating_sub(1);
 
-                for target in aromatic.targets() {
-                    if valence == target - allowance {
-                        return AtomKind::Aromatic(aromatic)
-                    }
+                if implicit == hcount {
+                    Some(AtomKind::Aromatic(aromatic))
+                } else {
+                    None
                 }
-
-                self
             },
             BracketSymbol::Element(element) => {
                 let valence = bond_order_sum.checked_add(match hcount {
@@ -84,16 +99,14 @@ impl AtomKind {
                 }).expect("valence");
                 let aliphatic = match Aliphatic::try_from(element) {
                     Ok(aliphatic) => aliphatic,
-                    Err(_) => return self
+                    Err(_) => return None
                 };
 
-                for target in aliphatic.targets() {
-                    if target == &valence {
-                        return AtomKind::Aliphatic(aliphatic)
-                    }
+                if aliphatic.targets().contains(&valence) {
+                    Some(AtomKind::Aliphatic(aliphatic))
+                } else {
+                    None
                 }
-
-                self
             }
         }
     }
@@ -112,6 +125,38 @@ impl AtomKind {
         }
     }
 
+    /// Returns the capitalized elemental symbol for this atom kind, or `*`
+    /// for stars. Aromatic symbols are capitalized.
+    pub(crate) fn symbol(&self) -> String {
+        match self {
+            Self::Star => "*".to_string(),
+            Self::Aliphatic(aliphatic) => aliphatic.to_string(),
+            Self::Aromatic(aromatic) => Aliphatic::from(aromatic).to_string(),
+            Self::Bracket { symbol, .. } => match symbol {
+                BracketSymbol::Star => "*".to_string(),
+                BracketSymbol::Element(element) => element.to_string(),
+                BracketSymbol::Aromatic(aromatic) =>
+                    Element::from(aromatic).to_string()
+            }
+        }
+    }
+
+    /// Returns the element for this atom kind, or None for stars.
+    pub(crate) fn element(&self) -> Option<Element> {
+        match self {
+            Self::Star => None,
+            Self::Aliphatic(aliphatic) => Some(aliphatic.into()),
+            Self::Aromatic(aromatic) =>
+                Some((&Aliphatic::from(aromatic)).into()),
+            Self::Bracket { symbol, .. } => match symbol {
+                BracketSymbol::Star => None,
+                BracketSymbol::Element(element) =>
+                    Some(element.clone()),
+                BracketSymbol::Aromatic(aromatic) => Some(aromatic.into())
+            }
+        }
+    }
+
     /// Returns the valence targets for this atom kind.
     pub fn targets(&self) -> &[u8] {
         match self {
@@ -129,30 +174,41 @@ impl AtomKind {
     }
 
     /// Inverts configuration given if it and at least one implicit
-    /// hydrogen are present.
+    /// hydrogen are present, as when the hydrogen trades places with the
+    /// neighbor ahead of it. Configurations other than TH and AL follow
+    /// `permute_neighbors`.
+    pub fn invert_configuration(&mut self) {
+        let count = match self {
+            AtomKind::Bracket {
+                hcount: Some(hcount), configuration: Some(config), ..
+            } if !hcount.is_zero() => config.neighbor_count(),
+            _ => return
+        };
+        let mut permutation = (0..count).collect::<Vec<_>>();
+
+        permutation.swap(0, 1);
+        self.permute_neighbors(&permutation)
+    }
+
+    /// Updates configuration to describe the same spatial arrangement after
+    /// reordering neighbors. `permutation[i]` gives the original position of
+    /// the neighbor now at position `i`. Positions follow SMILES neighbor
+    /// order, with any virtual hydrogen at its written position. Kinds
+    /// without a configuration are unchanged.
+    /// 
+    /// Every configuration is updated, except that square planar,
+    /// trigonal bipyramidal, and octahedral configurations given the wrong
+    /// number of neighbors are cleared.
     /// 
     /// # Panics
     /// 
-    /// Panics given a Configuration other than TH1 or TH2.
-    pub fn invert_configuration(&mut self) {
-        if let AtomKind::Bracket { hcount, configuration, .. } = self {
-            let new_config = match configuration {
-                Some(config) => match hcount {
-                    Some(hcount) => if hcount.is_zero() {
-                        return
-                    } else {
-                        match config {
-                            Configuration::TH1 => Configuration::TH2,
-                            Configuration::TH2 => Configuration::TH1,
-                            _ => unimplemented!("TODO: handle inversion for non-TH")
-                        }
-                    },
-                    None => return
-                },
-                None => return
-            };
-    
-            configuration.replace(new_config);
+    /// Panics given a `permutation` that is not a permutation of
+    /// `0..permutation.len()`.
+    pub fn permute_neighbors(&mut self, permutation: &[usize]) {
+        if let AtomKind::Bracket { configuration, .. } = self {
+            if let Some(config) = configuration.take() {
+                *configuration = config.permute(permutation)
+            }
         }
     }
 }
@@ -256,6 +312,438 @@ impl fmt::Display for AtomKind {
     }
 }
 
+#[cfg(test)]
+mod debracket {
+    use pretty_assertions::assert_eq;
+    use crate::feature::BracketAromatic;
+    use super::*;
+
+    fn aromatic(
+        aromatic: BracketAromatic, hcount: Option<VirtualHydrogen>
+    ) -> AtomKind {
+        AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Aromatic(aromatic),
+            configuration: None,
+            hcount,
+            charge: None,
+            map: None
+        }
+    }
+
+    #[test]
+    fn aromatic_carbon_h1_two_bonds() {
+        let kind = aromatic(BracketAromatic::C, Some(VirtualHydrogen::H1));
+
+        assert_eq!(kind.debracket(2), AtomKind::Aromatic(Aromatic::C))
+    }
+
+    #[test]
+    fn aromatic_carbon_h0_three_bonds() {
+        let kind = aromatic(BracketAromatic::C, None);
+
+        assert_eq!(kind.debracket(3), AtomKind::Aromatic(Aromatic::C))
+    }
+
+    #[test]
+    fn aromatic_carbon_h0_two_bonds() {
+        let kind = aromatic(BracketAromatic::C, None);
+
+        assert_eq!(
+            kind.debracket(2),
+            aromatic(BracketAromatic::C, None)
+        )
+    }
+
+    #[test]
+    fn aromatic_boron_h0_two_bonds() {
+        let kind = aromatic(BracketAromatic::B, Some(VirtualHydrogen::H0));
+
+        assert_eq!(kind.debracket(2), AtomKind::Aromatic(Aromatic::B))
+    }
+
+    #[test]
+    fn aromatic_boron_h0_three_bonds() {
+        let kind = aromatic(BracketAromatic::B, None);
+
+        assert_eq!(kind.debracket(3), AtomKind::Aromatic(Aromatic::B))
+    }
+
+    #[test]
+    fn aromatic_boron_h1_two_bonds() {
+        let kind = aromatic(BracketAromatic::B, Some(VirtualHydrogen::H1));
+
+        assert_eq!(
+            kind.debracket(2),
+            aromatic(BracketAromatic::B, Some(VirtualHydrogen::H1))
+        )
+    }
+
+    #[test]
+    fn aromatic_nitrogen_h0_two_bonds() {
+        let kind = aromatic(BracketAromatic::N, None);
+
+        assert_eq!(kind.debracket(2), AtomKind::Aromatic(Aromatic::N))
+    }
+
+    #[test]
+    fn aromatic_nitrogen_h1_two_bonds() {
+        let kind = aromatic(BracketAromatic::N, Some(VirtualHydrogen::H1));
+
+        assert_eq!(
+            kind.debracket(2),
+            aromatic(BracketAromatic::N, Some(VirtualHydrogen::H1))
+        )
+    }
+
+    #[test]
+    fn is_debracketable_methyl() {
+        let kind = AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::C),
+            configuration: None,
+            hcount: Some(VirtualHydrogen::H3),
+            charge: None,
+            map: None
+        };
+
+        assert!(kind.is_debracketable(1));
+        assert!(!kind.is_debracketable(2))
+    }
+
+    #[test]
+    fn is_debracketable_iron() {
+        let kind = AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::Fe),
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: None
+        };
+
+        assert!(!kind.is_debracketable(0))
+    }
+
+    #[test]
+    fn is_debracketable_unbracketed() {
+        assert!(!AtomKind::Aliphatic(Aliphatic::C).is_debracketable(0))
+    }
+}
+
+#[cfg(test)]
+mod permute_neighbors {
+    use pretty_assertions::assert_eq;
+    use crate::read::{ read, read_bracket, Scanner, ReadOptions };
+    use crate::graph::Builder;
+    use crate::walk::walk;
+    use crate::write::Writer;
+    use super::*;
+
+    fn kind(bracket: &str) -> AtomKind {
+        let mut scanner = Scanner::new(bracket);
+
+        read_bracket(&mut scanner, &ReadOptions::default()).unwrap().unwrap()
+    }
+
+    fn configuration(kind: AtomKind) -> Option<Configuration> {
+        match kind {
+            AtomKind::Bracket { configuration, .. } => configuration,
+            _ => None
+        }
+    }
+
+    #[test]
+    fn aliphatic() {
+        let mut kind = AtomKind::Aliphatic(Aliphatic::C);
+
+        kind.permute_neighbors(&[ 1, 0 ]);
+
+        assert_eq!(kind, AtomKind::Aliphatic(Aliphatic::C))
+    }
+
+    #[test]
+    fn bracket_without_configuration() {
+        let mut kind = kind("[C]");
+
+        kind.permute_neighbors(&[ 1, 0, 2, 3 ]);
+
+        assert_eq!(configuration(kind), None)
+    }
+
+    #[test]
+    fn identity() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 0, 1, 2, 3 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TH1))
+    }
+
+    #[test]
+    fn swap_first_two() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 1, 0, 2, 3 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TH2))
+    }
+
+    #[test]
+    fn swap_last_two() {
+        let mut kind = kind("[C@@]");
+
+        kind.permute_neighbors(&[ 0, 1, 3, 2 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TH1))
+    }
+
+    #[test]
+    fn rotate_three() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 0, 2, 3, 1 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TH1))
+    }
+
+    #[test]
+    fn rotate_four() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 1, 2, 3, 0 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TH2))
+    }
+
+    #[test]
+    fn swap_bonds_in_graph() {
+        let mut builder = Builder::new();
+        let mut writer = Writer::new();
+
+        read("[C@](F)(Cl)(Br)I", &mut builder, None).unwrap();
+
+        let mut atoms = builder.build().unwrap();
+
+        atoms[0].bonds.swap(0, 1);
+        atoms[0].kind.permute_neighbors(&[ 1, 0, 2, 3 ]);
+        walk(atoms, &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "[C@@](Cl)(F)(Br)I")
+    }
+
+    #[test]
+    fn allene() {
+        let mut kind = kind("[C@AL1]");
+
+        kind.permute_neighbors(&[ 1, 0, 2, 3 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::AL2))
+    }
+
+    #[test]
+    fn square_planar_swap_trans_partner() {
+        let mut kind = kind("[Pt@SP1]");
+
+        kind.permute_neighbors(&[ 0, 2, 1, 3 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::SP2))
+    }
+
+    #[test]
+    fn square_planar_rotate() {
+        let mut kind = kind("[Pt@SP1]");
+
+        kind.permute_neighbors(&[ 1, 2, 3, 0 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::SP1))
+    }
+
+    #[test]
+    fn square_planar_z() {
+        let mut kind = kind("[Pt@SP3]");
+
+        kind.permute_neighbors(&[ 0, 1, 3, 2 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::SP1))
+    }
+
+    #[test]
+    fn square_planar_three_neighbors() {
+        let mut kind = kind("[Pt@SP1]");
+
+        kind.permute_neighbors(&[ 1, 0, 2 ]);
+
+        assert_eq!(configuration(kind), None)
+    }
+
+    #[test]
+    fn trigonal_bipyramidal_swap_equatorial() {
+        let mut kind = kind("[As@TB1]");
+
+        kind.permute_neighbors(&[ 0, 2, 1, 3, 4 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TB2))
+    }
+
+    #[test]
+    fn trigonal_bipyramidal_swap_axial() {
+        let mut kind = kind("[As@TB1]");
+
+        kind.permute_neighbors(&[ 4, 1, 2, 3, 0 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TB2))
+    }
+
+    #[test]
+    fn trigonal_bipyramidal_move_axis() {
+        let mut kind = kind("[As@TB1]");
+
+        kind.permute_neighbors(&[ 1, 0, 2, 3, 4 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TB9))
+    }
+
+    #[test]
+    fn trigonal_bipyramidal_round_trip() {
+        let mut kind = kind("[As@TB15]");
+
+        kind.permute_neighbors(&[ 3, 0, 4, 1, 2 ]);
+        kind.permute_neighbors(&[ 1, 3, 4, 0, 2 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::TB15))
+    }
+
+    #[test]
+    fn trigonal_bipyramidal_every_class() {
+        let classes = [
+            "@TB1", "@TB2", "@TB3", "@TB4", "@TB5", "@TB6", "@TB7", "@TB8",
+            "@TB9", "@TB10", "@TB11", "@TB12", "@TB13", "@TB14", "@TB15",
+            "@TB16", "@TB17", "@TB18", "@TB19", "@TB20"
+        ];
+
+        for class in classes.iter() {
+            let mut kind = kind(&format!("[As{}]", class));
+
+            kind.permute_neighbors(&[ 1, 2, 3, 4, 0 ]);
+            kind.permute_neighbors(&[ 4, 0, 1, 2, 3 ]);
+
+            assert_eq!(
+                configuration(kind).unwrap().to_string(), *class, "{}", class
+            )
+        }
+    }
+
+    #[test]
+    fn octahedral_identity() {
+        let mut kind = kind("[Fe@OH1]");
+
+        kind.permute_neighbors(&[ 0, 1, 2, 3, 4, 5 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::OH1))
+    }
+
+    #[test]
+    fn octahedral_swap_equatorial() {
+        let mut kind = kind("[Fe@OH1]");
+
+        kind.permute_neighbors(&[ 0, 1, 4, 3, 2, 5 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::OH2))
+    }
+
+    #[test]
+    fn octahedral_swap_axial() {
+        let mut kind = kind("[Fe@OH1]");
+
+        kind.permute_neighbors(&[ 5, 1, 2, 3, 4, 0 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::OH2))
+    }
+
+    #[test]
+    fn octahedral_move_axis() {
+        let mut kind = kind("[Fe@OH1]");
+
+        kind.permute_neighbors(&[ 0, 1, 2, 3, 5, 4 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::OH3))
+    }
+
+    #[test]
+    fn octahedral_z_shape() {
+        let mut kind = kind("[Fe@OH1]");
+
+        kind.permute_neighbors(&[ 0, 1, 2, 4, 3, 5 ]);
+
+        assert_eq!(configuration(kind), Some(Configuration::OH27))
+    }
+
+    #[test]
+    fn octahedral_every_class() {
+        for number in 1..=30 {
+            let class = format!("@OH{}", number);
+            let mut kind = kind(&format!("[Fe{}]", class));
+
+            kind.permute_neighbors(&[ 1, 2, 3, 4, 5, 0 ]);
+            kind.permute_neighbors(&[ 5, 0, 1, 2, 3, 4 ]);
+
+            assert_eq!(
+                configuration(kind).unwrap().to_string(), class, "{}", class
+            )
+        }
+    }
+
+    #[test]
+    fn octahedral_rotations() {
+        let mut counts = std::collections::HashMap::new();
+        let mut permutation = [ 0, 1, 2, 3, 4, 5 ];
+
+        // Heap's algorithm over all 720 orders of six neighbors.
+        let mut stack = [ 0; 6 ];
+        let mut i = 1;
+        let mut visit = |permutation: &[usize]| {
+            let mut kind = kind("[Fe@OH1]");
+
+            kind.permute_neighbors(permutation);
+            *counts.entry(configuration(kind).unwrap()).or_insert(0) += 1;
+        };
+
+        visit(&permutation);
+
+        while i < 6 {
+            if stack[i] < i {
+                permutation.swap(if i % 2 == 0 { 0 } else { stack[i] }, i);
+                visit(&permutation);
+                stack[i] += 1;
+                i = 1
+            } else {
+                stack[i] = 0;
+                i += 1
+            }
+        }
+
+        assert_eq!(counts.len(), 30);
+        assert!(counts.values().all(|&count| count == 24))
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation")]
+    fn repeated_position() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 0, 0, 2, 3 ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation")]
+    fn out_of_range_position() {
+        let mut kind = kind("[C@]");
+
+        kind.permute_neighbors(&[ 0, 1, 2, 4 ]);
+    }
+}
+
 #[cfg(test)]
 mod invert {
     use pretty_assertions::assert_eq;
@@ -332,4 +820,61 @@ mod invert {
             _ => panic!("expected bracket")
         }
     }
+
+    #[test]
+    fn tb1_h1() {
+        let mut kind = AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::As),
+            configuration: Some(Configuration::TB1),
+            hcount: Some(VirtualHydrogen::H1),
+            charge: None,
+            map: None
+        };
+
+        kind.invert_configuration();
+
+        match kind {
+            AtomKind::Bracket { configuration, .. } =>
+                assert_eq!(configuration, Some(Configuration::TB9)),
+            _ => panic!("expected bracket")
+        }
+    }
+}
+
+#[cfg(test)]
+mod defaults {
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    fn bracket() -> AtomKind {
+        AtomKind::Bracket {
+            isotope: Some(Number::default()),
+            symbol: BracketSymbol::Element(Element::C),
+            configuration: None,
+            hcount: Some(VirtualHydrogen::default()),
+            charge: Option::default(),
+            map: Some(Number::default())
+        }
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(u16::from(&Number::default()), 0)
+    }
+
+    #[test]
+    fn virtual_hydrogen() {
+        assert_eq!(VirtualHydrogen::default(), VirtualHydrogen::H0)
+    }
+
+    #[test]
+    fn charge() {
+        assert_eq!(Option::<Charge>::default(), None)
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(bracket().to_string(), "[0C:0]")
+    }
 }
\ No newline at end of file
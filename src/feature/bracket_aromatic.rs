@@ -1,16 +1,17 @@
+use std::convert::TryFrom;
 use std::fmt;
 
 use super::Element;
 
 /// Eligible symbols for aromatic bracket atoms.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum BracketAromatic {
     B, C, N, O, S, P, Se, As
 }
 
-impl Into<Element> for &BracketAromatic {
-    fn into(self) -> Element {
-        match self {
+impl From<&BracketAromatic> for Element {
+    fn from(aromatic: &BracketAromatic) -> Self {
+        match aromatic {
             BracketAromatic::As => Element::As,
             BracketAromatic::B => Element::B,
             BracketAromatic::C => Element::C,
@@ -23,6 +24,24 @@ impl Into<Element> for &BracketAromatic {
     }
 }
 
+impl TryFrom<&Element> for BracketAromatic {
+    type Error = ();
+
+    fn try_from(element: &Element) -> Result<Self, Self::Error> {
+        match element {
+            Element::As => Ok(BracketAromatic::As),
+            Element::B => Ok(BracketAromatic::B),
+            Element::C => Ok(BracketAromatic::C),
+            Element::N => Ok(BracketAromatic::N),
+            Element::O => Ok(BracketAromatic::O),
+            Element::P => Ok(BracketAromatic::P),
+            Element::S => Ok(BracketAromatic::S),
+            Element::Se => Ok(BracketAromatic::Se),
+            _ => Err(())
+        }
+    }
+}
+
 impl fmt::Display for BracketAromatic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -4,7 +4,7 @@ use super::{ BracketAromatic, Element };
 
 /// Represents those atomic symbols capable of appearing within a bracket
 /// atom in the string representation.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum BracketSymbol {
     Star,
     Element(Element),
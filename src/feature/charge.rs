@@ -1,8 +1,10 @@
 use std::fmt;
-use std::convert::{ Into, TryFrom };
+use std::convert::TryFrom;
 
-/// An integer charge from -15 to +15.
-#[derive(Debug,PartialEq)]
+/// An integer charge from -15 to +15. There is no zero variant, and no
+/// `Default`; a neutral atom has a charge of `None`, which is the default
+/// of `Option<Charge>`.
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum Charge {
     MinusFifteen,
     MinusFourteen,
@@ -76,9 +78,9 @@ impl TryFrom<i8> for Charge {
     }
 }
 
-impl Into<i8> for &Charge {
-    fn into(self) -> i8 {
-        match self {
+impl From<&Charge> for i8 {
+    fn from(charge: &Charge) -> Self {
+        match charge {
             Charge::MinusFifteen => -15,
             Charge::MinusFourteen => -14,
             Charge::MinusThirteen => -13,
@@ -2,7 +2,7 @@ use std::fmt;
 
 /// Representation of a configurational template. Most applications
 /// will use only `TH1` (counterclockwise) and `TH2` (clockwise).
-#[derive(Debug,PartialEq,Clone)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum Configuration {
     AL1,
     AL2,
@@ -63,6 +63,300 @@ pub enum Configuration {
     TH2
 }
 
+impl Configuration {
+    /// Returns the configuration describing the same spatial arrangement
+    /// after reordering neighbors, where `permutation[i]` gives the
+    /// original position of the neighbor now at position `i`. Returns
+    /// None for a square planar, trigonal bipyramidal, or octahedral
+    /// configuration whose neighbor count does not match `permutation`.
+    ///
+    /// # Panics
+    ///
+    /// Panics given a `permutation` that is not a permutation of
+    /// `0..permutation.len()`.
+    pub(crate) fn permute(&self, permutation: &[usize]) -> Option<Self> {
+        let odd = is_odd(permutation);
+
+        if permutation.iter().enumerate().all(|(i, &p)| i == p) {
+            return Some(self.clone())
+        }
+
+        match self {
+            Configuration::TH1 | Configuration::TH2 |
+            Configuration::AL1 | Configuration::AL2 => Some(if odd {
+                self.inverse()
+            } else {
+                self.clone()
+            }),
+            Configuration::SP1 | Configuration::SP2 |
+            Configuration::SP3 => permute_square(self, permutation),
+            Configuration::OH1 | Configuration::OH2 | Configuration::OH3 |
+            Configuration::OH4 | Configuration::OH5 | Configuration::OH6 |
+            Configuration::OH7 | Configuration::OH8 | Configuration::OH9 |
+            Configuration::OH10 | Configuration::OH11 |
+            Configuration::OH12 | Configuration::OH13 |
+            Configuration::OH14 | Configuration::OH15 |
+            Configuration::OH16 | Configuration::OH17 |
+            Configuration::OH18 | Configuration::OH19 |
+            Configuration::OH20 | Configuration::OH21 |
+            Configuration::OH22 | Configuration::OH23 |
+            Configuration::OH24 | Configuration::OH25 |
+            Configuration::OH26 | Configuration::OH27 |
+            Configuration::OH28 | Configuration::OH29 |
+            Configuration::OH30 => permute_octahedron(self, permutation),
+            _ => permute_bipyramid(self, permutation)
+        }
+    }
+
+    /// Returns the number of neighbors this configuration describes.
+    pub(crate) fn neighbor_count(&self) -> usize {
+        match self {
+            Configuration::TH1 | Configuration::TH2 |
+            Configuration::AL1 | Configuration::AL2 |
+            Configuration::SP1 | Configuration::SP2 |
+            Configuration::SP3 => 4,
+            _ => if BIPYRAMID.iter().any(|(bipyramid, ..)| bipyramid == self) {
+                5
+            } else {
+                6
+            }
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        match self {
+            Configuration::TH1 => Configuration::TH2,
+            Configuration::TH2 => Configuration::TH1,
+            Configuration::AL1 => Configuration::AL2,
+            _ => Configuration::AL1
+        }
+    }
+}
+
+// Trans partner of the first neighbor for SP1, SP2, and SP3, which trace
+// the neighbors in U, 4, and Z shapes, respectively.
+const SQUARE: [(Configuration, usize); 3] = [
+    (Configuration::SP1, 2),
+    (Configuration::SP2, 1),
+    (Configuration::SP3, 3)
+];
+
+fn permute_square(
+    config: &Configuration, permutation: &[usize]
+) -> Option<Configuration> {
+    if permutation.len() != 4 {
+        return None
+    }
+
+    let (_, partner) = SQUARE.iter().find(|(square, _)| square == config)
+        .expect("square planar");
+    let mut partners = [ 0; 4 ];
+    let mut rest = (1..4).filter(|i| i != partner);
+    let left = rest.next().expect("left");
+    let right = rest.next().expect("right");
+
+    partners[0] = *partner;
+    partners[*partner] = 0;
+    partners[left] = right;
+    partners[right] = left;
+
+    let trans = partners[permutation[0]];
+    let partner = permutation.iter().position(|&old| old == trans)
+        .expect("trans partner");
+
+    SQUARE.iter().find(|(_, other)| *other == partner)
+        .map(|(square, _)| square.clone())
+}
+
+// Axial positions and whether the equatorial neighbors run anticlockwise
+// (@) when viewed from the first axial neighbor, for TB1 through TB20.
+const BIPYRAMID: [(Configuration, usize, usize, bool); 20] = [
+    (Configuration::TB1, 0, 4, true),
+    (Configuration::TB2, 0, 4, false),
+    (Configuration::TB3, 0, 3, true),
+    (Configuration::TB4, 0, 3, false),
+    (Configuration::TB5, 0, 2, true),
+    (Configuration::TB6, 0, 2, false),
+    (Configuration::TB7, 0, 1, true),
+    (Configuration::TB8, 0, 1, false),
+    (Configuration::TB9, 1, 4, true),
+    (Configuration::TB10, 1, 3, true),
+    (Configuration::TB11, 1, 4, false),
+    (Configuration::TB12, 1, 3, false),
+    (Configuration::TB13, 1, 2, true),
+    (Configuration::TB14, 1, 2, false),
+    (Configuration::TB15, 2, 4, true),
+    (Configuration::TB16, 2, 3, true),
+    (Configuration::TB17, 3, 4, true),
+    (Configuration::TB18, 3, 4, false),
+    (Configuration::TB19, 2, 3, false),
+    (Configuration::TB20, 2, 4, false)
+];
+
+fn permute_bipyramid(
+    config: &Configuration, permutation: &[usize]
+) -> Option<Configuration> {
+    if permutation.len() != 5 {
+        return None
+    }
+
+    let (_, first, last, anticlockwise) = BIPYRAMID.iter()
+        .find(|(bipyramid, ..)| bipyramid == config)
+        .expect("trigonal bipyramidal");
+    let position = |old: usize| {
+        permutation.iter().position(|&p| p == old).expect("position")
+    };
+    let (new_first, new_last) = (position(*first), position(*last));
+    let equatorial = (0..5).filter(|&i| i != *first && i != *last)
+        .collect::<Vec<_>>();
+    let order = permutation.iter()
+        .filter(|&&old| old != *first && old != *last)
+        .map(|old| equatorial.iter().position(|e| e == old).expect("index"))
+        .collect::<Vec<_>>();
+    let flipped = new_first > new_last;
+    let axis = if flipped {
+        (new_last, new_first)
+    } else {
+        (new_first, new_last)
+    };
+    let anticlockwise = anticlockwise ^ flipped ^ is_odd(&order);
+
+    BIPYRAMID.iter().find(|(_, first, last, other)| {
+        (*first, *last) == axis && *other == anticlockwise
+    }).map(|(bipyramid, ..)| bipyramid.clone())
+}
+
+// Axial partner of the first neighbor, trans partner of the first
+// equatorial neighbor, and whether the equatorial neighbors run
+// anticlockwise (@) when viewed from the first neighbor, for OH1 through
+// OH30. Trans partners 2, 1, and 3 give the U, 4, and Z shapes of SQUARE.
+const OCTAHEDRON: [(Configuration, usize, usize, bool); 30] = [
+    (Configuration::OH1, 5, 2, true),
+    (Configuration::OH2, 5, 2, false),
+    (Configuration::OH3, 4, 2, true),
+    (Configuration::OH4, 4, 3, true),
+    (Configuration::OH5, 4, 1, true),
+    (Configuration::OH6, 4, 1, false),
+    (Configuration::OH7, 4, 3, false),
+    (Configuration::OH8, 4, 2, false),
+    (Configuration::OH9, 3, 2, true),
+    (Configuration::OH10, 3, 3, true),
+    (Configuration::OH11, 3, 1, true),
+    (Configuration::OH12, 3, 1, false),
+    (Configuration::OH13, 3, 3, false),
+    (Configuration::OH14, 3, 2, false),
+    (Configuration::OH15, 2, 2, true),
+    (Configuration::OH16, 2, 3, true),
+    (Configuration::OH17, 2, 1, true),
+    (Configuration::OH18, 2, 1, false),
+    (Configuration::OH19, 2, 3, false),
+    (Configuration::OH20, 2, 2, false),
+    (Configuration::OH21, 1, 2, true),
+    (Configuration::OH22, 1, 3, true),
+    (Configuration::OH23, 1, 1, true),
+    (Configuration::OH24, 1, 1, false),
+    (Configuration::OH25, 1, 3, false),
+    (Configuration::OH26, 1, 2, false),
+    (Configuration::OH27, 5, 3, true),
+    (Configuration::OH28, 5, 3, false),
+    (Configuration::OH29, 5, 1, true),
+    (Configuration::OH30, 5, 1, false)
+];
+
+fn permute_octahedron(
+    config: &Configuration, permutation: &[usize]
+) -> Option<Configuration> {
+    if permutation.len() != 6 {
+        return None
+    }
+
+    let (_, axial, trans, anticlockwise) = OCTAHEDRON.iter()
+        .find(|(octahedron, ..)| octahedron == config)
+        .expect("octahedral");
+    let old = octahedron(*axial, *trans, *anticlockwise);
+    let new = permutation.iter().map(|&old_index| old[old_index])
+        .collect::<Vec<_>>();
+    let opposite = |a: [i8; 3], b: [i8; 3]| (0..3).all(|i| a[i] == -b[i]);
+    let axial = (1..6).find(|&i| opposite(new[i], new[0]))
+        .expect("axial");
+    let equatorial = (1..6).filter(|&i| i != axial).map(|i| new[i])
+        .collect::<Vec<_>>();
+    let trans = (1..4).find(|&i| opposite(equatorial[i], equatorial[0]))
+        .expect("trans");
+    // The 4 shape starts across the square, so its direction is taken
+    // from the second step.
+    let (from, to) = if trans == 1 {
+        (equatorial[1], equatorial[2])
+    } else {
+        (equatorial[0], equatorial[1])
+    };
+    let normal = [
+        from[1] * to[2] - from[2] * to[1],
+        from[2] * to[0] - from[0] * to[2],
+        from[0] * to[1] - from[1] * to[0]
+    ];
+    let anticlockwise = (0..3).map(|i| normal[i] * new[0][i]).sum::<i8>() > 0;
+
+    OCTAHEDRON.iter().find(|(_, other_axial, other_trans, other)| {
+        (*other_axial, *other_trans, *other) == (axial, trans, anticlockwise)
+    }).map(|(octahedron, ..)| octahedron.clone())
+}
+
+// Unit vectors for the six neighbors of an octahedral configuration, with
+// the first neighbor on +z and the equatorial neighbors around z.
+fn octahedron(axial: usize, trans: usize, anticlockwise: bool) -> [[i8; 3]; 6] {
+    let equatorial = (1..6).filter(|&i| i != axial).collect::<Vec<_>>();
+    let cycle = match trans {
+        2 => [ 0, 1, 2, 3 ],
+        3 => [ 0, 1, 3, 2 ],
+        _ => [ 0, 3, 1, 2 ]
+    };
+    let turn = if anticlockwise { 1 } else { -1 };
+    let square = [ [ 1, 0, 0 ], [ 0, turn, 0 ], [ -1, 0, 0 ], [ 0, -turn, 0 ] ];
+    let mut result = [ [ 0; 3 ]; 6 ];
+
+    result[0] = [ 0, 0, 1 ];
+    result[axial] = [ 0, 0, -1 ];
+
+    for (corner, &index) in cycle.iter().enumerate() {
+        result[equatorial[index]] = square[corner]
+    }
+
+    result
+}
+
+fn is_odd(permutation: &[usize]) -> bool {
+    let mut seen = vec![ false; permutation.len() ];
+
+    for &position in permutation {
+        assert!(
+            position < permutation.len() && !seen[position], "permutation"
+        );
+
+        seen[position] = true;
+    }
+
+    let mut visited = vec![ false; permutation.len() ];
+    let mut cycles = 0;
+
+    for start in 0..permutation.len() {
+        let mut position = start;
+
+        if visited[position] {
+            continue
+        }
+
+        while !visited[position] {
+            visited[position] = true;
+            position = permutation[position];
+        }
+
+        cycles += 1;
+    }
+
+    (permutation.len() - cycles) % 2 == 1
+}
+
 impl fmt::Display for Configuration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -89,10 +383,10 @@ impl fmt::Display for Configuration {
             Configuration::TB17 => "@TB17",
             Configuration::TB18 => "@TB18",
             Configuration::TB19 => "@TB19",
-            Configuration::TB20 => "@TH1",
+            Configuration::TB20 => "@TB20",
             Configuration::OH1 => "@OH1",
             Configuration::OH2 => "@OH2",
-            Configuration::OH3 => "@TH1",
+            Configuration::OH3 => "@OH3",
             Configuration::OH4 => "@OH4",
             Configuration::OH5 => "@OH5",
             Configuration::OH6 => "@OH6",
@@ -103,7 +397,7 @@ impl fmt::Display for Configuration {
             Configuration::OH11 => "@OH11",
             Configuration::OH12 => "@OH12",
             Configuration::OH13 => "@OH13",
-            Configuration::OH14 => "@OH6",
+            Configuration::OH14 => "@OH14",
             Configuration::OH15 => "@OH15",
             Configuration::OH16 => "@OH16",
             Configuration::OH17 => "@OH17",
@@ -125,4 +419,30 @@ impl fmt::Display for Configuration {
             Configuration::SP3 => "@SP3"
         })
     }
+}
+
+#[cfg(test)]
+mod display {
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    #[test]
+    fn th1() {
+        assert_eq!(Configuration::TH1.to_string(), "@")
+    }
+
+    #[test]
+    fn tb20() {
+        assert_eq!(Configuration::TB20.to_string(), "@TB20")
+    }
+
+    #[test]
+    fn oh3() {
+        assert_eq!(Configuration::OH3.to_string(), "@OH3")
+    }
+
+    #[test]
+    fn oh14() {
+        assert_eq!(Configuration::OH14.to_string(), "@OH14")
+    }
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
 use std::fmt;
+use std::ops::RangeInclusive;
 
-/// Complete and up-to-date set of atomic symbols.
-#[derive(Debug,PartialEq)]
+/// Complete and up-to-date set of atomic symbols. Variants are declared,
+/// and therefore ordered, by atomic number.
+#[derive(Debug,PartialEq,Eq,PartialOrd,Ord,Hash,Clone)]
 pub enum Element {
 //  0   1   2   3   4   5   6   7   8   9
         H,  He, Li, Be, B,  C,  N,  O,  F,  // 0
@@ -46,7 +48,7 @@ impl fmt::Display for Element {
             Element::Cn => "Cn",
             Element::Co => "Co",
             Element::Cr => "Cr",
-            Element::Cs => "Ac",
+            Element::Cs => "Cs",
             Element::Cu => "Cu",
             Element::Db => "Db",
             Element::Ds => "Ds",
@@ -141,4 +143,617 @@ impl fmt::Display for Element {
             Element::Zr => "Zr"
         })
     }
+}
+
+impl Element {
+    /// Returns the atomic number.
+    pub fn atomic_number(&self) -> u8 {
+        self.clone() as u8 + 1
+    }
+
+    /// Returns true for metals: the transition metals, lanthanides,
+    /// actinides, and the alkali, alkaline earth, and post-transition
+    /// metals.
+    pub fn is_metal(&self) -> bool {
+        let category = self.category();
+
+        category == Category::TransitionMetal || category == Category::Metal
+    }
+
+    /// Returns true for the d-block elements of groups 3 through 12,
+    /// excluding the lanthanides and actinides.
+    pub fn is_transition_metal(&self) -> bool {
+        self.category() == Category::TransitionMetal
+    }
+
+    /// Returns true for B, Si, Ge, As, Sb, and Te.
+    pub fn is_metalloid(&self) -> bool {
+        self.category() == Category::Metalloid
+    }
+
+    /// Returns true for the group 17 elements.
+    pub fn is_halogen(&self) -> bool {
+        self.category() == Category::Halogen
+    }
+
+    /// Returns true for the group 18 elements.
+    pub fn is_noble_gas(&self) -> bool {
+        self.category() == Category::NobleGas
+    }
+
+    /// Returns true for the OpenSMILES organic subset: B, C, N, O, P, S, F,
+    /// Cl, Br, and I. These can be written without brackets when their
+    /// hydrogens are implied by valence.
+    pub fn is_organic_subset(&self) -> bool {
+        matches!(
+            self,
+            Element::B  | Element::C  | Element::N  | Element::O  |
+            Element::P  | Element::S  | Element::F  | Element::Cl |
+            Element::Br | Element::I
+        )
+    }
+
+    fn category(&self) -> Category {
+        match self {
+            Element::H  | Element::C  | Element::N  | Element::O  |
+            Element::P  | Element::S  | Element::Se => Category::Nonmetal,
+            Element::F  | Element::Cl | Element::Br | Element::I  |
+            Element::At | Element::Ts => Category::Halogen,
+            Element::He | Element::Ne | Element::Ar | Element::Kr |
+            Element::Xe | Element::Rn | Element::Og => Category::NobleGas,
+            Element::B  | Element::Si | Element::Ge | Element::As |
+            Element::Sb | Element::Te => Category::Metalloid,
+            Element::Sc | Element::Ti | Element::V  | Element::Cr |
+            Element::Mn | Element::Fe | Element::Co | Element::Ni |
+            Element::Cu | Element::Zn |
+            Element::Y  | Element::Zr | Element::Nb | Element::Mo |
+            Element::Tc | Element::Ru | Element::Rh | Element::Pd |
+            Element::Ag | Element::Cd |
+            Element::Hf | Element::Ta | Element::W  | Element::Re |
+            Element::Os | Element::Ir | Element::Pt | Element::Au |
+            Element::Hg |
+            Element::Rf | Element::Db | Element::Sg | Element::Bh |
+            Element::Hs | Element::Mt | Element::Ds | Element::Rg |
+            Element::Cn => Category::TransitionMetal,
+            _ => Category::Metal
+        }
+    }
+
+    /// Returns the standard atomic weight in daltons. Elements without a
+    /// standard weight use the mass number of their longest-lived isotope.
+    pub fn atomic_weight(&self) -> f64 {
+        match self {
+            Element::Ac => 227.0,
+            Element::Ag => 107.87,
+            Element::Al => 26.982,
+            Element::Am => 243.0,
+            Element::Ar => 39.95,
+            Element::As => 74.922,
+            Element::At => 210.0,
+            Element::Au => 196.97,
+            Element::B  => 10.81,
+            Element::Ba => 137.33,
+            Element::Be => 9.0122,
+            Element::Bh => 270.0,
+            Element::Bi => 208.98,
+            Element::Bk => 247.0,
+            Element::Br => 79.904,
+            Element::C  => 12.011,
+            Element::Ca => 40.078,
+            Element::Cd => 112.41,
+            Element::Ce => 140.12,
+            Element::Cf => 251.0,
+            Element::Cl => 35.45,
+            Element::Cm => 247.0,
+            Element::Cn => 285.0,
+            Element::Co => 58.933,
+            Element::Cr => 51.996,
+            Element::Cs => 132.91,
+            Element::Cu => 63.546,
+            Element::Db => 268.0,
+            Element::Ds => 281.0,
+            Element::Dy => 162.50,
+            Element::Er => 167.26,
+            Element::Es => 252.0,
+            Element::Eu => 151.96,
+            Element::F  => 18.998,
+            Element::Fe => 55.845,
+            Element::Fl => 289.0,
+            Element::Fm => 257.0,
+            Element::Fr => 223.0,
+            Element::Ga => 69.723,
+            Element::Gd => 157.25,
+            Element::Ge => 72.630,
+            Element::H  => 1.008,
+            Element::He => 4.0026,
+            Element::Hf => 178.49,
+            Element::Hg => 200.59,
+            Element::Ho => 164.93,
+            Element::Hs => 277.0,
+            Element::I  => 126.90,
+            Element::In => 114.82,
+            Element::Ir => 192.22,
+            Element::K  => 39.098,
+            Element::Kr => 83.798,
+            Element::La => 138.91,
+            Element::Li => 6.94,
+            Element::Lr => 266.0,
+            Element::Lu => 174.97,
+            Element::Lv => 293.0,
+            Element::Mc => 290.0,
+            Element::Md => 258.0,
+            Element::Mg => 24.305,
+            Element::Mn => 54.938,
+            Element::Mo => 95.95,
+            Element::Mt => 278.0,
+            Element::N  => 14.007,
+            Element::Na => 22.990,
+            Element::Nb => 92.906,
+            Element::Nd => 144.24,
+            Element::Ne => 20.180,
+            Element::Nh => 286.0,
+            Element::Ni => 58.693,
+            Element::No => 259.0,
+            Element::Np => 237.0,
+            Element::O  => 15.999,
+            Element::Og => 294.0,
+            Element::Os => 190.23,
+            Element::P  => 30.974,
+            Element::Pa => 231.04,
+            Element::Pb => 207.2,
+            Element::Pd => 106.42,
+            Element::Pm => 145.0,
+            Element::Po => 209.0,
+            Element::Pr => 140.91,
+            Element::Pt => 195.08,
+            Element::Pu => 244.0,
+            Element::Ra => 226.0,
+            Element::Rb => 85.468,
+            Element::Re => 186.21,
+            Element::Rf => 267.0,
+            Element::Rg => 282.0,
+            Element::Rh => 102.91,
+            Element::Rn => 222.0,
+            Element::Ru => 101.07,
+            Element::S  => 32.06,
+            Element::Sb => 121.76,
+            Element::Sc => 44.956,
+            Element::Se => 78.971,
+            Element::Sg => 269.0,
+            Element::Si => 28.085,
+            Element::Sm => 150.36,
+            Element::Sn => 118.71,
+            Element::Sr => 87.62,
+            Element::Ta => 180.95,
+            Element::Tb => 158.93,
+            Element::Tc => 98.0,
+            Element::Te => 127.60,
+            Element::Th => 232.04,
+            Element::Ti => 47.867,
+            Element::Tl => 204.38,
+            Element::Tm => 168.93,
+            Element::Ts => 294.0,
+            Element::U  => 238.03,
+            Element::V  => 50.942,
+            Element::W  => 183.84,
+            Element::Xe => 131.29,
+            Element::Y  => 88.906,
+            Element::Yb => 173.05,
+            Element::Zn => 65.38,
+            Element::Zr => 91.224
+        }
+    }
+
+    /// Returns the mass in daltons of this element's most abundant
+    /// isotope. Elements without a stable isotope use their longest-lived
+    /// isotope.
+    pub fn monoisotopic_mass(&self) -> f64 {
+        match self {
+            Element::Ac => 227.027752,
+            Element::Ag => 106.905092,
+            Element::Al => 26.981539,
+            Element::Am => 243.061381,
+            Element::Ar => 39.962383,
+            Element::As => 74.921595,
+            Element::At => 209.987148,
+            Element::Au => 196.966569,
+            Element::B  => 11.009305,
+            Element::Ba => 137.905247,
+            Element::Be => 9.012183,
+            Element::Bh => 270.13336,
+            Element::Bi => 208.980399,
+            Element::Bk => 247.070307,
+            Element::Br => 78.918338,
+            Element::C  => 12.0,
+            Element::Ca => 39.962591,
+            Element::Cd => 113.903365,
+            Element::Ce => 139.905443,
+            Element::Cf => 251.079589,
+            Element::Cl => 34.968853,
+            Element::Cm => 247.070354,
+            Element::Cn => 285.17712,
+            Element::Co => 58.933194,
+            Element::Cr => 51.940506,
+            Element::Cs => 132.905452,
+            Element::Cu => 62.929598,
+            Element::Db => 268.12567,
+            Element::Ds => 281.16451,
+            Element::Dy => 163.929182,
+            Element::Er => 165.930299,
+            Element::Es => 252.08298,
+            Element::Eu => 152.921238,
+            Element::F  => 18.998403,
+            Element::Fe => 55.934936,
+            Element::Fl => 289.19042,
+            Element::Fm => 257.095106,
+            Element::Fr => 223.019736,
+            Element::Ga => 68.925573,
+            Element::Gd => 157.924112,
+            Element::Ge => 73.921178,
+            Element::H  => 1.007825,
+            Element::He => 4.002603,
+            Element::Hf => 179.946557,
+            Element::Hg => 201.970643,
+            Element::Ho => 164.930329,
+            Element::Hs => 277.1519,
+            Element::I  => 126.904472,
+            Element::In => 114.903879,
+            Element::Ir => 192.962922,
+            Element::K  => 38.963706,
+            Element::Kr => 83.911498,
+            Element::La => 138.906356,
+            Element::Li => 7.016003,
+            Element::Lr => 266.11983,
+            Element::Lu => 174.940775,
+            Element::Lv => 293.20449,
+            Element::Mc => 290.19598,
+            Element::Md => 258.098432,
+            Element::Mg => 23.985042,
+            Element::Mn => 54.938044,
+            Element::Mo => 97.905405,
+            Element::Mt => 278.15631,
+            Element::N  => 14.003074,
+            Element::Na => 22.989769,
+            Element::Nb => 92.906373,
+            Element::Nd => 141.907729,
+            Element::Ne => 19.99244,
+            Element::Nh => 286.18221,
+            Element::Ni => 57.935342,
+            Element::No => 259.10103,
+            Element::Np => 237.048174,
+            Element::O  => 15.994915,
+            Element::Og => 294.21392,
+            Element::Os => 191.961477,
+            Element::P  => 30.973762,
+            Element::Pa => 231.035884,
+            Element::Pb => 207.976653,
+            Element::Pd => 105.90348,
+            Element::Pm => 144.912756,
+            Element::Po => 208.982431,
+            Element::Pr => 140.907658,
+            Element::Pt => 194.964792,
+            Element::Pu => 244.064205,
+            Element::Ra => 226.02541,
+            Element::Rb => 84.91179,
+            Element::Re => 186.95575,
+            Element::Rf => 267.12179,
+            Element::Rg => 282.16912,
+            Element::Rh => 102.905498,
+            Element::Rn => 222.017578,
+            Element::Ru => 101.904344,
+            Element::S  => 31.972071,
+            Element::Sb => 120.903812,
+            Element::Sc => 44.955908,
+            Element::Se => 79.916522,
+            Element::Sg => 269.12863,
+            Element::Si => 27.976927,
+            Element::Sm => 151.91974,
+            Element::Sn => 119.902202,
+            Element::Sr => 87.905613,
+            Element::Ta => 180.947996,
+            Element::Tb => 158.925355,
+            Element::Tc => 97.907212,
+            Element::Te => 129.906223,
+            Element::Th => 232.038056,
+            Element::Ti => 47.947942,
+            Element::Tl => 204.974428,
+            Element::Tm => 168.934218,
+            Element::Ts => 294.21046,
+            Element::U  => 238.050788,
+            Element::V  => 50.943957,
+            Element::W  => 183.950931,
+            Element::Xe => 131.904155,
+            Element::Y  => 88.90584,
+            Element::Yb => 173.938866,
+            Element::Zn => 63.929142,
+            Element::Zr => 89.904698
+        }
+    }
+
+    /// Returns the formal charges that are plausible on an atom of this
+    /// element in SMILES: zero, the charges of its common monatomic ions
+    /// and onium or ate forms, and down to -4 for transition metals at the
+    /// center of anionic complexes. This is not the range of oxidation
+    /// states, so `[C-4]` and `[Cl+7]` fall outside. Superheavy elements
+    /// allow every charge from -15 to +15.
+    pub fn charge_range(&self) -> RangeInclusive<i8> {
+        match self {
+            Element::Ac => 0..=3,
+            Element::Ag => -3..=1,
+            Element::Al => -1..=3,
+            Element::Am => 0..=4,
+            Element::Ar => 0..=0,
+            Element::As => -3..=1,
+            Element::At => -1..=1,
+            Element::Au => -3..=3,
+            Element::B  => -1..=1,
+            Element::Ba => 0..=2,
+            Element::Be => -2..=2,
+            Element::Bh => -15..=15,
+            Element::Bi => -3..=3,
+            Element::Bk => 0..=3,
+            Element::Br => -1..=3,
+            Element::C  => -1..=1,
+            Element::Ca => 0..=2,
+            Element::Cd => -2..=2,
+            Element::Ce => 0..=4,
+            Element::Cf => 0..=3,
+            Element::Cl => -1..=3,
+            Element::Cm => 0..=3,
+            Element::Cn => -15..=15,
+            Element::Co => -3..=3,
+            Element::Cr => -4..=3,
+            Element::Cs => 0..=1,
+            Element::Cu => -3..=2,
+            Element::Db => -15..=15,
+            Element::Ds => -15..=15,
+            Element::Dy => 0..=3,
+            Element::Er => 0..=3,
+            Element::Es => 0..=3,
+            Element::Eu => 0..=3,
+            Element::F  => -1..=1,
+            Element::Fe => -4..=3,
+            Element::Fl => -15..=15,
+            Element::Fm => 0..=3,
+            Element::Fr => 0..=1,
+            Element::Ga => -1..=3,
+            Element::Gd => 0..=3,
+            Element::Ge => -2..=1,
+            Element::H  => -1..=1,
+            Element::He => 0..=0,
+            Element::Hf => -2..=4,
+            Element::Hg => -2..=2,
+            Element::Ho => 0..=3,
+            Element::Hs => -15..=15,
+            Element::I  => -1..=3,
+            Element::In => -1..=3,
+            Element::Ir => -3..=3,
+            Element::K  => 0..=1,
+            Element::Kr => 0..=1,
+            Element::La => 0..=3,
+            Element::Li => 0..=1,
+            Element::Lr => 0..=3,
+            Element::Lu => 0..=3,
+            Element::Lv => -15..=15,
+            Element::Mc => -15..=15,
+            Element::Md => 0..=3,
+            Element::Mg => 0..=2,
+            Element::Mn => -3..=3,
+            Element::Mo => -4..=3,
+            Element::Mt => -15..=15,
+            Element::N  => -3..=1,
+            Element::Na => 0..=1,
+            Element::Nb => -3..=3,
+            Element::Nd => 0..=3,
+            Element::Ne => 0..=0,
+            Element::Nh => -15..=15,
+            Element::Ni => -2..=4,
+            Element::No => 0..=3,
+            Element::Np => 0..=6,
+            Element::O  => -2..=1,
+            Element::Og => -15..=15,
+            Element::Os => -4..=3,
+            Element::P  => -3..=1,
+            Element::Pa => 0..=5,
+            Element::Pb => -2..=4,
+            Element::Pd => -2..=4,
+            Element::Pm => 0..=3,
+            Element::Po => -2..=2,
+            Element::Pr => 0..=3,
+            Element::Pt => -2..=4,
+            Element::Pu => 0..=6,
+            Element::Ra => 0..=2,
+            Element::Rb => 0..=1,
+            Element::Re => -3..=3,
+            Element::Rf => -15..=15,
+            Element::Rg => -15..=15,
+            Element::Rh => -3..=3,
+            Element::Rn => 0..=1,
+            Element::Ru => -4..=3,
+            Element::S  => -2..=1,
+            Element::Sb => -3..=3,
+            Element::Sc => 0..=3,
+            Element::Se => -2..=1,
+            Element::Sg => -15..=15,
+            Element::Si => -2..=1,
+            Element::Sm => 0..=3,
+            Element::Sn => -2..=4,
+            Element::Sr => 0..=2,
+            Element::Ta => -3..=3,
+            Element::Tb => 0..=3,
+            Element::Tc => -3..=3,
+            Element::Te => -2..=1,
+            Element::Th => 0..=4,
+            Element::Ti => -2..=4,
+            Element::Tl => 0..=3,
+            Element::Tm => 0..=3,
+            Element::Ts => -15..=15,
+            Element::U  => 0..=6,
+            Element::V  => -3..=3,
+            Element::W  => -4..=3,
+            Element::Xe => 0..=1,
+            Element::Y  => 0..=3,
+            Element::Yb => 0..=3,
+            Element::Zn => -2..=2,
+            Element::Zr => -2..=4
+        }
+    }
+}
+
+#[derive(Debug,PartialEq,Eq)]
+enum Category {
+    Nonmetal,
+    Halogen,
+    NobleGas,
+    Metalloid,
+    TransitionMetal,
+    Metal
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    #[test]
+    fn cesium() {
+        assert_eq!(Element::Cs.to_string(), "Cs")
+    }
+
+    #[test]
+    fn actinium() {
+        assert_eq!(Element::Ac.to_string(), "Ac")
+    }
+
+    #[test]
+    fn atomic_weight_carbon() {
+        assert_eq!(Element::C.atomic_weight(), 12.011)
+    }
+
+    #[test]
+    fn monoisotopic_mass_carbon() {
+        assert_eq!(Element::C.monoisotopic_mass(), 12.0)
+    }
+
+    #[test]
+    fn monoisotopic_mass_bromine() {
+        assert_eq!(Element::Br.monoisotopic_mass(), 78.918338)
+    }
+
+    #[test]
+    fn charge_range_iron() {
+        assert_eq!(Element::Fe.charge_range(), -4..=3)
+    }
+
+    #[test]
+    fn charge_range_carbon() {
+        assert_eq!(Element::C.charge_range(), -1..=1)
+    }
+
+    #[test]
+    fn charge_range_noble_gas() {
+        assert_eq!(Element::He.charge_range(), 0..=0)
+    }
+
+    #[test]
+    fn charge_range_alkali_metal() {
+        assert_eq!(Element::Na.charge_range(), 0..=1)
+    }
+
+    #[test]
+    fn charge_range_chlorine() {
+        assert_eq!(Element::Cl.charge_range(), -1..=3)
+    }
+
+    #[test]
+    fn atomic_weight_technetium() {
+        assert_eq!(Element::Tc.atomic_weight(), 98.0)
+    }
+
+    #[test]
+    fn iron() {
+        assert!(Element::Fe.is_transition_metal());
+        assert!(Element::Fe.is_metal());
+        assert!(!Element::Fe.is_metalloid())
+    }
+
+    #[test]
+    fn chlorine() {
+        assert!(Element::Cl.is_halogen());
+        assert!(!Element::Cl.is_metal())
+    }
+
+    #[test]
+    fn silicon() {
+        assert!(Element::Si.is_metalloid());
+        assert!(!Element::Si.is_metal())
+    }
+
+    #[test]
+    fn argon() {
+        assert!(Element::Ar.is_noble_gas());
+        assert!(!Element::Ar.is_halogen())
+    }
+
+    #[test]
+    fn sodium() {
+        assert!(Element::Na.is_metal());
+        assert!(!Element::Na.is_transition_metal())
+    }
+
+    #[test]
+    fn carbon() {
+        assert!(!Element::C.is_metal());
+        assert!(!Element::C.is_metalloid())
+    }
+
+    #[test]
+    fn organic_subset() {
+        let subset = vec![
+            Element::B, Element::C, Element::N, Element::O, Element::P,
+            Element::S, Element::F, Element::Cl, Element::Br, Element::I
+        ];
+
+        for element in subset {
+            assert!(element.is_organic_subset(), "{}", element)
+        }
+    }
+
+    #[test]
+    fn organic_subset_iron() {
+        assert!(!Element::Fe.is_organic_subset())
+    }
+
+    #[test]
+    fn atomic_number_hydrogen() {
+        assert_eq!(Element::H.atomic_number(), 1)
+    }
+
+    #[test]
+    fn atomic_number_oganesson() {
+        assert_eq!(Element::Og.atomic_number(), 118)
+    }
+
+    #[test]
+    fn sort_by_atomic_number() {
+        let mut elements = vec![ Element::O, Element::C, Element::H ];
+
+        elements.sort();
+
+        assert_eq!(elements, vec![ Element::H, Element::C, Element::O ])
+    }
+
+    #[test]
+    fn formula_histogram() {
+        let mut histogram = BTreeMap::new();
+
+        for element in &[ Element::O, Element::C, Element::H, Element::C ] {
+            *histogram.entry(element.clone()).or_insert(0) += 1;
+        }
+
+        assert_eq!(histogram.into_iter().collect::<Vec<_>>(), vec![
+            (Element::H, 1), (Element::C, 2), (Element::O, 1)
+        ])
+    }
 }
\ No newline at end of file
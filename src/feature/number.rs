@@ -1,8 +1,10 @@
 use std::convert::{ TryFrom, TryInto, From };
 use std::fmt;
 
-/// An integer from zero to 1,000.
-#[derive(Debug,PartialEq)]
+/// An integer from zero to 9,999, as used by isotopes and atom maps.
+/// OpenSMILES allows up to 999; wider values are read only by raising
+/// `ReadOptions::max_number_digits`. The default is zero.
+#[derive(Debug,PartialEq,Eq,Hash,Clone,Default)]
 pub struct Number {
     value: u16
 }
@@ -11,8 +13,8 @@ impl TryFrom<u16> for Number {
     type Error = ();
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        if value < 1000 {
-            return Ok(Number { value })
+        if value < 10000 {
+            Ok(Number { value })
         } else {
             Err(())
         }
@@ -30,7 +32,7 @@ impl TryInto<Number> for String {
 
     fn try_into(self) -> Result<Number, Self::Error> {
         match self.parse::<u16>() {
-            Ok(number) => Ok(Number { value: number}),
+            Ok(number) => Number::try_from(number),
             Err(_) => Err(())
         }
     }
@@ -40,4 +42,26 @@ impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.value)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_widest() {
+        assert_eq!(u16::from(&Number::try_from(9999).unwrap()), 9999)
+    }
+
+    #[test]
+    fn try_from_too_wide() {
+        assert_eq!(Number::try_from(10000), Err(()))
+    }
+
+    #[test]
+    fn try_into_too_wide() {
+        let result: Result<Number, ()> = "10000".to_string().try_into();
+
+        assert_eq!(result, Err(()))
+    }
 }
\ No newline at end of file
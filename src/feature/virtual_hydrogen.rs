@@ -3,8 +3,10 @@ use std::convert::TryFrom;
 
 /// Represents the virtual hydrogen count on a bracket atom.
 /// See: [Hydrogen Suppression in SMILES](https://depth-first.com/articles/2020/06/08/hydrogen-suppression-in-smiles/).
-#[derive(Debug,PartialEq)]
+/// The default is `H0`.
+#[derive(Debug,PartialEq,Eq,Hash,Clone,Default)]
 pub enum VirtualHydrogen {
+    #[default]
     H0,
     H1,
     H2,
@@ -44,9 +46,9 @@ impl TryFrom<u8> for VirtualHydrogen {
     }
 }
 
-impl Into<u8> for &VirtualHydrogen {
-    fn into(self) -> u8 {
-        match self {
+impl From<&VirtualHydrogen> for u8 {
+    fn from(hcount: &VirtualHydrogen) -> Self {
+        match hcount {
             VirtualHydrogen::H0 => 0,
             VirtualHydrogen::H1 => 1,
             VirtualHydrogen::H2 => 2,
@@ -0,0 +1,115 @@
+use crate::feature::BondKind;
+use super::{ Atom, Bond, Error };
+
+/// Adds a bond of `kind` between existing atoms `sid` and `tid`. Directional
+/// kinds are reversed for the bond from `tid` to `sid`.
+/// 
+/// ```
+/// use purr::graph::{ Atom, Bond, add_bond };
+/// use purr::feature::{ AtomKind, BondKind };
+/// 
+/// fn main() {
+///     let mut atoms = vec![
+///         Atom::new(AtomKind::Star),
+///         Atom::new(AtomKind::Star)
+///     ];
+/// 
+///     add_bond(&mut atoms, 0, 1, BondKind::Up).unwrap();
+/// 
+///     assert_eq!(atoms[0].bonds, vec![ Bond::new(BondKind::Up, 1) ]);
+///     assert_eq!(atoms[1].bonds, vec![ Bond::new(BondKind::Down, 0) ])
+/// }
+/// ```
+pub fn add_bond(
+    atoms: &mut [Atom], sid: usize, tid: usize, kind: BondKind
+) -> Result<(), Error> {
+    if sid >= atoms.len() || tid >= atoms.len() {
+        return Err(Error::UnknownTarget(sid, tid))
+    } else if sid == tid {
+        return Err(Error::Loop(sid))
+    } else if atoms[sid].bonds.iter().any(|bond| bond.tid == tid) {
+        return Err(Error::DuplicateBond(sid, tid))
+    }
+
+    atoms[tid].bonds.push(Bond::new(kind.reverse(), sid));
+    atoms[sid].bonds.push(Bond::new(kind, tid));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ AtomKind, Aliphatic };
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn unknown_source() {
+        let mut atoms = build("C.C");
+
+        assert_eq!(
+            add_bond(&mut atoms, 2, 0, BondKind::Single),
+            Err(Error::UnknownTarget(2, 0))
+        )
+    }
+
+    #[test]
+    fn unknown_target() {
+        let mut atoms = build("C.C");
+
+        assert_eq!(
+            add_bond(&mut atoms, 0, 2, BondKind::Single),
+            Err(Error::UnknownTarget(0, 2))
+        )
+    }
+
+    #[test]
+    fn self_bond() {
+        let mut atoms = build("C.C");
+
+        assert_eq!(
+            add_bond(&mut atoms, 1, 1, BondKind::Single),
+            Err(Error::Loop(1))
+        )
+    }
+
+    #[test]
+    fn duplicate() {
+        let mut atoms = build("CC");
+
+        assert_eq!(
+            add_bond(&mut atoms, 1, 0, BondKind::Single),
+            Err(Error::DuplicateBond(1, 0))
+        )
+    }
+
+    #[test]
+    fn fragments() {
+        let mut atoms = build("CC.O");
+
+        add_bond(&mut atoms, 1, 2, BondKind::Elided).unwrap();
+
+        assert_eq!(atoms, vec![
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0),
+                    Bond::new(BondKind::Elided, 2)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::O),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1)
+                ]
+            }
+        ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::feature::BondKind;
+use super::Atom;
+
+/// Returns an N×N matrix of bond orders, with zero where atoms are not
+/// bonded. Aromatic bonds, whether written with `:` or elided between two
+/// aromatic atoms, are assigned the value `aromatic`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn adjacency_matrix(atoms: &[Atom], aromatic: u8) -> Vec<Vec<u8>> {
+    let mut result = vec![ vec![ 0; atoms.len() ]; atoms.len() ];
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            let is_aromatic = match bond.kind {
+                BondKind::Aromatic => true,
+                BondKind::Elided =>
+                    atom.is_aromatic() && atoms[bond.tid].is_aromatic(),
+                _ => false
+            };
+
+            result[sid][bond.tid] = if is_aromatic {
+                aromatic
+            } else {
+                bond.order()
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(adjacency_matrix(&[ ], 0), Vec::<Vec<u8>>::new())
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(adjacency_matrix(&build("CCO"), 0), vec![
+            vec![ 0, 1, 0 ],
+            vec![ 1, 0, 1 ],
+            vec![ 0, 1, 0 ]
+        ])
+    }
+
+    #[test]
+    fn acetonitrile() {
+        assert_eq!(adjacency_matrix(&build("CC#N"), 0), vec![
+            vec![ 0, 1, 0 ],
+            vec![ 1, 0, 3 ],
+            vec![ 0, 3, 0 ]
+        ])
+    }
+
+    #[test]
+    fn cyclopropene() {
+        assert_eq!(adjacency_matrix(&build("C1=CC1"), 0), vec![
+            vec![ 0, 2, 1 ],
+            vec![ 2, 0, 1 ],
+            vec![ 1, 1, 0 ]
+        ])
+    }
+
+    #[test]
+    fn phenol() {
+        assert_eq!(adjacency_matrix(&build("Oc1ccccc1"), 15), vec![
+            vec![ 0, 1, 0, 0, 0, 0, 0 ],
+            vec![ 1, 0, 15, 0, 0, 0, 15 ],
+            vec![ 0, 15, 0, 15, 0, 0, 0 ],
+            vec![ 0, 0, 15, 0, 15, 0, 0 ],
+            vec![ 0, 0, 0, 15, 0, 15, 0 ],
+            vec![ 0, 0, 0, 0, 15, 0, 15 ],
+            vec![ 0, 15, 0, 0, 0, 15, 0 ]
+        ])
+    }
+
+    #[test]
+    fn explicit_aromatic() {
+        assert_eq!(adjacency_matrix(&build("C:C"), 4), vec![
+            vec![ 0, 4 ],
+            vec![ 4, 0 ]
+        ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use super::Atom;
+
+/// Returns whether each atom of `atoms` is aromatic, indexed by atom id,
+/// as when rendering the result of `perceive_aromaticity`. Only the
+/// aromatic marking of each atom is reported, so the result for a Kekulé
+/// `atoms` is all false until aromaticity has been perceived.
+///
+/// ```
+/// use purr::graph::{
+///     Builder, AromaticityModel, aromatic_atoms, perceive_aromaticity
+/// };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("CC1=CC=CC=C1", &mut builder, None)?;
+///
+///     let mut atoms = builder.build().expect("atoms");
+///
+///     perceive_aromaticity(&mut atoms, &AromaticityModel::OpenSmiles);
+///
+///     assert_eq!(aromatic_atoms(&atoms), vec![
+///         false, true, true, true, true, true, true
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn aromatic_atoms(atoms: &[Atom]) -> Vec<bool> {
+    atoms.iter().map(Atom::is_aromatic).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::{ AromaticityModel, perceive_aromaticity };
+    use crate::test_support::build;
+    use super::*;
+
+    fn perceived(smiles: &str) -> Vec<bool> {
+        let mut atoms = build(smiles);
+
+        perceive_aromaticity(&mut atoms, &AromaticityModel::OpenSmiles);
+
+        aromatic_atoms(&atoms)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(aromatic_atoms(&[ ]), vec![ ])
+    }
+
+    #[test]
+    fn kekule_not_perceived() {
+        assert_eq!(aromatic_atoms(&build("C1=CC=CC=C1")), vec![ false; 6 ])
+    }
+
+    #[test]
+    fn pyridine() {
+        assert_eq!(perceived("C1=CC=NC=C1"), vec![ true; 6 ])
+    }
+
+    #[test]
+    fn toluene() {
+        assert_eq!(perceived("CC1=CC=CC=C1"), vec![
+            false, true, true, true, true, true, true
+        ])
+    }
+
+    #[test]
+    fn cyclohexene() {
+        assert_eq!(perceived("C1=CCCCC1"), vec![ false; 6 ])
+    }
+
+    #[test]
+    fn aromatic_input() {
+        assert_eq!(perceived("c1ccncc1C"), vec![
+            true, true, true, true, true, true, false
+        ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use super::Atom;
+
+/// Returns the number of rings in `rings` whose atoms are all aromatic.
+/// `rings` is typically the result of `sssr`.
+/// 
+/// # Panics
+/// 
+/// Panics given a ring member outside of `atoms`.
+pub fn aromatic_ring_count(atoms: &[Atom], rings: &[Vec<usize>]) -> usize {
+    rings.iter().filter(|ring| {
+        ring.iter().all(|&id| atoms[id].is_aromatic())
+    }).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::sssr;
+    use crate::test_support::build;
+    use super::*;
+
+    fn count(smiles: &str) -> usize {
+        let atoms = build(smiles);
+
+        aromatic_ring_count(&atoms, &sssr(&atoms))
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(count("c1ccc2ccccc2c1"), 2)
+    }
+
+    #[test]
+    fn decalin() {
+        assert_eq!(count("C1CCC2CCCCC2C1"), 0)
+    }
+
+    #[test]
+    fn tetralin() {
+        assert_eq!(count("c1ccc2CCCCc2c1"), 1)
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(count("c1cc[nH]c1"), 1)
+    }
+}
\ No newline at end of file
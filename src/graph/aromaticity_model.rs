@@ -0,0 +1,15 @@
+/// Rules for counting the pi electrons that ring atoms contribute during
+/// aromaticity perception. The models agree on rings made only of ring
+/// double bonds and lone pairs, such as benzene, pyrrole, and furan.
+#[derive(Debug,PartialEq,Eq,Clone,Default)]
+pub enum AromaticityModel {
+    /// A ring atom with an exocyclic double bond disqualifies its ring,
+    /// unless the double bond leads to an atom perceived as aromatic, as
+    /// in fused rings.
+    #[default]
+    OpenSmiles,
+    /// As `OpenSmiles`, except that an exocyclic double bond to nitrogen,
+    /// oxygen, or sulfur contributes no electrons. Under this model,
+    /// 2-pyridone is aromatic.
+    Daylight
+}
\ No newline at end of file
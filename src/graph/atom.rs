@@ -2,7 +2,7 @@ use crate::feature::AtomKind;
 use super::Bond;
 
 /// Atom used in graph-like (adjacency) SMILES representation.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub struct Atom {
     pub kind: AtomKind,
     pub bonds: Vec<Bond>
@@ -28,10 +28,7 @@ impl Atom {
     /// This value is independent of an atom's aromaticity marking.
     pub fn subvalence(&self) -> u8 {
         let hcount: u8 = match &self.kind {
-            AtomKind::Bracket { hcount, .. } => match hcount {
-                Some(hcount) => hcount.into(),
-                None => 0
-            }
+            AtomKind::Bracket { hcount: Some(hcount), .. } => hcount.into(),
             _ => 0
         };
         let valence = self.bonds.iter().fold(hcount, |sum,bond| {
@@ -69,7 +66,15 @@ impl Atom {
             }
         }
     }
-    
+
+    /// Returns the valence of this Atom, computed as the sum of bond orders
+    /// plus implicit or virtual hydrogens. Elided bonds between aromatic
+    /// atoms contribute an order of one.
+    pub fn valence(&self) -> u8 {
+        self.bonds.iter().fold(self.suppressed_hydrogens(), |sum, bond| {
+            sum + bond.order()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -240,6 +245,56 @@ mod subvalence {
 
         assert_eq!(atom.subvalence(), 1)
     }
+
+    #[test]
+    fn bracket_star_hydrogen_double() {
+        let atom = Atom {
+            kind: AtomKind::Bracket {
+                isotope: None,
+                symbol: BracketSymbol::Star,
+                configuration: None,
+                hcount: Some(VirtualHydrogen::H1),
+                charge: None,
+                map: None
+            },
+            bonds: vec![
+                Bond::new(BondKind::Double, 1)
+            ]
+        };
+
+        assert_eq!(atom.subvalence(), 0)
+    }
+}
+
+#[cfg(test)]
+mod aromatic_targets {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+
+    #[test]
+    fn bracket_selenium() {
+        assert_eq!(build("[se]")[0].kind.targets(), &[ 2, 4, 6 ])
+    }
+
+    #[test]
+    fn phosphorus() {
+        assert_eq!(build("p")[0].kind.targets(), &[ 3, 5 ])
+    }
+
+    #[test]
+    fn bracket_phosphorus() {
+        assert_eq!(build("[p]")[0].kind.targets(), &[ 3, 5 ])
+    }
+
+    #[test]
+    fn selenophene_selenium_divalent() {
+        assert_eq!(build("c1cc[se]c1")[3].subvalence(), 0)
+    }
+
+    #[test]
+    fn phosphinine_phosphorus_trivalent() {
+        assert_eq!(build("c1ccpcc1")[3].subvalence(), 1)
+    }
 }
 
 #[cfg(test)]
@@ -248,8 +303,14 @@ mod suppressed_hydrogens {
     use crate::feature::{
         Aromatic, Aliphatic, BondKind, BracketSymbol, Element, VirtualHydrogen
     };
+    use crate::test_support::build;
     use super::*;
 
+    fn hydrogens(smiles: &str) -> Vec<u8> {
+        build(smiles).iter()
+            .map(|atom| atom.suppressed_hydrogens()).collect()
+    }
+
     #[test]
     fn star() {
         let atom = Atom::new(AtomKind::Star);
@@ -284,6 +345,74 @@ mod suppressed_hydrogens {
         assert_eq!(atom.suppressed_hydrogens(), 1)
     }
 
+    #[test]
+    fn aromatic_boron_two_bonds() {
+        let atom = Atom {
+            kind: AtomKind::Aromatic(Aromatic::B),
+            bonds: vec![
+                Bond::new(BondKind::Elided, 1),
+                Bond::new(BondKind::Elided, 2)
+            ]
+        };
+
+        assert_eq!(atom.suppressed_hydrogens(), 0)
+    }
+
+    #[test]
+    fn borabenzene() {
+        assert_eq!(hydrogens("b1ccccc1"), vec![ 0, 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(hydrogens("c1cc[nH]c1"), vec![ 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn pyridine() {
+        assert_eq!(hydrogens("c1ccncc1"), vec![ 1, 1, 1, 0, 1, 1 ])
+    }
+
+    #[test]
+    fn methylpyrrole() {
+        assert_eq!(hydrogens("Cn1cccc1"), vec![ 3, 0, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn pyridinium() {
+        assert_eq!(hydrogens("c1cc[nH+]cc1"), vec![ 1, 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn methylpyridinium() {
+        assert_eq!(hydrogens("C[n+]1ccccc1"), vec![ 3, 0, 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn phosphinine() {
+        assert_eq!(hydrogens("c1ccpcc1"), vec![ 1, 1, 1, 0, 1, 1 ])
+    }
+
+    #[test]
+    fn phosphole() {
+        assert_eq!(hydrogens("c1cc[pH]c1"), vec![ 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn methylphosphole() {
+        assert_eq!(hydrogens("Cp1cccc1"), vec![ 3, 0, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn selenophene() {
+        assert_eq!(hydrogens("c1cc[se]c1"), vec![ 1, 1, 1, 0, 1 ])
+    }
+
+    #[test]
+    fn selenopyrylium() {
+        assert_eq!(hydrogens("c1cc[se+]cc1"), vec![ 1, 1, 1, 0, 1, 1 ])
+    }
+
     #[test]
     fn aliphatic_subvalence_0() {
         let atom = Atom {
@@ -376,4 +505,141 @@ mod suppressed_hydrogens {
 
         assert_eq!(atom.suppressed_hydrogens(), 1)
     }
+}
+
+#[cfg(test)]
+mod valence {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{
+        Aliphatic, Aromatic, BondKind, BracketSymbol, Element, VirtualHydrogen
+    };
+    use super::*;
+
+    #[test]
+    fn star() {
+        let atom = Atom::new(AtomKind::Star);
+
+        assert_eq!(atom.valence(), 0)
+    }
+
+    #[test]
+    fn star_single_single() {
+        let atom = Atom {
+            kind: AtomKind::Star,
+            bonds: vec![
+                Bond::new(BondKind::Single, 0),
+                Bond::new(BondKind::Single, 2)
+            ]
+        };
+
+        assert_eq!(atom.valence(), 2)
+    }
+
+    #[test]
+    fn nitrogen() {
+        let atom = Atom::new(AtomKind::Aliphatic(Aliphatic::N));
+
+        assert_eq!(atom.valence(), 3)
+    }
+
+    #[test]
+    fn carbon_double() {
+        let atom = Atom {
+            kind: AtomKind::Aliphatic(Aliphatic::C),
+            bonds: vec![
+                Bond::new(BondKind::Double, 1)
+            ]
+        };
+
+        assert_eq!(atom.valence(), 4)
+    }
+
+    #[test]
+    fn aromatic_carbon_elided_elided() {
+        let atom = Atom {
+            kind: AtomKind::Aromatic(Aromatic::C),
+            bonds: vec![
+                Bond::new(BondKind::Elided, 1),
+                Bond::new(BondKind::Elided, 2)
+            ]
+        };
+
+        assert_eq!(atom.valence(), 3)
+    }
+
+    #[test]
+    fn bracket_h2_single() {
+        let atom = Atom {
+            kind: AtomKind::Bracket {
+                isotope: None,
+                symbol: BracketSymbol::Element(Element::O),
+                configuration: None,
+                hcount: Some(VirtualHydrogen::H2),
+                charge: None,
+                map: None
+            },
+            bonds: vec![
+                Bond::new(BondKind::Single, 1)
+            ]
+        };
+
+        assert_eq!(atom.valence(), 3)
+    }
+}
+
+#[cfg(test)]
+mod hash {
+    use std::collections::HashSet;
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ Aliphatic, BondKind };
+    use super::*;
+
+    #[test]
+    fn equal_atoms() {
+        let mut atoms = HashSet::new();
+
+        for _ in 0..2 {
+            atoms.insert(Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ Bond::new(BondKind::Elided, 1) ]
+            });
+        }
+
+        assert_eq!(atoms.len(), 1)
+    }
+
+    #[test]
+    fn unequal_bonds() {
+        let mut atoms = HashSet::new();
+
+        for tid in 0..2 {
+            atoms.insert(Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ Bond::new(BondKind::Elided, tid) ]
+            });
+        }
+
+        assert_eq!(atoms.len(), 2)
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ Aliphatic, BondKind };
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn mutate_clone() {
+        let atoms = build("CO");
+        let mut copy = atoms.clone();
+
+        copy[1].kind = AtomKind::Aliphatic(Aliphatic::N);
+        copy[0].bonds[0].kind = BondKind::Double;
+
+        assert_eq!(atoms[1].kind, AtomKind::Aliphatic(Aliphatic::O));
+        assert_eq!(atoms[0].bonds[0].kind, BondKind::Elided);
+        assert_eq!(copy[1].kind, AtomKind::Aliphatic(Aliphatic::N))
+    }
 }
\ No newline at end of file
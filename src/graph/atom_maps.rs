@@ -0,0 +1,67 @@
+use crate::feature::AtomKind;
+use super::Atom;
+
+/// Returns the index and atom map number of each mapped atom, in index
+/// order.
+/// 
+/// ```
+/// use purr::graph::{ Builder, atom_maps };
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("[CH3:1]C[OH:2]", &mut builder, None)?;
+/// 
+///     assert_eq!(atom_maps(&builder.build().unwrap()), vec![
+///         (0, 1), (2, 2)
+///     ]);
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn atom_maps(atoms: &[Atom]) -> Vec<(usize, u16)> {
+    atoms.iter().enumerate().filter_map(|(id, atom)| match &atom.kind {
+        AtomKind::Bracket { map: Some(map), .. } => Some((id, map.into())),
+        _ => None
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::walk::walk;
+    use crate::write::Writer;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn unmapped() {
+        assert_eq!(atom_maps(&build("CC[OH]")), vec![ ])
+    }
+
+    #[test]
+    fn mapped() {
+        assert_eq!(atom_maps(&build("[CH3:1][OH:2]")), vec![ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn mapped_components() {
+        assert_eq!(
+            atom_maps(&build("[CH3:3]C.[OH2:7]")),
+            vec![ (0, 3), (2, 7) ]
+        )
+    }
+
+    #[test]
+    fn mapped_round_trip() {
+        let mut writer = Writer::new();
+
+        walk(build("[CH3:1][OH:2]"), &mut writer).unwrap();
+
+        let smiles = writer.write();
+
+        assert_eq!(smiles, "[CH3:1][OH:2]");
+        assert_eq!(atom_maps(&build(&smiles)), vec![ (0, 1), (1, 2) ])
+    }
+}
\ No newline at end of file
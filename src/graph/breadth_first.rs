@@ -0,0 +1,86 @@
+use super::Atom;
+
+/// Returns the atoms reachable from `root` in breadth-first order, each as
+/// `(id, parent, distance)`. `parent` is the atom `id` was reached from,
+/// or `root` itself, and `distance` counts bonds from `root`. Atoms marked
+/// in `visited` are neither returned nor passed through, and each atom
+/// returned is marked, so one mask can be shared across components.
+///
+/// # Panics
+///
+/// Panics given `root` or a bond to an atom outside of `atoms`.
+pub(crate) fn breadth_first(
+    atoms: &[Atom], root: usize, visited: &mut [bool]
+) -> Vec<(usize, usize, usize)> {
+    let mut result = vec![ (root, root, 0) ];
+    let mut index = 0;
+
+    visited[root] = true;
+
+    while index < result.len() {
+        let (sid, _, distance) = result[index];
+
+        for bond in atoms[sid].bonds.iter() {
+            if !visited[bond.tid] {
+                visited[bond.tid] = true;
+                result.push((bond.tid, sid, distance + 1))
+            }
+        }
+
+        index += 1
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            breadth_first(&build("C"), 0, &mut [ false ]),
+            vec![ (0, 0, 0) ]
+        )
+    }
+
+    #[test]
+    fn branched() {
+        assert_eq!(
+            breadth_first(&build("CC(C)O"), 0, &mut [ false; 4 ]),
+            vec![ (0, 0, 0), (1, 0, 1), (2, 1, 2), (3, 1, 2) ]
+        )
+    }
+
+    #[test]
+    fn ring() {
+        assert_eq!(
+            breadth_first(&build("C1CCC1"), 1, &mut [ false; 4 ]),
+            vec![ (1, 1, 0), (0, 1, 1), (2, 1, 1), (3, 0, 2) ]
+        )
+    }
+
+    #[test]
+    fn components() {
+        let mut visited = [ false; 3 ];
+
+        assert_eq!(
+            breadth_first(&build("CC.C"), 0, &mut visited),
+            vec![ (0, 0, 0), (1, 0, 1) ]
+        );
+        assert_eq!(visited, [ true, true, false ])
+    }
+
+    #[test]
+    fn visited_blocks() {
+        let mut visited = [ false, false, true, false ];
+
+        assert_eq!(
+            breadth_first(&build("C1CCC1"), 1, &mut visited),
+            vec![ (1, 1, 0), (0, 1, 1), (3, 0, 2) ]
+        )
+    }
+}
\ No newline at end of file
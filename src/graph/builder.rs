@@ -39,7 +39,8 @@ pub struct Builder {
     graph: Vec<Node>,
     opens: HashMap<Rnum, usize>,
     errors: Vec<Error>,
-    rid: usize
+    rid: usize,
+    max_degree: Option<usize>
 }
 
 impl Builder {
@@ -49,7 +50,33 @@ impl Builder {
             graph: Vec::new(),
             opens: HashMap::new(),
             errors: Vec::new(),
-            rid: 0
+            rid: 0,
+            max_degree: None
+        }
+    }
+
+    /// Returns a Builder with room for `atoms` atoms before reallocating.
+    /// When reading a SMILES string, half its length is a reasonable
+    /// estimate, since most atoms are written with one character and most
+    /// are followed by a bond, branch, or ring closure character.
+    pub fn with_capacity(atoms: usize) -> Self {
+        Self {
+            stack: Vec::with_capacity(atoms),
+            graph: Vec::with_capacity(atoms),
+            opens: HashMap::new(),
+            errors: Vec::new(),
+            rid: 0,
+            max_degree: None
+        }
+    }
+
+    /// Returns a Builder that rejects atoms with more than `max` bonds,
+    /// including open ring closures, to catch malformed input early. The
+    /// first such atom is reported by `build` as `Error::TooManyBonds`.
+    pub fn with_max_degree(max: usize) -> Self {
+        Self {
+            max_degree: Some(max),
+            ..Self::new()
         }
     }
 
@@ -80,6 +107,45 @@ impl Builder {
 
         Ok(result)
     }
+
+    /// Bonds atom `sid` to atom `tid` with `bond_kind` as a ring closure,
+    /// without a ring number. Each atom receives the new bond after its
+    /// existing ones, as if the closure digit were written at this point.
+    /// Errors are reported by `build`: `Error::Loop` when `sid` and `tid`
+    /// match, `Error::UnknownTarget` given an id not yet added, and
+    /// `Error::DuplicateBond` when the atoms are already bonded.
+    pub fn close_ring(&mut self, sid: usize, tid: usize, bond_kind: BondKind) {
+        let size = self.graph.len();
+
+        if sid == tid {
+            return self.errors.push(Error::Loop(sid))
+        } else if sid >= size || tid >= size {
+            return self.errors.push(Error::UnknownTarget(sid, tid))
+        } else if self.graph[sid].edges.iter().any(|edge| {
+            edge.target == Target::Id(tid)
+        }) {
+            return self.errors.push(Error::DuplicateBond(sid, tid))
+        }
+
+        self.graph[tid].add_edge(bond_kind.reverse(), Target::Id(sid));
+        self.graph[sid].add_edge(bond_kind, Target::Id(tid));
+        self.check_degree(sid);
+        self.check_degree(tid)
+    }
+
+    fn check_degree(&mut self, id: usize) {
+        if let Some(max) = self.max_degree {
+            if self.graph[id].edges.len() == max + 1 {
+                self.errors.push(Error::TooManyBonds(id))
+            }
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Follower for Builder {
@@ -98,7 +164,8 @@ impl Follower for Builder {
 
         self.stack.push(self.graph.len());
         self.graph.push(Node::child(reverse, atom_kind));
-        self.graph[sid].edges.push(forward)
+        self.graph[sid].edges.push(forward);
+        self.check_degree(sid)
     }
 
     fn join(&mut self, bond_kind: BondKind, rnum: Rnum) {
@@ -106,6 +173,13 @@ impl Follower for Builder {
             Entry::Occupied(occupied) => {
                 let sid = *self.stack.last().expect("last on stack");
                 let (rnum, tid) = occupied.remove_entry();
+
+                if sid == tid {
+                    self.rid += 1;
+
+                    return self.errors.push(Error::Loop(sid))
+                }
+
                 let edge = self.graph[tid].edges.iter_mut().find(|edge| {
                     if let Target::Rnum(_, _, test) = &edge.target {
                         test == &rnum
@@ -119,7 +193,8 @@ impl Follower for Builder {
                         edge.target = Target::Id(sid);
                         edge.kind = left;
 
-                        self.graph[sid].add_edge(right, Target::Id(tid))
+                        self.graph[sid].add_edge(right, Target::Id(tid));
+                        self.check_degree(sid)
                     },
                     None => self.errors.push(Error::Join(sid, tid))
                 }
@@ -130,6 +205,7 @@ impl Follower for Builder {
 
                 vacant.insert(sid);
                 self.graph[sid].add_edge(bond_kind, Target::Rnum(self.rid, sid, rnum));
+                self.check_degree(sid)
             }
         }
 
@@ -222,6 +298,166 @@ mod errors {
 
         assert_eq!(builder.build(), Err(Error::Rnum(2)))
     }
+
+    #[test]
+    fn close_ring_loop() {
+        let mut builder = Builder::new();
+
+        builder.root(AtomKind::Star);
+        builder.close_ring(0, 0, BondKind::Elided);
+
+        assert_eq!(builder.build(), Err(Error::Loop(0)))
+    }
+
+    #[test]
+    fn close_ring_unknown_target() {
+        let mut builder = Builder::new();
+
+        builder.root(AtomKind::Star);
+        builder.close_ring(0, 1, BondKind::Elided);
+
+        assert_eq!(builder.build(), Err(Error::UnknownTarget(0, 1)))
+    }
+
+    #[test]
+    fn close_ring_duplicate() {
+        let mut builder = Builder::new();
+
+        builder.root(AtomKind::Star);
+        builder.extend(BondKind::Elided, AtomKind::Star);
+        builder.close_ring(1, 0, BondKind::Elided);
+
+        assert_eq!(builder.build(), Err(Error::DuplicateBond(1, 0)))
+    }
+
+    #[test]
+    fn join_conflicting_orders() {
+        let mut builder = Builder::new();
+
+        crate::read::read("C=1CCCCC#1", &mut builder, None).unwrap();
+
+        assert_eq!(builder.build(), Err(Error::Join(5, 0)))
+    }
+
+    #[test]
+    fn join_conflicting_directions() {
+        let mut builder = Builder::new();
+
+        crate::read::read("C/1=C/CCCC/1", &mut builder, None).unwrap();
+
+        assert_eq!(builder.build(), Err(Error::Join(5, 0)))
+    }
+
+    #[test]
+    fn join_one_end_elided() {
+        let mut builder = Builder::new();
+
+        crate::read::read("C=1CCCCC1", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Double, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Double, 0))
+    }
+
+    #[test]
+    fn join_aromatic_both_ends() {
+        let mut builder = Builder::new();
+
+        crate::read::read("c:1ccccc:1", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Aromatic, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Aromatic, 0))
+    }
+
+    #[test]
+    fn join_aromatic_explicit_single() {
+        let mut builder = Builder::new();
+
+        crate::read::read("c-1ccccc1", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Single, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Single, 0))
+    }
+
+    #[test]
+    fn join_aromatic_one_end_elided() {
+        let mut builder = Builder::new();
+
+        crate::read::read("c1ccccc:1", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Aromatic, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Aromatic, 0))
+    }
+
+    #[test]
+    fn join_reopened_on_same_atom() {
+        let mut builder = Builder::new();
+
+        crate::read::read("C11CC", &mut builder, None).unwrap();
+
+        assert_eq!(builder.build(), Err(Error::Loop(0)))
+    }
+
+    #[test]
+    fn join_reused_after_close() {
+        let mut builder = Builder::new();
+
+        crate::read::read("C1CC1C1CC1", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[2].bonds[1], Bond::new(BondKind::Elided, 0));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Elided, 3))
+    }
+
+    #[test]
+    fn max_degree_extend() {
+        let mut builder = Builder::with_max_degree(8);
+
+        crate::read::read(
+            "C(C)(C)(C)(C)(C)(C)(C)(C)C", &mut builder, None
+        ).unwrap();
+
+        assert_eq!(builder.build(), Err(Error::TooManyBonds(0)))
+    }
+
+    #[test]
+    fn max_degree_join() {
+        let mut builder = Builder::with_max_degree(2);
+
+        crate::read::read("C12CC1C2", &mut builder, None).unwrap();
+
+        assert_eq!(builder.build(), Err(Error::TooManyBonds(0)))
+    }
+
+    #[test]
+    fn max_degree_close_ring() {
+        let mut builder = Builder::with_max_degree(1);
+
+        crate::read::read("C.C.C", &mut builder, None).unwrap();
+        builder.close_ring(0, 1, BondKind::Elided);
+        builder.close_ring(2, 0, BondKind::Elided);
+
+        assert_eq!(builder.build(), Err(Error::TooManyBonds(0)))
+    }
+
+    #[test]
+    fn max_degree_at_limit() {
+        let mut builder = Builder::with_max_degree(8);
+
+        crate::read::read(
+            "C(C)(C)(C)(C)(C)(C)(C)C", &mut builder, None
+        ).unwrap();
+
+        assert_eq!(builder.build().unwrap()[0].bonds.len(), 8)
+    }
 }
 
 #[cfg(test)]
@@ -230,6 +466,85 @@ mod build {
     use crate::feature::{ BracketSymbol, VirtualHydrogen, Configuration };
     use super::*;
 
+    #[test]
+    fn with_capacity() {
+        let mut builder = Builder::with_capacity(8);
+        let mut expected = Builder::new();
+
+        crate::read::read("CC(=O)O", &mut builder, None).unwrap();
+        crate::read::read("CC(=O)O", &mut expected, None).unwrap();
+
+        assert_eq!(builder.build(), expected.build())
+    }
+
+    #[test]
+    fn c3_close_ring() {
+        let mut builder = Builder::new();
+
+        builder.root(AtomKind::Star);
+        builder.extend(BondKind::Elided, AtomKind::Star);
+        builder.extend(BondKind::Elided, AtomKind::Star);
+        builder.close_ring(2, 0, BondKind::Elided);
+
+        assert_eq!(builder.build(), Ok(vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1),
+                    Bond::new(BondKind::Elided, 2)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0),
+                    Bond::new(BondKind::Elided, 2)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1),
+                    Bond::new(BondKind::Elided, 0)
+                ]
+            }
+        ]))
+    }
+
+    #[test]
+    fn c3_close_ring_double() {
+        let mut builder = Builder::new();
+
+        builder.root(AtomKind::Star);
+        builder.extend(BondKind::Elided, AtomKind::Star);
+        builder.extend(BondKind::Elided, AtomKind::Star);
+        builder.close_ring(2, 0, BondKind::Double);
+
+        assert_eq!(builder.build(), Ok(vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1),
+                    Bond::new(BondKind::Double, 2)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0),
+                    Bond::new(BondKind::Elided, 2)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1),
+                    Bond::new(BondKind::Double, 0)
+                ]
+            }
+        ]))
+    }
+
     #[test]
     fn p1() {
         let mut builder = Builder::new();
@@ -0,0 +1,275 @@
+use crate::feature::AtomKind;
+use super::Atom;
+
+/// Returns a canonical rank for each atom. Ranks form a permutation of
+/// `0..atoms.len()` that depends only on the graph, not the order in which
+/// its atoms and bonds were given (up to symmetry).
+/// 
+/// Ranks are seeded from atomic invariants (symbol, aromaticity, degree,
+/// hydrogen count, charge, isotope) and refined by neighbor ranks and bond
+/// orders. When `maps` is true, atom map numbers break any remaining ties
+/// before symmetric atoms are split by index.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn canonical_ranks(atoms: &[Atom], maps: bool) -> Vec<usize> {
+    let mut ranks = symmetry_classes(atoms, maps);
+
+    loop {
+        let tied = match first_tie(&ranks) {
+            Some(tied) => tied,
+            None => break ranks
+        };
+        let chosen = ranks.iter().position(|rank| *rank == tied)
+            .expect("tied rank");
+
+        ranks = split(&ranks, chosen);
+
+        refine(atoms, &mut ranks)
+    }
+}
+
+/// Returns each ranking that `canonical_ranks` would give if every tie
+/// could be broken at any of its tied atoms, not just the first. Symmetric
+/// atoms can still differ in stereochemistry, so callers compare the
+/// results to pick one independent of input order. Of twin atoms, bonded
+/// alike to the same unconfigured neighbors, only the first is tried.
+pub(crate) fn tie_breaks(atoms: &[Atom], maps: bool) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+
+    branch(atoms, symmetry_classes(atoms, maps), &mut result);
+
+    result
+}
+
+/// Returns the symmetry class of each atom: canonical ranks before ties
+/// are broken, so symmetry-equivalent atoms share a class.
+pub(crate) fn symmetry_classes(atoms: &[Atom], maps: bool) -> Vec<usize> {
+    let mut ranks = dense(&atoms.iter().map(invariant).collect::<Vec<_>>());
+
+    refine(atoms, &mut ranks);
+
+    if maps {
+        let keys = atoms.iter().zip(ranks.iter()).map(|(atom, rank)| {
+            (*rank, map(atom))
+        }).collect::<Vec<_>>();
+
+        ranks = dense(&keys);
+
+        refine(atoms, &mut ranks)
+    }
+
+    ranks
+}
+
+fn branch(atoms: &[Atom], ranks: Vec<usize>, result: &mut Vec<Vec<usize>>) {
+    let tied = match first_tie(&ranks) {
+        Some(tied) => tied,
+        None => return result.push(ranks)
+    };
+    let mut tried = Vec::new();
+
+    for chosen in (0..atoms.len()).filter(|&id| ranks[id] == tied) {
+        if tried.iter().any(|&other| twins(atoms, chosen, other)) {
+            continue
+        }
+
+        let mut next = split(&ranks, chosen);
+
+        refine(atoms, &mut next);
+        branch(atoms, next, result);
+        tried.push(chosen)
+    }
+}
+
+fn first_tie(ranks: &[usize]) -> Option<usize> {
+    let mut counts = vec![ 0; ranks.len() ];
+
+    for rank in ranks.iter() {
+        counts[*rank] += 1
+    }
+
+    counts.iter().position(|count| *count > 1)
+}
+
+// Gives `chosen` a lower rank than the atoms it was tied with.
+fn split(ranks: &[usize], chosen: usize) -> Vec<usize> {
+    let keys = ranks.iter().enumerate().map(|(id, rank)| {
+        (*rank, id != chosen)
+    }).collect::<Vec<_>>();
+
+    dense(&keys)
+}
+
+// Swapping twins maps the graph onto itself, stereochemistry included, so
+// either gives the same ranking up to that swap.
+fn twins(atoms: &[Atom], left: usize, right: usize) -> bool {
+    let bonds = |id: usize| {
+        let mut result = atoms[id].bonds.iter()
+            .map(|bond| (bond.tid, &bond.kind)).collect::<Vec<_>>();
+
+        result.sort_unstable_by_key(|(tid, _)| *tid);
+
+        result
+    };
+    let configured = |id: usize| matches!(
+        atoms[id].kind, AtomKind::Bracket { configuration: Some(_), .. }
+    );
+
+    atoms[left].kind == atoms[right].kind
+        && bonds(left) == bonds(right)
+        && !configured(left)
+        && !configured(right)
+        && atoms[left].bonds.iter().all(|bond| !configured(bond.tid))
+}
+
+fn refine(atoms: &[Atom], ranks: &mut Vec<usize>) {
+    let mut count = classes(ranks);
+
+    loop {
+        let keys = atoms.iter().enumerate().map(|(id, atom)| {
+            let mut neighbors = atom.bonds.iter().map(|bond| {
+                (ranks[bond.tid], bond.order())
+            }).collect::<Vec<_>>();
+
+            neighbors.sort_unstable();
+
+            (ranks[id], neighbors)
+        }).collect::<Vec<_>>();
+        let next = dense(&keys);
+        let next_count = classes(&next);
+
+        *ranks = next;
+
+        if next_count == count {
+            break
+        }
+
+        count = next_count
+    }
+}
+
+fn classes(ranks: &[usize]) -> usize {
+    ranks.iter().max().map_or(0, |max| max + 1)
+}
+
+fn dense<T: Ord>(keys: &[T]) -> Vec<usize> {
+    let mut order = (0..keys.len()).collect::<Vec<_>>();
+    let mut result = vec![ 0; keys.len() ];
+    let mut rank = 0;
+
+    order.sort_by(|&left, &right| keys[left].cmp(&keys[right]));
+
+    for (index, id) in order.iter().enumerate() {
+        if index > 0 && keys[*id] != keys[order[index - 1]] {
+            rank += 1
+        }
+
+        result[*id] = rank
+    }
+
+    result
+}
+
+fn invariant(atom: &Atom) -> (String, bool, usize, u8, i8, u16) {
+    let (isotope, charge) = match &atom.kind {
+        AtomKind::Bracket { isotope, charge, .. } => (
+            isotope.as_ref().map_or(0, |isotope| isotope.into()),
+            charge.as_ref().map_or(0, |charge| charge.into())
+        ),
+        _ => (0, 0)
+    };
+
+    (
+        atom.kind.symbol(),
+        atom.is_aromatic(),
+        atom.bonds.len(),
+        atom.suppressed_hydrogens(),
+        charge,
+        isotope
+    )
+}
+
+fn map(atom: &Atom) -> Option<u16> {
+    match &atom.kind {
+        AtomKind::Bracket { map, .. } => map.as_ref().map(|map| map.into()),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn ranks(smiles: &str, maps: bool) -> Vec<usize> {
+        canonical_ranks(&build(smiles), maps)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(canonical_ranks(&[ ], false), vec![ ])
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(ranks("CCO", false), vec![ 0, 1, 2 ])
+    }
+
+    #[test]
+    fn ethanol_reversed() {
+        assert_eq!(ranks("OCC", false), vec![ 2, 1, 0 ])
+    }
+
+    #[test]
+    fn propane() {
+        assert_eq!(ranks("CCC", false), vec![ 0, 2, 1 ])
+    }
+
+    #[test]
+    fn isobutane_permutation() {
+        let mut ranks = ranks("CC(C)C", false);
+
+        ranks.sort_unstable();
+
+        assert_eq!(ranks, vec![ 0, 1, 2, 3 ])
+    }
+
+    #[test]
+    fn symmetric_maps_ignored() {
+        assert_eq!(ranks("[CH3:2]C[CH3:1]", false), vec![ 0, 2, 1 ])
+    }
+
+    #[test]
+    fn symmetric_maps() {
+        assert_eq!(ranks("[CH3:2]C[CH3:1]", true), vec![ 1, 2, 0 ])
+    }
+
+    #[test]
+    fn unmapped_before_mapped() {
+        assert_eq!(ranks("[CH3:1]CC", true), vec![ 1, 2, 0 ])
+    }
+
+    #[test]
+    fn input_order_independent() {
+        let left = ranks("OC(N)C", false);
+        let right = ranks("NC(C)O", false);
+
+        assert_eq!(left[0], right[3]);
+        assert_eq!(left[1], right[1]);
+        assert_eq!(left[2], right[0]);
+        assert_eq!(left[3], right[2])
+    }
+
+    #[test]
+    fn tie_breaks_twins_once() {
+        assert_eq!(tie_breaks(&build("CC(C)C"), true).len(), 1)
+    }
+
+    #[test]
+    fn tie_breaks_ring() {
+        assert_eq!(tie_breaks(&build("C1CC1"), true).len(), 6)
+    }
+}
\ No newline at end of file
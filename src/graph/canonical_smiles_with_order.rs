@@ -0,0 +1,163 @@
+use crate::feature::AtomKind;
+use crate::walk::{ Error, walk_from_ordered };
+use crate::write::Writer;
+use super::{
+    Atom, canonical_ranks, component_roots, relabel, tie_breaks
+};
+
+/// Returns the canonical SMILES string of `atoms`, as given by
+/// `write::canonical`, together with the ids of `atoms` in the order their
+/// atoms appear in the string. The order is a permutation of
+/// `0..atoms.len()`, so callers can map each written atom back to their
+/// own data.
+///
+/// ```
+/// use purr::graph::{ Builder, canonical_smiles_with_order };
+/// use purr::read::read;
+///
+/// fn main() {
+///     let mut builder = Builder::new();
+///
+///     read("OCC", &mut builder, None).expect("read");
+///
+///     let atoms = builder.build().expect("atoms");
+///     let (smiles, order) = canonical_smiles_with_order(&atoms)
+///         .expect("smiles");
+///
+///     assert_eq!(smiles, "CCO");
+///     assert_eq!(order, vec![ 2, 1, 0 ])
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn canonical_smiles_with_order(
+    atoms: &[Atom]
+) -> Result<(String, Vec<usize>), Error> {
+    write(atoms, &canonical_order(atoms)?)
+}
+
+/// Returns the ranks by which `canonical_smiles_with_order` writes
+/// `atoms`. Without stereochemistry, these are `canonical_ranks`.
+/// Otherwise, ties between symmetric atoms are broken every possible way,
+/// and the ranks giving the smallest string are kept, so that symmetric
+/// stereocenters are written the same way for any input order.
+pub(crate) fn canonical_order(atoms: &[Atom]) -> Result<Vec<usize>, Error> {
+    if !atoms.iter().any(has_stereo) {
+        return Ok(canonical_ranks(atoms, true))
+    }
+
+    let mut result: Option<(String, Vec<usize>)> = None;
+
+    for ranks in tie_breaks(atoms, true) {
+        let (smiles, _) = write(atoms, &ranks)?;
+
+        let smaller = match &result {
+            Some((best, _)) => smiles < *best,
+            None => true
+        };
+
+        if smaller {
+            result = Some((smiles, ranks))
+        }
+    }
+
+    Ok(result.expect("ranks").1)
+}
+
+fn write(
+    atoms: &[Atom], ranks: &[usize]
+) -> Result<(String, Vec<usize>), Error> {
+    let mut ids = vec![ 0; atoms.len() ];
+
+    for (id, &rank) in ranks.iter().enumerate() {
+        ids[rank] = id
+    }
+
+    let atoms = relabel(atoms.to_vec(), ranks);
+    let roots = component_roots(&atoms);
+    let mut writer = Writer::new();
+    let order = walk_from_ordered(atoms, &roots, &mut writer)?;
+
+    Ok((writer.write(), order.into_iter().map(|rank| ids[rank]).collect()))
+}
+
+fn has_stereo(atom: &Atom) -> bool {
+    matches!(atom.kind, AtomKind::Bracket { configuration: Some(_), .. })
+        || atom.bonds.iter().any(|bond| bond.is_directional())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::write::canonical;
+    use crate::test_support::{ build, chirality };
+    use super::*;
+
+    fn is_permutation(order: &[usize]) -> bool {
+        let mut sorted = order.to_vec();
+
+        sorted.sort_unstable();
+
+        sorted == (0..order.len()).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            canonical_smiles_with_order(&[ ]),
+            Ok((String::new(), vec![ ]))
+        )
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(
+            canonical_smiles_with_order(&build("OCC")),
+            Ok(("CCO".to_string(), vec![ 2, 1, 0 ]))
+        )
+    }
+
+    #[test]
+    fn matches_canonical() {
+        for smiles in &[ "OC(=O)c1ccccc1", "C1CC1.[Na+].O", "N[C@@H](C)C=O" ] {
+            let (output, order) =
+                canonical_smiles_with_order(&build(smiles)).unwrap();
+
+            assert_eq!(output, canonical(smiles).unwrap());
+            assert!(is_permutation(&order))
+        }
+    }
+
+    #[test]
+    fn order_maps_atoms() {
+        let atoms = build("[Na+].[O-]C(=O)C[13CH3]");
+        let (output, order) = canonical_smiles_with_order(&atoms).unwrap();
+        let symbols = order.iter()
+            .map(|&id| atoms[id].kind.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(output, "[13CH3]CC([O-])=O.[Na+]");
+        assert_eq!(order, vec![ 5, 4, 2, 1, 3, 0 ]);
+        assert_eq!(symbols, vec![ "[13CH3]", "C", "C", "[O-]", "O", "[Na+]" ])
+    }
+
+    #[test]
+    fn chirality_kept() {
+        let tests = vec![
+            "[F:1][C@:2]([Cl:3])([CH3:4])[CH2:5][CH3:6]",
+            "[F:1][C@:2]1([Cl:3])[CH2:4][CH2:5][NH:6][CH2:7]1",
+            "[OH:1][CH2:2][C@@H:3]1[CH2:4][CH2:5][C@H:6]([CH3:7])[O:8]1"
+        ];
+
+        for smiles in tests {
+            let (output, _) =
+                canonical_smiles_with_order(&build(smiles)).unwrap();
+
+            assert_eq!(
+                chirality(&build(&output)), chirality(&build(smiles)),
+                "{} -> {}", smiles, output
+            )
+        }
+    }
+}
\ No newline at end of file
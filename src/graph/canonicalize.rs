@@ -0,0 +1,185 @@
+use crate::feature::BondKind;
+use super::{ Atom, canonical_order, canonical_ranks, reorder_bonds };
+
+/// Returns `atoms` reordered by canonical rank, with each atom's bonds
+/// sorted by the canonical rank of their targets. Configurations are
+/// updated to match the new neighbor order, so walking the result gives
+/// the same string for any input order of an equivalent graph. Atom maps
+/// take part in ranking.
+///
+/// Directional bonds are flipped as a set wherever they meet through
+/// double bonds, so that the lowest-ranked bond of each set points up from
+/// its lower-ranked atom. Double bond geometry is unchanged.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn canonicalize(atoms: Vec<Atom>) -> Vec<Atom> {
+    let ranks = canonical_order(&atoms)
+        .unwrap_or_else(|_| canonical_ranks(&atoms, true));
+
+    relabel(atoms, &ranks)
+}
+
+/// Returns `atoms` reordered so that each atom's id is its rank, with
+/// bonds sorted by the rank of their targets, as in `canonicalize`.
+pub(crate) fn relabel(atoms: Vec<Atom>, ranks: &[usize]) -> Vec<Atom> {
+    let mut result = atoms.iter().map(|_| None).collect::<Vec<_>>();
+
+    for (id, mut atom) in atoms.into_iter().enumerate() {
+        let mut order = (0..atom.bonds.len()).collect::<Vec<_>>();
+
+        order.sort_by_key(|&index| ranks[atom.bonds[index].tid]);
+        reorder_bonds(&mut atom, &order);
+
+        for bond in atom.bonds.iter_mut() {
+            bond.tid = ranks[bond.tid]
+        }
+
+        result[ranks[id]] = Some(atom);
+    }
+
+    let mut result = result.into_iter()
+        .map(|atom| atom.expect("atom")).collect::<Vec<_>>();
+
+    normalize_directions(&mut result);
+
+    result
+}
+
+// Flipping every directional bond around a double bond preserves its
+// geometry, so directional bonds joined through double bonds are flipped
+// together.
+fn normalize_directions(atoms: &mut [Atom]) {
+    let mut parents = (0..atoms.len()).collect::<Vec<_>>();
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            if bond.is_directional() || bond.kind == BondKind::Double {
+                let left = root(&parents, sid);
+                let right = root(&parents, bond.tid);
+
+                parents[left.max(right)] = left.min(right)
+            }
+        }
+    }
+
+    let mut flips = vec![ None; atoms.len() ];
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            if bond.is_directional() && sid < bond.tid {
+                flips[root(&parents, sid)]
+                    .get_or_insert(bond.kind == BondKind::Down);
+            }
+        }
+    }
+
+    for (sid, atom) in atoms.iter_mut().enumerate() {
+        if flips[root(&parents, sid)] != Some(true) {
+            continue
+        }
+
+        for bond in atom.bonds.iter_mut() {
+            if bond.is_directional() {
+                bond.kind = bond.kind.reverse()
+            }
+        }
+    }
+}
+
+fn root(parents: &[usize], mut id: usize) -> usize {
+    while parents[id] != id {
+        id = parents[id]
+    }
+
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(canonicalize(vec![ ]), vec![ ])
+    }
+
+    #[test]
+    fn atom_order_independent() {
+        assert_eq!(canonicalize(build("OCC")), canonicalize(build("CCO")))
+    }
+
+    #[test]
+    fn bond_targets_sorted() {
+        let atoms = canonicalize(build("C(O)(N)C"));
+
+        for atom in atoms.iter() {
+            let targets = atom.bonds.iter().map(|bond| bond.tid)
+                .collect::<Vec<_>>();
+            let mut sorted = targets.clone();
+
+            sorted.sort_unstable();
+
+            assert_eq!(targets, sorted)
+        }
+    }
+
+    #[test]
+    fn tetrahedral_neighbor_order_independent() {
+        assert_eq!(
+            canonicalize(build("[C@](F)(Cl)(Br)I")),
+            canonicalize(build("F[C@](Cl)(Br)I"))
+        )
+    }
+
+    #[test]
+    fn symmetric_stereocenters_independent() {
+        assert_eq!(
+            canonicalize(build("C[C@H](CC[C@@H](CC1)O)1")),
+            canonicalize(build("C([C@H](C)CC[C@@H](C1)O)1"))
+        )
+    }
+
+    #[test]
+    fn tetrahedral_enantiomers_differ() {
+        assert_ne!(
+            canonicalize(build("[C@](F)(Cl)(Br)I")),
+            canonicalize(build("[C@@](F)(Cl)(Br)I"))
+        )
+    }
+
+    #[test]
+    fn tetrahedral_hydrogen_neighbor_order_independent() {
+        assert_eq!(
+            canonicalize(build("N[C@@H](C)C(=O)O")),
+            canonicalize(build("C[C@H](N)C(=O)O"))
+        )
+    }
+
+    #[test]
+    fn directional_flipped_together() {
+        assert_eq!(
+            canonicalize(build("F/C=C/F")),
+            canonicalize(build("F\\C=C\\F"))
+        );
+        assert_eq!(
+            canonicalize(build("F/C=C\\F")),
+            canonicalize(build("F\\C=C/F"))
+        );
+        assert_ne!(
+            canonicalize(build("F/C=C/F")),
+            canonicalize(build("F/C=C\\F"))
+        )
+    }
+
+    #[test]
+    fn directional_conjugated() {
+        assert_eq!(
+            canonicalize(build("C/C=C/C=C/C")),
+            canonicalize(build("C\\C=C\\C=C\\C"))
+        )
+    }
+}
\ No newline at end of file
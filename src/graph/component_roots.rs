@@ -0,0 +1,65 @@
+use std::cmp::Reverse;
+
+use super::{ Atom, breadth_first, canonical_ranks };
+
+/// Returns one root per connected component, suitable for
+/// `walk::walk_from`. Each root is the component's lowest canonically
+/// ranked atom. Components are ordered by decreasing size, then by the
+/// canonical rank of their roots, so the order depends only on the graph.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn component_roots(atoms: &[Atom]) -> Vec<usize> {
+    let ranks = canonical_ranks(atoms, true);
+    let mut visited = vec![ false; atoms.len() ];
+    let mut components = Vec::new();
+
+    for start in 0..atoms.len() {
+        if visited[start] {
+            continue
+        }
+
+        let component = breadth_first(atoms, start, &mut visited);
+        let root = component.iter().map(|&(id, _, _)| id)
+            .min_by_key(|&id| ranks[id]).expect("root");
+
+        components.push((Reverse(component.len()), ranks[root], root))
+    }
+
+    components.sort_unstable();
+
+    components.into_iter().map(|(_, _, root)| root).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn roots(smiles: &str) -> Vec<usize> {
+        component_roots(&build(smiles))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(component_roots(&[ ]), vec![ ])
+    }
+
+    #[test]
+    fn one_component() {
+        assert_eq!(roots("OCC"), vec![ 2 ])
+    }
+
+    #[test]
+    fn larger_first() {
+        assert_eq!(roots("O.CCO"), vec![ 1, 0 ])
+    }
+
+    #[test]
+    fn same_size_by_rank() {
+        assert_eq!(roots("[Na+].[Cl-]"), vec![ 1, 0 ]);
+        assert_eq!(roots("[Cl-].[Na+]"), vec![ 0, 1 ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use super::Atom;
+
+/// Returns the degree (number of bonds) of every atom in non-increasing
+/// order. Implicit and virtual hydrogens are not counted.
+pub fn degree_sequence(atoms: &[Atom]) -> Vec<usize> {
+    let mut result = atoms.iter().map(|atom| atom.bonds.len())
+        .collect::<Vec<_>>();
+
+    result.sort_unstable_by(|left, right| right.cmp(left));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn methane() {
+        assert_eq!(degree_sequence(&build("C")), vec![ 0 ])
+    }
+
+    #[test]
+    fn propane() {
+        assert_eq!(degree_sequence(&build("CCC")), vec![ 2, 1, 1 ])
+    }
+
+    #[test]
+    fn isobutane() {
+        assert_eq!(degree_sequence(&build("CC(C)C")), vec![ 3, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn cyclopropane_methanol() {
+        assert_eq!(
+            degree_sequence(&build("C1CC1.CO")),
+            vec![ 2, 2, 2, 1, 1 ]
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use crate::feature::Element;
+use super::Atom;
+
+/// Returns the number of atoms of each element in `atoms`, ordered by
+/// atomic number. Implicit and virtual hydrogens are counted. Stars
+/// contribute nothing.
+/// 
+/// ```
+/// use purr::graph::{ Builder, element_counts };
+/// use purr::feature::Element;
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("CC(=O)O", &mut builder, None)?;
+/// 
+///     let counts = element_counts(&builder.build().unwrap());
+/// 
+///     assert_eq!(counts.get(&Element::C), Some(&2));
+///     assert_eq!(counts.get(&Element::H), Some(&4));
+///     assert_eq!(counts.get(&Element::O), Some(&2));
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn element_counts(atoms: &[Atom]) -> BTreeMap<Element, usize> {
+    let mut result = BTreeMap::new();
+
+    for atom in atoms {
+        let hydrogens = atom.suppressed_hydrogens() as usize;
+
+        if hydrogens > 0 {
+            *result.entry(Element::H).or_insert(0) += hydrogens
+        }
+
+        if let Some(element) = atom.kind.element() {
+            *result.entry(element).or_insert(0) += 1
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(element_counts(&[ ]), BTreeMap::new())
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(
+            element_counts(&build("CCO")).into_iter().collect::<Vec<_>>(),
+            vec![ (Element::H, 6), (Element::C, 2), (Element::O, 1) ]
+        )
+    }
+
+    #[test]
+    fn explicit_and_virtual_hydrogen() {
+        assert_eq!(
+            element_counts(&build("[H][CH3]")).into_iter().collect::<Vec<_>>(),
+            vec![ (Element::H, 4), (Element::C, 1) ]
+        )
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(
+            element_counts(&build("*O")).into_iter().collect::<Vec<_>>(),
+            vec![ (Element::H, 1), (Element::O, 1) ]
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,289 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::mem;
+
+use crate::feature::{
+    AtomKind, BondKind, BracketSymbol, Element, VirtualHydrogen
+};
+use super::{ Atom, canonicalize, to_smiles };
+
+/// Returns up to `max` tautomers of `atoms`, starting with `atoms` itself.
+/// Tautomers are found breadth-first by 1,3 proton shifts, in which a
+/// hydrogen moves from X to Z across X-Y=Z to give X=Y-Z. Duplicates are
+/// recognized by canonical string.
+///
+/// The rule set is deliberately small, so the result is not exhaustive:
+///
+/// - Y is carbon, and X and Z are carbon, nitrogen, or oxygen, with at
+///   least one of them nitrogen or oxygen. This covers keto-enol and
+///   amide-imidic acid tautomerism, among others.
+/// - X-Y is single or elided, Y=Z is double, and X bears no double bond,
+///   so that Y never becomes cumulated.
+/// - None of the three atoms is aromatic or charged, X has no
+///   configuration, and none of them bears a directional bond.
+///
+/// Shifted atoms are debracketed where possible.
+///
+/// ```
+/// use purr::graph::{ Builder, enumerate_tautomers, to_smiles };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("CC=O", &mut builder, None)?;
+///
+///     let atoms = builder.build().expect("atoms");
+///     let tautomers = enumerate_tautomers(&atoms, 8).iter()
+///         .map(|atoms| to_smiles(atoms).expect("smiles"))
+///         .collect::<Vec<_>>();
+///
+///     assert_eq!(tautomers, vec![ "CC=O", "C=CO" ]);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn enumerate_tautomers(atoms: &[Atom], max: usize) -> Vec<Vec<Atom>> {
+    let mut result = Vec::new();
+    let mut seen = HashSet::new();
+    let mut index = 0;
+
+    if max == 0 {
+        return result
+    }
+
+    seen.insert(canonical(atoms));
+    result.push(atoms.to_vec());
+
+    while index < result.len() {
+        for (xid, yid, zid) in triads(&result[index]) {
+            if result.len() == max {
+                return result
+            }
+
+            let tautomer = shift(&result[index], xid, yid, zid);
+
+            if seen.insert(canonical(&tautomer)) {
+                result.push(tautomer)
+            }
+        }
+
+        index += 1
+    }
+
+    result
+}
+
+fn triads(atoms: &[Atom]) -> Vec<(usize, usize, usize)> {
+    let mut result = Vec::new();
+
+    for (xid, x) in atoms.iter().enumerate() {
+        if !is_donor(x) ||
+            x.bonds.iter().any(|bond| bond.kind == BondKind::Double) {
+            continue
+        }
+
+        for xy in x.bonds.iter() {
+            let y = &atoms[xy.tid];
+
+            if !is_single(&xy.kind) || !is_bridge(y) {
+                continue
+            }
+
+            for yz in y.bonds.iter() {
+                let z = &atoms[yz.tid];
+
+                if yz.tid == xid || yz.kind != BondKind::Double ||
+                    !is_acceptor(z) {
+                    continue
+                }
+
+                if is_hetero(x) || is_hetero(z) {
+                    result.push((xid, xy.tid, yz.tid))
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn is_donor(atom: &Atom) -> bool {
+    if let AtomKind::Bracket { configuration: Some(_), .. } = atom.kind {
+        return false
+    }
+
+    is_acceptor(atom) && atom.suppressed_hydrogens() > 0
+}
+
+fn is_acceptor(atom: &Atom) -> bool {
+    is_plain(atom) && matches!(
+        atom.kind.element(), Some(Element::C) | Some(Element::N) |
+        Some(Element::O)
+    )
+}
+
+fn is_bridge(atom: &Atom) -> bool {
+    is_plain(atom) && atom.kind.element() == Some(Element::C)
+}
+
+fn is_plain(atom: &Atom) -> bool {
+    if let AtomKind::Bracket { charge: Some(_), .. } = atom.kind {
+        return false
+    }
+
+    !atom.is_aromatic() && !atom.bonds.iter().any(|bond| bond.is_directional())
+}
+
+fn is_hetero(atom: &Atom) -> bool {
+    matches!(atom.kind.element(), Some(Element::N) | Some(Element::O))
+}
+
+fn is_single(kind: &BondKind) -> bool {
+    matches!(kind, BondKind::Elided | BondKind::Single)
+}
+
+fn shift(atoms: &[Atom], xid: usize, yid: usize, zid: usize) -> Vec<Atom> {
+    let mut result = atoms.to_vec();
+    let x_hydrogens = atoms[xid].suppressed_hydrogens() - 1;
+    let z_hydrogens = atoms[zid].suppressed_hydrogens() + 1;
+
+    set_bond(&mut result, xid, yid, BondKind::Double);
+    set_bond(&mut result, yid, zid, BondKind::Elided);
+    set_hydrogens(&mut result[xid], x_hydrogens);
+    set_hydrogens(&mut result[zid], z_hydrogens);
+
+    result
+}
+
+fn set_bond(atoms: &mut [Atom], sid: usize, tid: usize, kind: BondKind) {
+    for bond in atoms[sid].bonds.iter_mut().filter(|bond| bond.tid == tid) {
+        bond.kind = kind.clone()
+    }
+
+    for bond in atoms[tid].bonds.iter_mut().filter(|bond| bond.tid == sid) {
+        bond.kind = kind.clone()
+    }
+}
+
+fn set_hydrogens(atom: &mut Atom, hydrogens: u8) {
+    let hcount = if hydrogens > 0 {
+        VirtualHydrogen::try_from(hydrogens).ok()
+    } else {
+        None
+    };
+
+    atom.kind = match mem::replace(&mut atom.kind, AtomKind::Star) {
+        AtomKind::Bracket {
+            isotope, symbol, configuration, charge, map, ..
+        } => AtomKind::Bracket {
+            isotope, symbol, configuration, hcount, charge, map
+        },
+        kind => AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(kind.element().expect("element")),
+            configuration: None,
+            hcount,
+            charge: None,
+            map: None
+        }
+    };
+
+    let bond_order_sum = atom.bonds.iter()
+        .fold(0, |sum, bond| sum + bond.order());
+
+    atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+        .debracket(bond_order_sum)
+}
+
+fn canonical(atoms: &[Atom]) -> String {
+    to_smiles(&canonicalize(atoms.to_vec())).expect("canonical smiles")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn tautomers(smiles: &str, max: usize) -> Vec<String> {
+        enumerate_tautomers(&build(smiles), max).iter()
+            .map(|atoms| to_smiles(atoms).unwrap()).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(enumerate_tautomers(&[ ], 8), vec![ vec![ ] ])
+    }
+
+    #[test]
+    fn max_zero() {
+        assert_eq!(tautomers("CC=O", 0), Vec::<String>::new())
+    }
+
+    #[test]
+    fn max_one() {
+        assert_eq!(tautomers("CC=O", 1), vec![ "CC=O" ])
+    }
+
+    #[test]
+    fn acetaldehyde() {
+        assert_eq!(tautomers("CC=O", 8), vec![ "CC=O", "C=CO" ])
+    }
+
+    #[test]
+    fn vinyl_alcohol() {
+        assert_eq!(tautomers("C=CO", 8), vec![ "C=CO", "CC=O" ])
+    }
+
+    #[test]
+    fn acetamide() {
+        assert_eq!(tautomers("CC(=O)N", 8), vec![
+            "CC(=O)N", "C=C(O)N", "CC(O)=N"
+        ])
+    }
+
+    #[test]
+    fn acetylacetone() {
+        assert_eq!(tautomers("CC(=O)CC(=O)C", 16), vec![
+            "CC(=O)CC(=O)C",
+            "C=C(O)CC(=O)C",
+            "CC(O)=CC(=O)C",
+            "C=C(O)C=C(O)C",
+            "C=C(O)CC(O)=C"
+        ])
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(tautomers("CCO", 8), vec![ "CCO" ])
+    }
+
+    #[test]
+    fn propene() {
+        assert_eq!(tautomers("CC=C", 8), vec![ "CC=C" ])
+    }
+
+    #[test]
+    fn phenol() {
+        assert_eq!(tautomers("c1ccccc1O", 8), vec![ "c(c(cccc1)O)1" ])
+    }
+
+    #[test]
+    fn acetate() {
+        assert_eq!(tautomers("CC(=O)[O-]", 8), vec![
+            "CC(=O)[O-]", "C=C(O)[O-]"
+        ])
+    }
+
+    #[test]
+    fn labeled() {
+        assert_eq!(tautomers("[13CH3]C=O", 8), vec![
+            "[13CH3]C=O", "[13CH2]=CO"
+        ])
+    }
+}
\ No newline at end of file
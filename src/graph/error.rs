@@ -1,5 +1,51 @@
-#[derive(Debug,PartialEq)]
+use std::fmt;
+
+/// An error resulting from building or editing a graph representation.
+#[derive(Debug,PartialEq,Eq)]
 pub enum Error {
     Join(usize, usize),
-    Rnum(usize)
+    Rnum(usize),
+    UnknownTarget(usize, usize),
+    DuplicateBond(usize, usize),
+    Loop(usize),
+    Kekulization(usize),
+    TooManyBonds(usize),
+    UnsupportedBond(usize, usize)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Join(sid, tid) =>
+                write!(f, "mismatched ring closure bond {}-{}", sid, tid),
+            Error::Rnum(rid) => write!(f, "unclosed ring closure {}", rid),
+            Error::UnknownTarget(sid, tid) =>
+                write!(f, "bond {}-{} to unknown atom", sid, tid),
+            Error::DuplicateBond(sid, tid) =>
+                write!(f, "duplicate bond {}-{}", sid, tid),
+            Error::Loop(id) => write!(f, "bond from atom {} to itself", id),
+            Error::Kekulization(id) =>
+                write!(f, "no kekule structure at atom {}", id),
+            Error::TooManyBonds(id) =>
+                write!(f, "too many bonds at atom {}", id),
+            Error::UnsupportedBond(sid, tid) =>
+                write!(f, "bond {}-{} has no supported type", sid, tid)
+        }
+    }
+}
+
+impl std::error::Error for Error { }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::Join(2, 0).to_string(),
+            "mismatched ring closure bond 2-0"
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,165 @@
+use crate::feature::{ AtomKind, BondKind };
+use super::{ Atom, Bond, breadth_first };
+
+/// Severs each bond `(sid, tid)` in `bonds` and returns the resulting
+/// connected components as independent graphs. Each severed bond leaves a
+/// star attachment point on both of its atoms, bonded with the original
+/// bond kind at the original position in the bond list, so configurations
+/// are unaffected. A directional bond becomes elided on an atom left
+/// without a double bond. A bond listed more than once, in either
+/// direction, is severed once.
+/// 
+/// Fragments are ordered by their lowest original atom id. Within a
+/// fragment, original atoms keep their relative order and attachment
+/// stars follow them in the order they were created.
+/// 
+/// # Panics
+/// 
+/// Panics given a pair in `bonds` that is not bonded, or a bond to an atom
+/// outside of `atoms`.
+pub fn fragment_on_bonds(
+    atoms: &[Atom], bonds: &[(usize, usize)]
+) -> Vec<Vec<Atom>> {
+    let mut atoms = atoms.to_vec();
+    let mut cuts: Vec<(usize, usize)> = Vec::new();
+
+    for &(sid, tid) in bonds {
+        if !cuts.contains(&(sid, tid)) && !cuts.contains(&(tid, sid)) {
+            cuts.push((sid, tid))
+        }
+    }
+
+    for (sid, tid) in cuts {
+        cap(&mut atoms, sid, tid);
+        cap(&mut atoms, tid, sid);
+    }
+
+    let mut ids = vec![ None; atoms.len() ];
+    let mut visited = vec![ false; atoms.len() ];
+    let mut components = Vec::new();
+
+    for start in 0..atoms.len() {
+        if visited[start] {
+            continue
+        }
+
+        let mut component = breadth_first(&atoms, start, &mut visited)
+            .into_iter().map(|(id, _, _)| id).collect::<Vec<_>>();
+
+        component.sort_unstable();
+
+        for (index, &id) in component.iter().enumerate() {
+            ids[id] = Some(index)
+        }
+
+        components.push(component)
+    }
+
+    components.into_iter().map(|component| {
+        component.into_iter().map(|id| Atom {
+            kind: atoms[id].kind.clone(),
+            bonds: atoms[id].bonds.iter().map(|bond| Bond::new(
+                bond.kind.clone(), ids[bond.tid].expect("id")
+            )).collect()
+        }).collect()
+    }).collect()
+}
+
+fn cap(atoms: &mut Vec<Atom>, sid: usize, tid: usize) {
+    let star = atoms.len();
+    let conjugated = atoms[sid].bonds.iter()
+        .any(|bond| bond.kind == BondKind::Double);
+    let bond = atoms[sid].bonds.iter_mut()
+        .find(|bond| bond.tid == tid).expect("bond to cut");
+
+    if bond.is_directional() && !conjugated {
+        bond.kind = BondKind::Elided
+    }
+
+    let kind = bond.kind.reverse();
+
+    bond.tid = star;
+
+    atoms.push(Atom {
+        kind: AtomKind::Star,
+        bonds: vec![ Bond::new(kind, sid) ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn fragments(smiles: &str, bonds: &[(usize, usize)]) -> Vec<String> {
+        fragment_on_bonds(&build(smiles), bonds).iter()
+            .map(|atoms| to_smiles(atoms).unwrap()).collect()
+    }
+
+    #[test]
+    #[should_panic(expected = "bond to cut")]
+    fn unbonded_pair() {
+        fragment_on_bonds(&build("C.C"), &[ (0, 1) ]);
+    }
+
+    #[test]
+    fn no_bonds() {
+        assert_eq!(fragments("CCO", &[ ]), vec![ "CCO" ])
+    }
+
+    #[test]
+    fn diethyl_ether_central() {
+        assert_eq!(fragments("CCOCC", &[ (1, 2) ]), vec![ "CC*", "O(*)CC" ])
+    }
+
+    #[test]
+    fn diethyl_ether_both_sides() {
+        assert_eq!(
+            fragments("CCOCC", &[ (1, 2), (2, 3) ]),
+            vec![ "CC*", "O(*)*", "C(*)C" ]
+        )
+    }
+
+    #[test]
+    fn ring_bond_cut_keeps_one_fragment() {
+        assert_eq!(fragments("C1CC1", &[ (0, 2) ]), vec![ "C(*)CC*" ])
+    }
+
+    #[test]
+    fn double_bond() {
+        assert_eq!(fragments("C=C", &[ (1, 0) ]), vec![ "C=*", "C=*" ])
+    }
+
+    #[test]
+    fn repeated_bond() {
+        assert_eq!(
+            fragments("CCOCC", &[ (1, 2), (2, 1), (1, 2) ]),
+            vec![ "CC*", "O(*)CC" ]
+        )
+    }
+
+    #[test]
+    fn directional_bond() {
+        assert_eq!(
+            fragments("F/C=C/C", &[ (2, 3) ]),
+            vec![ "F/C=C/*", "C*" ]
+        )
+    }
+
+    #[test]
+    fn directional_bond_conjugated() {
+        assert_eq!(
+            fragments("F/C=C/C=C", &[ (2, 3) ]),
+            vec![ "F/C=C/*", "C(\\*)=C" ]
+        )
+    }
+
+    #[test]
+    fn stereocenter_keeps_order() {
+        let atoms = fragment_on_bonds(&build("F[C@H](Cl)Br"), &[ (1, 2) ]);
+
+        assert_eq!(to_smiles(&atoms[0]).unwrap(), "F[C@H](*)Br")
+    }
+}
\ No newline at end of file
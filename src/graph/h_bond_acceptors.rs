@@ -0,0 +1,41 @@
+use crate::feature::Element;
+use super::Atom;
+
+/// Returns the number of hydrogen bond acceptors using the simple Lipinski
+/// rule: every nitrogen and oxygen atom.
+pub fn h_bond_acceptors(atoms: &[Atom]) -> usize {
+    atoms.iter().filter(|atom| {
+        matches!(atom.kind.element(), Some(Element::N) | Some(Element::O))
+    }).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn acceptors(smiles: &str) -> usize {
+        h_bond_acceptors(&build(smiles))
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(acceptors("CCO"), 1)
+    }
+
+    #[test]
+    fn acetone() {
+        assert_eq!(acceptors("CC(=O)C"), 1)
+    }
+
+    #[test]
+    fn glycine() {
+        assert_eq!(acceptors("NCC(=O)O"), 3)
+    }
+
+    #[test]
+    fn hexane() {
+        assert_eq!(acceptors("CCCCCC"), 0)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::feature::Element;
+use super::Atom;
+
+/// Returns the number of hydrogen bond donors using the simple Lipinski
+/// rule: nitrogen and oxygen atoms bearing at least one hydrogen, whether
+/// implicit, virtual, or an explicit hydrogen atom.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn h_bond_donors(atoms: &[Atom]) -> usize {
+    atoms.iter().filter(|atom| {
+        if !matches!(atom.kind.element(), Some(Element::N) | Some(Element::O)) {
+            return false
+        }
+
+        atom.suppressed_hydrogens() > 0 || atom.bonds.iter().any(|bond| {
+            atoms[bond.tid].kind.element() == Some(Element::H)
+        })
+    }).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn donors(smiles: &str) -> usize {
+        h_bond_donors(&build(smiles))
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(donors("CCO"), 1)
+    }
+
+    #[test]
+    fn acetone() {
+        assert_eq!(donors("CC(=O)C"), 0)
+    }
+
+    #[test]
+    fn explicit_hydrogen() {
+        assert_eq!(donors("C[O][H]"), 1)
+    }
+
+    #[test]
+    fn pyrrole_and_pyridine() {
+        assert_eq!(donors("c1cc[nH]c1.c1ccncc1"), 1)
+    }
+
+    #[test]
+    fn ammonium() {
+        assert_eq!(donors("[NH4+]"), 1)
+    }
+}
\ No newline at end of file
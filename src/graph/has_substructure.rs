@@ -0,0 +1,163 @@
+use super::{ Atom, SubstructureOptions, substructure };
+
+/// Returns true if `pattern` occurs as a subgraph of `haystack`, using
+/// default `SubstructureOptions`. Atoms match on element, charge, isotope
+/// (when the pattern gives one), and aromaticity. Pattern stars match any
+/// atom. Bonds match on order and aromaticity. Hydrogen counts are
+/// ignored.
+/// 
+/// ```
+/// use purr::graph::{ Atom, Builder, has_substructure };
+/// use purr::read::read;
+///
+/// fn build(smiles: &str) -> Vec<Atom> {
+///     let mut builder = Builder::new();
+///
+///     read(smiles, &mut builder, None).expect("read");
+///
+///     builder.build().expect("atoms")
+/// }
+///
+/// fn main() {
+///     assert!(has_substructure(&build("CCO"), &build("CO")));
+///     assert!(!has_substructure(&build("CCO"), &build("CN")))
+/// }
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `pattern`.
+pub fn has_substructure(haystack: &[Atom], pattern: &[Atom]) -> bool {
+    has_substructure_with(haystack, pattern, &SubstructureOptions::default())
+}
+
+/// Returns true if `pattern` occurs as a subgraph of `haystack` under
+/// `options`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `pattern`.
+pub fn has_substructure_with(
+    haystack: &[Atom], pattern: &[Atom], options: &SubstructureOptions
+) -> bool {
+    let mut result = false;
+
+    substructure(haystack, pattern, options, &mut |_| {
+        result = true;
+
+        false
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::build;
+    use super::*;
+
+    fn matches(haystack: &str, pattern: &str) -> bool {
+        has_substructure(&build(haystack), &build(pattern))
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert!(has_substructure(&build("C"), &[ ]))
+    }
+
+    #[test]
+    fn empty_haystack() {
+        assert!(!has_substructure(&[ ], &build("C")))
+    }
+
+    #[test]
+    fn methanol_in_ethanol() {
+        assert!(matches("CCO", "CO"))
+    }
+
+    #[test]
+    fn methylamine_not_in_ethanol() {
+        assert!(!matches("CCO", "CN"))
+    }
+
+    #[test]
+    fn bond_order() {
+        assert!(matches("CC=O", "C=O"));
+        assert!(!matches("CCO", "C=O"))
+    }
+
+    #[test]
+    fn charge() {
+        assert!(matches("CC(=O)[O-]", "C[O-]"));
+        assert!(!matches("CC(=O)O", "C[O-]"))
+    }
+
+    #[test]
+    fn isotope() {
+        assert!(matches("[13CH3]O", "CO"));
+        assert!(matches("[13CH3]O", "[13CH3]O"));
+        assert!(!matches("CO", "[13CH3]O"))
+    }
+
+    #[test]
+    fn star() {
+        assert!(matches("CCO", "*O"));
+        assert!(!matches("C", "*O"))
+    }
+
+    #[test]
+    fn ring() {
+        assert!(matches("C1CCCCC1", "CCCC"));
+        assert!(!matches("CCCCCC", "C1CCCCC1"))
+    }
+
+    #[test]
+    fn branched() {
+        assert!(matches("CC(C)(C)O", "C(C)(C)O"));
+        assert!(!matches("CC(C)CO", "C(C)(C)O"))
+    }
+
+    #[test]
+    fn disconnected_pattern() {
+        assert!(matches("[Na+].[Cl-]", "[Cl-].[Na+]"));
+        assert!(!matches("[Na+]", "[Na+].[Na+]"))
+    }
+
+    #[test]
+    fn aromaticity() {
+        assert!(matches("c1ccccc1", "cc"));
+        assert!(!matches("c1ccccc1", "CC"));
+        assert!(!matches("CCC", "cc"))
+    }
+
+    #[test]
+    fn aromaticity_ignored() {
+        let options = SubstructureOptions { aromaticity: false };
+
+        assert!(has_substructure_with(
+            &build("c1ccccc1"), &build("CC"), &options
+        ));
+        assert!(has_substructure_with(
+            &build("c1ccccc1"), &build("C:C"), &options
+        ))
+    }
+
+    #[test]
+    fn aromaticity_ignored_kekule() {
+        let options = SubstructureOptions { aromaticity: false };
+        let kekule = build("C1=CC=CC=C1");
+        let aromatic = build("c1ccccc1");
+
+        assert!(has_substructure_with(&aromatic, &kekule, &options));
+        assert!(has_substructure_with(&kekule, &aromatic, &options));
+        assert!(!has_substructure(&aromatic, &kekule))
+    }
+
+    #[test]
+    fn explicit_aromatic_closure_isomorphic() {
+        for smiles in &[ "c:1ccccc:1", "c:1ccccc1", "c1ccccc:1" ] {
+            assert!(matches("c1ccccc1", smiles));
+            assert!(matches(smiles, "c1ccccc1"))
+        }
+    }
+}
\ No newline at end of file
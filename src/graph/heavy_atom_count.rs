@@ -0,0 +1,54 @@
+use crate::feature::Element;
+use super::Atom;
+
+/// Returns the number of heavy atoms in `atoms`. Hydrogen atoms, whether
+/// written as `[H]` or as a bare `H`, and star atoms are not counted.
+/// Implicit and virtual hydrogens are never atoms, so they are not
+/// counted either.
+pub fn heavy_atom_count(atoms: &[Atom]) -> usize {
+    atoms.iter().filter(|atom| match atom.kind.element() {
+        Some(element) => element != Element::H,
+        None => false
+    }).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn count(smiles: &str) -> usize {
+        heavy_atom_count(&build(smiles))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(heavy_atom_count(&[ ]), 0)
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(count("[CH4]"), 1)
+    }
+
+    #[test]
+    fn methane_explicit_hydrogens() {
+        assert_eq!(count("[H]C([H])([H])[H]"), 1)
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(count("CCO"), 3)
+    }
+
+    #[test]
+    fn stars() {
+        assert_eq!(count("*C[*]"), 1)
+    }
+
+    #[test]
+    fn deuterium() {
+        assert_eq!(count("[2H]O[2H]"), 1)
+    }
+}
\ No newline at end of file
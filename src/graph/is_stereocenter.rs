@@ -0,0 +1,164 @@
+use crate::feature::{ AtomKind, Configuration };
+use super::{ Atom, breadth_first, symmetry_classes };
+
+/// Returns true if the atom at `id` carries a tetrahedral configuration
+/// (`@` or `@@`) and no two of its substituents are equivalent. Equivalence
+/// is judged by symmetry class, so two methyl groups or two hydrogens make
+/// the configuration meaningless.
+/// 
+/// Equivalent substituents are allowed in one case: a single equivalent
+/// pair closing a ring that also holds another such configured atom, as
+/// in cis- and trans-1,4-dimethylcyclohexane. The two centers are then
+/// stereogenic together.
+/// 
+/// # Panics
+/// 
+/// Panics given `id` outside of `atoms`, or a bond to an atom outside of
+/// `atoms`.
+pub fn is_stereocenter(atoms: &[Atom], id: usize) -> bool {
+    stereocenter(atoms, id, &symmetry_classes(atoms, false))
+}
+
+/// Performs `is_stereocenter` given the symmetry `classes` of `atoms`, so
+/// they can be computed once for many atoms.
+pub(crate) fn stereocenter(
+    atoms: &[Atom], id: usize, classes: &[usize]
+) -> bool {
+    if !is_candidate(&atoms[id]) {
+        return false
+    }
+
+    if pairs(&atoms[id], classes).is_empty() {
+        return true
+    }
+
+    match ring_pair(atoms, id, classes) {
+        Some((left, _)) => beyond(atoms, id, left).into_iter()
+            .any(|other| ring_pair(atoms, other, classes).is_some()),
+        None => false
+    }
+}
+
+// The only equivalent pair of substituents of a candidate, provided it
+// closes a ring through the candidate.
+fn ring_pair(
+    atoms: &[Atom], id: usize, classes: &[usize]
+) -> Option<(usize, usize)> {
+    if !is_candidate(&atoms[id]) {
+        return None
+    }
+
+    match *pairs(&atoms[id], classes).as_slice() {
+        [ (left, right) ] if beyond(atoms, id, left).contains(&right) =>
+            Some((left, right)),
+        _ => None
+    }
+}
+
+// The ids reachable from start without passing through id.
+fn beyond(atoms: &[Atom], id: usize, start: usize) -> Vec<usize> {
+    let mut visited = vec![ false; atoms.len() ];
+
+    visited[id] = true;
+
+    breadth_first(atoms, start, &mut visited).into_iter()
+        .map(|(other, _, _)| other).collect()
+}
+
+fn is_candidate(atom: &Atom) -> bool {
+    match &atom.kind {
+        AtomKind::Bracket {
+            configuration: Some(Configuration::TH1), ..
+        } |
+        AtomKind::Bracket {
+            configuration: Some(Configuration::TH2), ..
+        } => atom.suppressed_hydrogens() < 2,
+        _ => false
+    }
+}
+
+fn pairs(atom: &Atom, classes: &[usize]) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+
+    for (index, left) in atom.bonds.iter().enumerate() {
+        for right in atom.bonds[index + 1..].iter() {
+            if classes[left.tid] == classes[right.tid] {
+                result.push((left.tid, right.tid))
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn unmarked() {
+        assert!(!is_stereocenter(&build("C(F)(Cl)(Br)I"), 0))
+    }
+
+    #[test]
+    fn distinct_substituents() {
+        assert!(is_stereocenter(&build("[C@](F)(Cl)(Br)I"), 0))
+    }
+
+    #[test]
+    fn two_methyls() {
+        assert!(!is_stereocenter(&build("[C@](C)(C)(F)Cl"), 0))
+    }
+
+    #[test]
+    fn two_hydrogens() {
+        assert!(!is_stereocenter(&build("F[C@H2]Cl"), 1))
+    }
+
+    #[test]
+    fn one_hydrogen() {
+        assert!(is_stereocenter(&build("N[C@@H](C)C(=O)O"), 1))
+    }
+
+    #[test]
+    fn equivalent_branches() {
+        assert!(!is_stereocenter(&build("C[C@H](CC)CC"), 1))
+    }
+
+    #[test]
+    fn distinct_branches() {
+        assert!(is_stereocenter(&build("C[C@H](CC)CCC"), 1))
+    }
+
+    #[test]
+    fn ring_symmetric() {
+        assert!(!is_stereocenter(&build("C[C@H]1CCCCC1"), 1))
+    }
+
+    #[test]
+    fn ring_cis() {
+        let atoms = build("C[C@H]1CC[C@@H](C)CC1");
+
+        assert!(is_stereocenter(&atoms, 1));
+        assert!(is_stereocenter(&atoms, 4))
+    }
+
+    #[test]
+    fn ring_trans() {
+        let atoms = build("C[C@H]1CC[C@H](C)CC1");
+
+        assert!(is_stereocenter(&atoms, 1));
+        assert!(is_stereocenter(&atoms, 4))
+    }
+
+    #[test]
+    fn ring_partner_unmarked() {
+        assert!(!is_stereocenter(&build("C[C@H]1CCC(C)CC1"), 1))
+    }
+
+    #[test]
+    fn ring_partner_symmetric() {
+        assert!(!is_stereocenter(&build("C[C@H]1CC[C@@](C)(C)CC1"), 1))
+    }
+}
\ No newline at end of file
@@ -6,7 +6,7 @@ use std::convert::TryInto;
 
 use crate::feature::Rnum;
 
-#[derive(Eq,PartialEq,PartialOrd)]
+#[derive(Eq,PartialEq)]
 struct Index(u16);
 
 impl Ord for Index {
@@ -15,6 +15,12 @@ impl Ord for Index {
     }
 }
 
+impl PartialOrd for Index {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug,Eq)]
 struct Pair(usize, usize);
 
@@ -46,17 +52,10 @@ impl JoinPool {
         }
     }
 
+    /// Returns the ring closure digit for the bond between `sid` and `tid`.
+    /// The first hit opens the bond with the lowest free digit, and the
+    /// second hit closes it, freeing the digit for reuse.
     pub fn hit(&mut self, sid: usize, tid: usize) -> Rnum {
-        let next = match self.replaced.pop() {
-            Some(next) => next.0,
-            None => {
-                let next = self.counter;
-                self.counter += 1;
-
-                next
-            }
-        };
-
         match self.borrowed.entry(Pair(sid, tid)) {
             Entry::Occupied(occupied) => {
                 let result = occupied.remove();
@@ -66,6 +65,16 @@ impl JoinPool {
                 result.try_into().expect("rnum")
             },
             Entry::Vacant(vacant) => {
+                let next = match self.replaced.pop() {
+                    Some(next) => next.0,
+                    None => {
+                        let next = self.counter;
+                        self.counter += 1;
+
+                        next
+                    }
+                };
+
                 vacant.insert(next);
 
                 next.try_into().expect("rnum")
@@ -130,4 +139,27 @@ mod hit {
         assert_eq!(pool.hit(1, 0), Rnum::R1);
         assert_eq!(pool.hit(3, 5), Rnum::R1)
     }
+
+    #[test]
+    fn unknown_after_nested_returned() {
+        let mut pool = JoinPool::new();
+
+        assert_eq!(pool.hit(0, 5), Rnum::R1);
+        assert_eq!(pool.hit(1, 4), Rnum::R2);
+        assert_eq!(pool.hit(4, 1), Rnum::R2);
+        assert_eq!(pool.hit(5, 0), Rnum::R1);
+        assert_eq!(pool.hit(6, 9), Rnum::R1);
+        assert_eq!(pool.hit(7, 8), Rnum::R2)
+    }
+
+    #[test]
+    fn sequential_rings() {
+        let mut pool = JoinPool::new();
+
+        assert_eq!(pool.hit(0, 2), Rnum::R1);
+        assert_eq!(pool.hit(2, 0), Rnum::R1);
+        assert_eq!(pool.hit(3, 5), Rnum::R1);
+        assert_eq!(pool.hit(5, 3), Rnum::R1);
+        assert_eq!(pool.hit(6, 8), Rnum::R1)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,136 @@
+use super::{ Atom, assign, is_pi_bond, pi_atoms };
+
+const MAX_STRUCTURES: usize = 1024;
+
+/// Returns the Kekulé structures of `atoms`, one for each way of pairing
+/// the aromatic atoms that need a double bond, as when analyzing
+/// resonance. Each structure is rewritten as by `kekulize`. At most 1,024
+/// structures are returned. Without aromatic atoms, the result holds
+/// `atoms` alone, and it is empty if the aromatic atoms cannot be paired,
+/// as in `c1cccc1`.
+///
+/// ```
+/// use purr::graph::{ Builder, kekule_structures, to_smiles };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("c1ccccc1", &mut builder, None)?;
+///
+///     let structures = kekule_structures(&builder.build().expect("atoms"));
+///
+///     assert_eq!(structures.len(), 2);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn kekule_structures(atoms: &[Atom]) -> Vec<Vec<Atom>> {
+    let pi = pi_atoms(atoms);
+    let mut mates = vec![ None; atoms.len() ];
+    let mut matchings = Vec::new();
+
+    pair(atoms, &pi, &mut mates, &mut matchings);
+
+    matchings.into_iter().map(|mates| {
+        let mut result = atoms.to_vec();
+
+        assign(&mut result, &mates);
+
+        result
+    }).collect()
+}
+
+fn pair(
+    atoms: &[Atom],
+    pi: &[bool],
+    mates: &mut Vec<Option<usize>>,
+    matchings: &mut Vec<Vec<Option<usize>>>
+) {
+    if matchings.len() == MAX_STRUCTURES {
+        return
+    }
+
+    let unpaired = (0..atoms.len()).find(|&id| pi[id] && mates[id].is_none());
+    let sid = match unpaired {
+        Some(sid) => sid,
+        None => return matchings.push(mates.clone())
+    };
+
+    for bond in atoms[sid].bonds.iter() {
+        let tid = bond.tid;
+
+        if mates[tid].is_some() || !is_pi_bond(atoms, pi, sid, tid) {
+            continue
+        }
+
+        mates[sid] = Some(tid);
+        mates[tid] = Some(sid);
+
+        pair(atoms, pi, mates, matchings);
+
+        mates[sid] = None;
+        mates[tid] = None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn structures(smiles: &str) -> Vec<String> {
+        kekule_structures(&build(smiles)).iter()
+            .map(|atoms| to_smiles(atoms).unwrap()).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(kekule_structures(&[ ]), vec![ vec![ ] ])
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(structures("CC=O"), vec![ "CC=O" ])
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(structures("c1ccccc1"), vec![
+            "C(=CC=CC=C1)1", "C(C=CC=CC=1)=1"
+        ])
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(structures("c1cc[nH]c1"), vec![ "C(=CNC=C1)1" ])
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(structures("c1ccc2ccccc2c1").len(), 3)
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(structures("c1ccccc1c1ccccc1").len(), 4)
+    }
+
+    #[test]
+    fn odd_ring() {
+        assert_eq!(structures("c1cccc1"), Vec::<String>::new())
+    }
+
+    #[test]
+    fn capped() {
+        let smiles = [ "c1ccccc1"; 11 ].join(".");
+
+        assert_eq!(structures(&smiles).len(), MAX_STRUCTURES)
+    }
+}
\ No newline at end of file
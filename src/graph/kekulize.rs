@@ -0,0 +1,303 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use crate::feature::{
+    Aliphatic, AtomKind, BondKind, BracketSymbol, Element, VirtualHydrogen
+};
+use super::{ Atom, Error };
+
+/// Replaces the aromatic atoms and bonds of `atoms` with a Kekulé structure.
+/// Aromatic atoms that need a double bond to reach a valence target, such
+/// as the carbons of benzene but not the nitrogen of pyrrole, are paired
+/// across aromatic bonds. Paired bonds become double, and other bonds
+/// between aromatic atoms become elided. Hydrogen counts are kept, so atoms
+/// whose aliphatic symbols would imply a different count are bracketed.
+/// Other bracket atoms are debracketed where possible.
+///
+/// Returns `Error::Kekulization` with the lowest atom id of a conjugated
+/// system that cannot be paired, as in `c1cccc1`, leaving `atoms`
+/// unchanged.
+///
+/// ```
+/// use purr::graph::{ Builder, kekulize, to_smiles };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("c1ccccc1", &mut builder, None)?;
+///
+///     let mut atoms = builder.build().expect("atoms");
+///
+///     kekulize(&mut atoms).expect("kekulize");
+///
+///     assert_eq!(to_smiles(&atoms).expect("smiles"), "C(=CC=CC=C1)1");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn kekulize(atoms: &mut [Atom]) -> Result<(), Error> {
+    let pi = pi_atoms(atoms);
+    let mut mates = vec![ None; atoms.len() ];
+
+    for id in 0..atoms.len() {
+        if pi[id] && mates[id].is_none() {
+            let system = conjugated_system(atoms, &pi, id);
+
+            if !pair(atoms, &pi, &system, &mut mates) {
+                return Err(Error::Kekulization(id))
+            }
+        }
+    }
+
+    assign(atoms, &mates);
+
+    Ok(())
+}
+
+/// Returns whether each atom of `atoms` is aromatic and needs a double bond.
+pub(crate) fn pi_atoms(atoms: &[Atom]) -> Vec<bool> {
+    atoms.iter().map(needs_double).collect()
+}
+
+/// Rewrites the aromatic atoms and bonds of `atoms`, making a bond double
+/// when its atoms are mates.
+pub(crate) fn assign(atoms: &mut [Atom], mates: &[Option<usize>]) {
+    let hydrogens = atoms.iter()
+        .map(|atom| atom.suppressed_hydrogens()).collect::<Vec<_>>();
+    let aromatic = atoms.iter()
+        .map(|atom| atom.is_aromatic()).collect::<Vec<_>>();
+
+    for (sid, atom) in atoms.iter_mut().enumerate() {
+        for bond in atom.bonds.iter_mut() {
+            if bond.kind == BondKind::Aromatic ||
+                bond.kind == BondKind::Elided && aromatic[sid] &&
+                aromatic[bond.tid] {
+                bond.kind = if mates[sid] == Some(bond.tid) {
+                    BondKind::Double
+                } else {
+                    BondKind::Elided
+                }
+            }
+        }
+
+        if aromatic[sid] {
+            dearomatize(atom, hydrogens[sid])
+        }
+    }
+}
+
+fn needs_double(atom: &Atom) -> bool {
+    atom.is_aromatic() && atom.subvalence() > 0
+}
+
+/// Returns true if pi atoms `sid` and `tid` share an elided or aromatic
+/// bond.
+pub(crate) fn is_pi_bond(
+    atoms: &[Atom], pi: &[bool], sid: usize, tid: usize
+) -> bool {
+    if !pi[sid] || !pi[tid] {
+        return false
+    }
+
+    atoms[sid].bonds.iter().any(|bond| {
+        bond.tid == tid &&
+            matches!(bond.kind, BondKind::Elided | BondKind::Aromatic)
+    })
+}
+
+fn conjugated_system(atoms: &[Atom], pi: &[bool], root: usize) -> Vec<usize> {
+    let mut result = vec![ root ];
+    let mut visited = vec![ false; atoms.len() ];
+    let mut index = 0;
+
+    visited[root] = true;
+
+    while index < result.len() {
+        let sid = result[index];
+
+        for bond in atoms[sid].bonds.iter() {
+            if !visited[bond.tid] && is_pi_bond(atoms, pi, sid, bond.tid) {
+                visited[bond.tid] = true;
+                result.push(bond.tid)
+            }
+        }
+
+        index += 1
+    }
+
+    result
+}
+
+fn pair(
+    atoms: &[Atom],
+    pi: &[bool],
+    system: &[usize],
+    mates: &mut Vec<Option<usize>>
+) -> bool {
+    let sid = match system.iter().find(|&&id| mates[id].is_none()) {
+        Some(&sid) => sid,
+        None => return true
+    };
+
+    for bond in atoms[sid].bonds.iter() {
+        let tid = bond.tid;
+
+        if mates[tid].is_some() || !is_pi_bond(atoms, pi, sid, tid) {
+            continue
+        }
+
+        mates[sid] = Some(tid);
+        mates[tid] = Some(sid);
+
+        if pair(atoms, pi, system, mates) {
+            return true
+        }
+
+        mates[sid] = None;
+        mates[tid] = None
+    }
+
+    false
+}
+
+fn dearomatize(atom: &mut Atom, hydrogens: u8) {
+    atom.kind = match mem::replace(&mut atom.kind, AtomKind::Star) {
+        AtomKind::Aromatic(aromatic) =>
+            AtomKind::Aliphatic(Aliphatic::from(&aromatic)),
+        AtomKind::Bracket {
+            isotope,
+            symbol: BracketSymbol::Aromatic(aromatic),
+            configuration,
+            hcount,
+            charge,
+            map
+        } => AtomKind::Bracket {
+            isotope,
+            symbol: BracketSymbol::Element(Element::from(&aromatic)),
+            configuration,
+            hcount,
+            charge,
+            map
+        },
+        kind => kind
+    };
+
+    if atom.suppressed_hydrogens() != hydrogens {
+        atom.kind = AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(
+                atom.kind.element().expect("element")
+            ),
+            configuration: None,
+            hcount: if hydrogens > 0 {
+                VirtualHydrogen::try_from(hydrogens).ok()
+            } else {
+                None
+            },
+            charge: None,
+            map: None
+        }
+    }
+
+    let bond_order_sum = atom.bonds.iter()
+        .fold(0, |sum, bond| sum + bond.order());
+
+    atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+        .debracket(bond_order_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn kekule(smiles: &str) -> String {
+        let mut atoms = build(smiles);
+
+        kekulize(&mut atoms).unwrap();
+
+        to_smiles(&atoms).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(kekulize(&mut [ ]), Ok(()))
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(kekule("CC=O"), "CC=O")
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(kekule("c1ccccc1"), "C(=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn benzene_aromatic_bonds() {
+        assert_eq!(kekule("c:1:c:c:c:c:c:1"), "C(=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn pyridine() {
+        assert_eq!(kekule("n1ccccc1"), "N(=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(kekule("c1cc[nH]c1"), "C(=CNC=C1)1")
+    }
+
+    #[test]
+    fn furan() {
+        assert_eq!(kekule("c1ccoc1"), "C(=COC=C1)1")
+    }
+
+    #[test]
+    fn pyridinium() {
+        assert_eq!(kekule("C[n+]1ccccc1"), "C[N+](=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(
+            kekule("c1ccccc1c1ccccc1"),
+            "C(=C(C=CC=C1)C(=CC=CC=C2)2)1"
+        )
+    }
+
+    #[test]
+    fn naphthalene() {
+        let mut atoms = build("c1ccc2ccccc2c1");
+
+        kekulize(&mut atoms).unwrap();
+
+        let doubles = atoms.iter().map(|atom| {
+            atom.bonds.iter()
+                .filter(|bond| bond.kind == BondKind::Double).count()
+        }).collect::<Vec<_>>();
+
+        assert_eq!(doubles, vec![ 1; 10 ])
+    }
+
+    #[test]
+    fn labeled() {
+        assert_eq!(kekule("[13cH]1ccccc1"), "[13CH](=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn odd_ring() {
+        let mut atoms = build("c1cccc1");
+
+        assert_eq!(kekulize(&mut atoms), Err(Error::Kekulization(0)));
+        assert_eq!(atoms, build("c1cccc1"))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+
+use super::{
+    Atom, canonicalize, fragment_on_bonds, heavy_atom_count,
+    molecular_weight, to_smiles
+};
+
+/// Returns the connected component of `atoms` with the most heavy atoms,
+/// re-indexed from zero, as when stripping counterions and solvents.
+/// Hydrogen and star atoms are not heavy. Ties go to the higher molecular
+/// weight, then to the lexicographically smaller canonical string. Empty
+/// `atoms` give an empty result.
+///
+/// ```
+/// use purr::graph::{ Builder, largest_fragment, to_smiles };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("[Na+].CC(=O)[O-]", &mut builder, None)?;
+///
+///     let atoms = largest_fragment(&builder.build().expect("atoms"));
+///
+///     assert_eq!(to_smiles(&atoms).expect("smiles"), "CC(=O)[O-]");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn largest_fragment(atoms: &[Atom]) -> Vec<Atom> {
+    let mut result: Option<Vec<Atom>> = None;
+
+    for fragment in fragment_on_bonds(atoms, &[ ]) {
+        let better = match &result {
+            Some(best) => compare(&fragment, best) == Ordering::Greater,
+            None => true
+        };
+
+        if better {
+            result.replace(fragment);
+        }
+    }
+
+    result.unwrap_or_default()
+}
+
+fn compare(left: &[Atom], right: &[Atom]) -> Ordering {
+    heavy_atom_count(left).cmp(&heavy_atom_count(right))
+        .then_with(|| {
+            molecular_weight(left).partial_cmp(&molecular_weight(right))
+                .expect("comparable weights")
+        })
+        .then_with(|| canonical(right).cmp(&canonical(left)))
+}
+
+fn canonical(atoms: &[Atom]) -> String {
+    to_smiles(&canonicalize(atoms.to_vec())).expect("canonical smiles")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn largest(smiles: &str) -> String {
+        to_smiles(&largest_fragment(&build(smiles))).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(largest_fragment(&[ ]), vec![ ])
+    }
+
+    #[test]
+    fn single_component() {
+        assert_eq!(largest("CCO"), "CCO")
+    }
+
+    #[test]
+    fn sodium_acetate() {
+        assert_eq!(largest("CC(=O)O.[Na+]"), "CC(=O)O")
+    }
+
+    #[test]
+    fn sodium_first() {
+        assert_eq!(largest("[Na+].CC(=O)[O-]"), "CC(=O)[O-]")
+    }
+
+    #[test]
+    fn hydrogens_not_heavy() {
+        assert_eq!(largest("[H][H].[H][H].[H][H].C"), "C")
+    }
+
+    #[test]
+    fn tie_by_weight() {
+        assert_eq!(largest("CO.CC"), "CO")
+    }
+
+    #[test]
+    fn tie_by_canonical() {
+        assert_eq!(largest("COC.CCO"), largest("CCO.COC"))
+    }
+
+    #[test]
+    fn identical_fragments() {
+        assert_eq!(largest("OC.CO"), "OC")
+    }
+}
\ No newline at end of file
@@ -4,10 +4,105 @@ mod builder;
 mod reconcile;
 mod error;
 mod join_pool;
+mod remove_atom;
+mod add_bond;
+mod atom_maps;
+mod canonical_ranks;
+mod adjacency_matrix;
+mod degree_sequence;
+mod wiener_index;
+mod shortest_path;
+mod breadth_first;
+mod to_molblock;
+mod canonicalize;
+mod canonical_smiles_with_order;
+mod reorder_bonds;
+mod is_stereocenter;
+mod remove_nonstereogenic;
+mod substructure_options;
+mod substructure;
+mod has_substructure;
+mod substructure_matches;
+mod component_roots;
+mod molecular_formula;
+mod molecular_weight;
+mod monoisotopic_mass;
+mod element_counts;
+mod heavy_atom_count;
+mod to_smiles;
+mod fragment_on_bonds;
+mod sssr;
+mod rotatable_bonds;
+mod h_bond_donors;
+mod h_bond_acceptors;
+mod ring_count;
+mod aromatic_ring_count;
+mod aromaticity_model;
+mod perceive_aromaticity;
+mod aromatic_atoms;
+mod neutralize;
+mod enumerate_tautomers;
+mod largest_fragment;
+mod remove_stereo;
+mod kekulize;
+mod kekule_structures;
+mod molblock_options;
 
 pub use atom::Atom;
 pub use bond::Bond;
 pub use builder::Builder;
 pub use error::Error;
+pub use remove_atom::remove_atom;
+pub use add_bond::add_bond;
+pub use atom_maps::atom_maps;
+pub use canonical_ranks::canonical_ranks;
+pub use adjacency_matrix::adjacency_matrix;
+pub use degree_sequence::degree_sequence;
+pub use wiener_index::wiener_index;
+pub use shortest_path::shortest_path;
+pub use to_molblock::{ to_molblock, to_molblock_with };
+pub use canonicalize::canonicalize;
+pub use canonical_smiles_with_order::canonical_smiles_with_order;
+pub use is_stereocenter::is_stereocenter;
+pub use remove_nonstereogenic::remove_nonstereogenic;
+pub use substructure_options::SubstructureOptions;
+pub use has_substructure::{ has_substructure, has_substructure_with };
+pub use substructure_matches::{
+    substructure_matches, substructure_matches_with
+};
+pub use component_roots::component_roots;
+pub use molecular_formula::molecular_formula;
+pub use molecular_weight::molecular_weight;
+pub use monoisotopic_mass::monoisotopic_mass;
+pub use element_counts::element_counts;
+pub use heavy_atom_count::heavy_atom_count;
+pub use to_smiles::to_smiles;
+pub use fragment_on_bonds::fragment_on_bonds;
+pub use sssr::sssr;
+pub use rotatable_bonds::rotatable_bonds;
+pub use h_bond_donors::h_bond_donors;
+pub use h_bond_acceptors::h_bond_acceptors;
+pub use ring_count::ring_count;
+pub use aromatic_ring_count::aromatic_ring_count;
+pub use aromaticity_model::AromaticityModel;
+pub use perceive_aromaticity::perceive_aromaticity;
+pub use aromatic_atoms::aromatic_atoms;
+pub use neutralize::neutralize;
+pub use enumerate_tautomers::enumerate_tautomers;
+pub use largest_fragment::largest_fragment;
+pub use remove_stereo::remove_stereo;
+pub use kekulize::kekulize;
+pub use kekule_structures::kekule_structures;
+pub use molblock_options::MolblockOptions;
 pub(crate) use reconcile::reconcile;
-pub(crate) use join_pool::JoinPool;
\ No newline at end of file
+pub(crate) use join_pool::JoinPool;
+pub(crate) use canonical_ranks::{ symmetry_classes, tie_breaks };
+pub(crate) use substructure::{
+    Query, substructure, search, is_aromatic_bond
+};
+pub(crate) use reorder_bonds::reorder_bonds;
+pub(crate) use canonicalize::relabel;
+pub(crate) use canonical_smiles_with_order::canonical_order;
+pub(crate) use breadth_first::breadth_first;
+pub(crate) use is_stereocenter::stereocenter;
+pub(crate) use kekulize::{ assign, is_pi_bond, pi_atoms };
\ No newline at end of file
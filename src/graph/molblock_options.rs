@@ -0,0 +1,7 @@
+/// Options controlling how `to_molblock_with` writes atoms and bonds.
+#[derive(Debug,PartialEq,Eq,Clone,Default)]
+pub struct MolblockOptions {
+    /// When true, aromatic atoms and bonds are kekulized before writing, so
+    /// bonds use types 1 and 2. When false, aromatic bonds use type 4.
+    pub kekulize: bool
+}
\ No newline at end of file
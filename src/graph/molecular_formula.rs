@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crate::feature::Element;
+use super::{ Atom, element_counts };
+
+/// Returns the molecular formula of `atoms` in Hill order: carbon, then
+/// hydrogen, then the remaining elements alphabetically. Without carbon,
+/// all elements, hydrogen included, are alphabetical. Implicit and virtual
+/// hydrogens are counted. Stars contribute nothing, and charges and
+/// isotopes are ignored.
+/// 
+/// ```
+/// use purr::graph::{ Builder, molecular_formula };
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("CC(=O)O", &mut builder, None)?;
+/// 
+///     assert_eq!(molecular_formula(&builder.build().unwrap()), "C2H4O2");
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn molecular_formula(atoms: &[Atom]) -> String {
+    let mut counts = element_counts(atoms);
+    let carbon = counts.remove(&Element::C).unwrap_or(0);
+    let hydrogen = counts.remove(&Element::H).unwrap_or(0);
+    let mut counts = counts.into_iter().map(|(element, count)| {
+        (element.to_string(), count)
+    }).collect::<BTreeMap<_, _>>();
+
+    let mut result = String::new();
+
+    if carbon > 0 {
+        push(&mut result, "C", carbon);
+        push(&mut result, "H", hydrogen)
+    } else if hydrogen > 0 {
+        counts.insert("H".to_string(), hydrogen);
+    }
+
+    for (symbol, count) in counts {
+        push(&mut result, &symbol, count)
+    }
+
+    result
+}
+
+fn push(result: &mut String, symbol: &str, count: usize) {
+    match count {
+        0 => (),
+        1 => result.push_str(symbol),
+        _ => result.push_str(&format!("{}{}", symbol, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(molecular_formula(&[ ]), "")
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(molecular_formula(&build("C")), "CH4")
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(molecular_formula(&build("c1ccccc1")), "C6H6")
+    }
+
+    #[test]
+    fn water() {
+        assert_eq!(molecular_formula(&build("O")), "H2O")
+    }
+
+    #[test]
+    fn explicit_hydrogen() {
+        assert_eq!(molecular_formula(&build("[H]C([H])([H])[H]")), "CH4")
+    }
+
+    #[test]
+    fn carbon_without_hydrogen() {
+        assert_eq!(molecular_formula(&build("O=C=O")), "CO2")
+    }
+
+    #[test]
+    fn sodium_chloride() {
+        assert_eq!(molecular_formula(&build("[Na+].[Cl-]")), "ClNa")
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(molecular_formula(&build("C*C")), "C2H6")
+    }
+
+    #[test]
+    fn star_only() {
+        assert_eq!(molecular_formula(&build("*")), "")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use crate::feature::Element;
+use super::Atom;
+
+/// Returns the average molecular weight of `atoms` in daltons, using
+/// standard atomic weights. Implicit and virtual hydrogens are counted.
+/// Stars contribute nothing, and isotope labels are ignored.
+pub fn molecular_weight(atoms: &[Atom]) -> f64 {
+    atoms.iter().fold(0., |sum, atom| {
+        let hydrogens = atom.suppressed_hydrogens() as f64;
+        let weight = match atom.kind.element() {
+            Some(element) => element.atomic_weight(),
+            None => 0.
+        };
+
+        sum + weight + hydrogens * Element::H.atomic_weight()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::build;
+    use super::*;
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-9
+    }
+
+    #[test]
+    fn empty() {
+        assert!(close(molecular_weight(&[ ]), 0.))
+    }
+
+    #[test]
+    fn water() {
+        assert!(close(molecular_weight(&build("O")), 18.015))
+    }
+
+    #[test]
+    fn ethanol() {
+        assert!(close(molecular_weight(&build("CCO")), 46.069))
+    }
+
+    #[test]
+    fn star() {
+        assert!(close(molecular_weight(&build("C*C")), 30.07))
+    }
+
+    #[test]
+    fn star_only() {
+        assert!(close(molecular_weight(&build("*")), 0.))
+    }
+}
\ No newline at end of file
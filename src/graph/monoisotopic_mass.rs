@@ -0,0 +1,134 @@
+use crate::feature::{ AtomKind, Element };
+use super::Atom;
+
+/// Returns the monoisotopic mass of `atoms` in daltons, as used in mass
+/// spectrometry. Unlabeled atoms and implicit and virtual hydrogens use
+/// the mass of their element's most abundant isotope. Labeled atoms use
+/// the exact mass of common isotopic labels such as `[2H]`, `[13C]`,
+/// `[15N]`, and `[18O]`, and otherwise their mass number. Stars
+/// contribute nothing.
+/// 
+/// ```
+/// use purr::graph::{ Builder, monoisotopic_mass };
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("O", &mut builder, None)?;
+/// 
+///     let mass = monoisotopic_mass(&builder.build().expect("atoms"));
+/// 
+///     assert!((mass - 18.010565).abs() < 1e-6);
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn monoisotopic_mass(atoms: &[Atom]) -> f64 {
+    atoms.iter().fold(0., |sum, atom| {
+        let hydrogens = atom.suppressed_hydrogens() as f64;
+        let mass = match atom.kind.element() {
+            Some(element) => match &atom.kind {
+                AtomKind::Bracket { isotope: Some(isotope), .. } =>
+                    isotope_mass(&element, isotope.into()),
+                _ => element.monoisotopic_mass()
+            },
+            None => 0.
+        };
+
+        sum + mass + hydrogens * Element::H.monoisotopic_mass()
+    })
+}
+
+fn isotope_mass(element: &Element, mass_number: u16) -> f64 {
+    let mass = element.monoisotopic_mass();
+
+    if mass.round() as u16 == mass_number {
+        return mass
+    }
+
+    match (element, mass_number) {
+        (Element::H, 2) => 2.014102,
+        (Element::H, 3) => 3.016049,
+        (Element::Li, 6) => 6.015123,
+        (Element::B, 10) => 10.012937,
+        (Element::C, 11) => 11.011434,
+        (Element::C, 13) => 13.003355,
+        (Element::C, 14) => 14.003242,
+        (Element::N, 15) => 15.000109,
+        (Element::O, 17) => 16.999132,
+        (Element::O, 18) => 17.99916,
+        (Element::F, 18) => 18.000938,
+        (Element::Si, 29) => 28.976495,
+        (Element::Si, 30) => 29.97377,
+        (Element::P, 32) => 31.973908,
+        (Element::S, 33) => 32.971459,
+        (Element::S, 34) => 33.967867,
+        (Element::S, 35) => 34.969032,
+        (Element::Cl, 37) => 36.965903,
+        (Element::Br, 81) => 80.916291,
+        (Element::I, 123) => 122.90559,
+        (Element::I, 125) => 124.904630,
+        (Element::I, 131) => 130.906126,
+        _ => mass_number as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::build;
+    use super::*;
+
+    fn mass(smiles: &str) -> f64 {
+        monoisotopic_mass(&build(smiles))
+    }
+
+    fn close(left: f64, right: f64) -> bool {
+        (left - right).abs() < 1e-6
+    }
+
+    #[test]
+    fn empty() {
+        assert!(close(monoisotopic_mass(&[ ]), 0.))
+    }
+
+    #[test]
+    fn star() {
+        assert!(close(mass("*"), 0.))
+    }
+
+    #[test]
+    fn water() {
+        assert!(close(mass("O"), 18.010565))
+    }
+
+    #[test]
+    fn methane() {
+        assert!(close(mass("C"), 16.0313))
+    }
+
+    #[test]
+    fn labeled_methane() {
+        assert!(close(mass("[13CH4]"), 17.034655))
+    }
+
+    #[test]
+    fn heavy_water() {
+        assert!(close(mass("[2H]O[2H]"), 20.023119))
+    }
+
+    #[test]
+    fn most_abundant_label() {
+        assert!(close(mass("[12CH4]"), mass("C")))
+    }
+
+    #[test]
+    fn unlisted_label() {
+        assert!(close(mass("[99C]"), 99.))
+    }
+
+    #[test]
+    fn chloride() {
+        assert!(close(mass("[Cl-]"), 34.968853))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use crate::feature::{ AtomKind, Element, VirtualHydrogen };
+use super::Atom;
+
+/// Removes formal charges from common acids and bases by adding or
+/// removing one virtual hydrogen. The rule set is deliberately small:
+///
+/// - Oxygen, sulfur, and nitrogen with a charge of -1 gain a hydrogen,
+///   turning carboxylates, alkoxides, and thiolates into their acids.
+/// - Nitrogen with a charge of +1 and at least one hydrogen loses one,
+///   turning ammonium ions into amines.
+///
+/// Atoms bonded to an atom of opposite charge are kept, so charge-separated
+/// groups such as nitro are unchanged. Quaternary ammonium, metal ions,
+/// and all other charges are also unchanged, so the result is not always
+/// neutral overall. Neutralized atoms are debracketed where possible.
+///
+/// ```
+/// use purr::graph::{ Builder, neutralize, to_smiles };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("CC(=O)[O-]", &mut builder, None)?;
+///
+///     let mut atoms = builder.build().expect("atoms");
+///
+///     neutralize(&mut atoms);
+///
+///     assert_eq!(to_smiles(&atoms).expect("smiles"), "CC(=O)O");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn neutralize(atoms: &mut [Atom]) {
+    let charges = atoms.iter().map(charge).collect::<Vec<_>>();
+    let mut changes = Vec::new();
+
+    for (id, atom) in atoms.iter().enumerate() {
+        let hydrogens = atom.suppressed_hydrogens();
+        let opposed = atom.bonds.iter()
+            .any(|bond| charges[bond.tid] * charges[id] < 0);
+
+        if opposed {
+            continue
+        }
+
+        match (atom.kind.element(), charges[id]) {
+            (Some(Element::O), -1) |
+            (Some(Element::S), -1) |
+            (Some(Element::N), -1) => changes.push((id, hydrogens + 1)),
+            (Some(Element::N), 1) if hydrogens > 0 =>
+                changes.push((id, hydrogens - 1)),
+            _ => ()
+        }
+    }
+
+    for (id, hydrogens) in changes {
+        let atom = &mut atoms[id];
+        let bond_order_sum = atom.bonds.iter()
+            .fold(0, |sum, bond| sum + bond.order());
+
+        if let AtomKind::Bracket { hcount, charge, .. } = &mut atom.kind {
+            *hcount = if hydrogens > 0 {
+                VirtualHydrogen::try_from(hydrogens).ok()
+            } else {
+                None
+            };
+            *charge = None
+        }
+
+        atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+            .debracket(bond_order_sum)
+    }
+}
+
+fn charge(atom: &Atom) -> i8 {
+    match &atom.kind {
+        AtomKind::Bracket { charge: Some(charge), .. } => charge.into(),
+        _ => 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn neutral(smiles: &str) -> String {
+        let mut atoms = build(smiles);
+
+        neutralize(&mut atoms);
+
+        to_smiles(&atoms).unwrap()
+    }
+
+    #[test]
+    fn acetate() {
+        assert_eq!(neutral("CC(=O)[O-]"), "CC(=O)O")
+    }
+
+    #[test]
+    fn ammonium() {
+        assert_eq!(neutral("[NH4+]"), "N")
+    }
+
+    #[test]
+    fn methylammonium() {
+        assert_eq!(neutral("C[NH3+]"), "CN")
+    }
+
+    #[test]
+    fn thiolate() {
+        assert_eq!(neutral("C[S-]"), "CS")
+    }
+
+    #[test]
+    fn phenolate() {
+        assert_eq!(neutral("c1ccccc1[O-]"), "c(c(cccc1)O)1")
+    }
+
+    #[test]
+    fn pyridinium() {
+        assert_eq!(neutral("c1cc[nH+]cc1"), "c(ccncc1)1")
+    }
+
+    #[test]
+    fn zwitterion() {
+        assert_eq!(neutral("[NH3+]CC(=O)[O-]"), "NCC(=O)O")
+    }
+
+    #[test]
+    fn nitro() {
+        assert_eq!(neutral("C[N+](=O)[O-]"), "C[N+](=O)[O-]")
+    }
+
+    #[test]
+    fn quaternary_ammonium() {
+        assert_eq!(neutral("C[N+](C)(C)C"), "C[N+](C)(C)C")
+    }
+
+    #[test]
+    fn sodium_acetate() {
+        assert_eq!(neutral("CC(=O)[O-].[Na+]"), "CC(=O)O.[Na+]")
+    }
+
+    #[test]
+    fn labeled() {
+        assert_eq!(neutral("C[18O-]"), "C[18OH]")
+    }
+}
\ No newline at end of file
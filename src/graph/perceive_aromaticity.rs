@@ -0,0 +1,386 @@
+use std::convert::TryFrom;
+
+use crate::feature::{
+    Aromatic, AtomKind, BondKind, BracketAromatic, BracketSymbol, Element,
+    VirtualHydrogen
+};
+use super::{ Atom, AromaticityModel, sssr };
+
+/// Marks the atoms and bonds of Hückel aromatic rings in a Kekulé `atoms`
+/// as aromatic. Each ring of the smallest set of smallest rings is
+/// aromatic when every atom can be aromatic and their pi electrons number
+/// 4n + 2, as counted by `model`. Rings are revisited until none change,
+/// so a double bond shared with an aromatic ring, as in naphthalene,
+/// counts one electron.
+///
+/// Ring atoms contribute one electron for a ring double bond. Otherwise,
+/// neutral nitrogen, phosphorus, and arsenic contribute two, as do neutral
+/// oxygen, sulfur, and selenium. Carbanions contribute two, and
+/// carbocations and neutral boron none. Rings containing atoms already
+/// marked aromatic are left unchanged, as are larger rings made only of
+/// fused smaller ones, such as the periphery of azulene.
+///
+/// Ring bonds become elided, and elided bonds joining aromatic rings
+/// become single. Hydrogen counts are kept, so atoms whose aromatic
+/// symbols would imply a different count, like pyrrole nitrogen, are
+/// bracketed.
+///
+/// ```
+/// use purr::graph::{
+///     Builder, AromaticityModel, perceive_aromaticity, to_smiles
+/// };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("C1=CC=CN1", &mut builder, None)?;
+///
+///     let mut atoms = builder.build().expect("atoms");
+///
+///     perceive_aromaticity(&mut atoms, &AromaticityModel::OpenSmiles);
+///
+///     assert_eq!(to_smiles(&atoms).expect("smiles"), "c([nH]ccc1)1");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn perceive_aromaticity(atoms: &mut [Atom], model: &AromaticityModel) {
+    let rings = sssr(atoms);
+    let mut aromatic = vec![ false; atoms.len() ];
+    let mut perceived = vec![ false; rings.len() ];
+
+    loop {
+        let mut changed = false;
+
+        for (index, ring) in rings.iter().enumerate() {
+            if perceived[index] {
+                continue
+            }
+
+            if let Some(electrons) = ring_electrons(
+                atoms, ring, &aromatic, model
+            ) {
+                if electrons % 4 == 2 {
+                    for &id in ring {
+                        aromatic[id] = true
+                    }
+
+                    perceived[index] = true;
+                    changed = true
+                }
+            }
+        }
+
+        if !changed {
+            break
+        }
+    }
+
+    let hydrogens = atoms.iter()
+        .map(|atom| atom.suppressed_hydrogens()).collect::<Vec<_>>();
+
+    for (ring, found) in rings.iter().zip(perceived) {
+        if !found {
+            continue
+        }
+
+        for (index, &sid) in ring.iter().enumerate() {
+            let tid = ring[(index + 1) % ring.len()];
+
+            set_bond_kind(atoms, sid, tid, BondKind::Elided);
+            set_bond_kind(atoms, tid, sid, BondKind::Elided)
+        }
+    }
+
+    for (sid, atom) in atoms.iter_mut().enumerate() {
+        if !aromatic[sid] {
+            continue
+        }
+
+        for bond in atom.bonds.iter_mut() {
+            if aromatic[bond.tid] && bond.kind == BondKind::Elided &&
+                !rings_share_edge(&rings, sid, bond.tid) {
+                bond.kind = BondKind::Single
+            }
+        }
+
+        aromatize(atom, hydrogens[sid])
+    }
+}
+
+fn ring_electrons(
+    atoms: &[Atom],
+    ring: &[usize],
+    aromatic: &[bool],
+    model: &AromaticityModel
+) -> Option<u8> {
+    let mut result = 0;
+
+    for (index, &sid) in ring.iter().enumerate() {
+        let previous = ring[(index + ring.len() - 1) % ring.len()];
+        let next = ring[(index + 1) % ring.len()];
+
+        result += atom_electrons(atoms, sid, previous, next, aromatic, model)?
+    }
+
+    Some(result)
+}
+
+fn atom_electrons(
+    atoms: &[Atom],
+    sid: usize,
+    previous: usize,
+    next: usize,
+    aromatic: &[bool],
+    model: &AromaticityModel
+) -> Option<u8> {
+    let atom = &atoms[sid];
+
+    if atom.is_aromatic() {
+        return None
+    }
+
+    let element = atom.kind.element()?;
+
+    BracketAromatic::try_from(&element).ok()?;
+
+    let mut ring_doubles = 0;
+    let mut exocyclic = None;
+
+    for bond in atom.bonds.iter() {
+        match bond.order() {
+            1 => (),
+            2 if bond.tid == previous || bond.tid == next =>
+                ring_doubles += 1,
+            2 if exocyclic.is_none() => exocyclic = Some(bond.tid),
+            _ => return None
+        }
+    }
+
+    match (ring_doubles, exocyclic) {
+        (1, None) => Some(1),
+        (0, Some(tid)) => {
+            if aromatic[tid] {
+                Some(1)
+            } else if model == &AromaticityModel::Daylight &&
+                is_electronegative(&atoms[tid]) {
+                Some(0)
+            } else {
+                None
+            }
+        },
+        (0, None) => lone_pair_electrons(&atom.kind, element),
+        _ => None
+    }
+}
+
+fn lone_pair_electrons(kind: &AtomKind, element: Element) -> Option<u8> {
+    let charge = match kind {
+        AtomKind::Bracket { charge: Some(charge), .. } => charge.into(),
+        _ => 0
+    };
+
+    match (element, charge) {
+        (Element::C, -1) => Some(2),
+        (Element::C, 1) => Some(0),
+        (Element::B, 0) => Some(0),
+        (Element::N, 0) |
+        (Element::P, 0) |
+        (Element::As, 0) |
+        (Element::O, 0) |
+        (Element::S, 0) |
+        (Element::Se, 0) => Some(2),
+        _ => None
+    }
+}
+
+fn is_electronegative(atom: &Atom) -> bool {
+    matches!(
+        atom.kind.element(), Some(Element::N | Element::O | Element::S)
+    )
+}
+
+fn rings_share_edge(rings: &[Vec<usize>], sid: usize, tid: usize) -> bool {
+    rings.iter().any(|ring| {
+        ring.iter().enumerate().any(|(index, &id)| {
+            let next = ring[(index + 1) % ring.len()];
+
+            (id == sid && next == tid) || (id == tid && next == sid)
+        })
+    })
+}
+
+fn set_bond_kind(
+    atoms: &mut [Atom], sid: usize, tid: usize, kind: BondKind
+) {
+    for bond in atoms[sid].bonds.iter_mut() {
+        if bond.tid == tid {
+            bond.kind = kind.clone()
+        }
+    }
+}
+
+fn aromatize(atom: &mut Atom, hydrogens: u8) {
+    let element = atom.kind.element().expect("element");
+    let symbol = BracketAromatic::try_from(&element).expect("aromatic");
+
+    atom.kind = match std::mem::replace(&mut atom.kind, AtomKind::Star) {
+        AtomKind::Bracket {
+            isotope, configuration, hcount, charge, map, ..
+        } => AtomKind::Bracket {
+            isotope,
+            symbol: BracketSymbol::Aromatic(symbol.clone()),
+            configuration,
+            hcount,
+            charge,
+            map
+        },
+        _ => match Aromatic::try_from(&symbol) {
+            Ok(aromatic) => AtomKind::Aromatic(aromatic),
+            Err(_) => unreachable!("organic subset atom")
+        }
+    };
+
+    if atom.suppressed_hydrogens() != hydrogens {
+        atom.kind = AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Aromatic(symbol),
+            configuration: None,
+            hcount: if hydrogens > 0 {
+                VirtualHydrogen::try_from(hydrogens).ok()
+            } else {
+                None
+            },
+            charge: None,
+            map: None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn perceive(smiles: &str, model: &AromaticityModel) -> String {
+        let mut atoms = build(smiles);
+
+        perceive_aromaticity(&mut atoms, model);
+
+        to_smiles(&atoms).unwrap()
+    }
+
+    fn both(smiles: &str) -> String {
+        let daylight = perceive(smiles, &AromaticityModel::Daylight);
+
+        assert_eq!(perceive(smiles, &AromaticityModel::OpenSmiles), daylight);
+
+        daylight
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(both("C1=CC=CC=C1"), "c(ccccc1)1")
+    }
+
+    #[test]
+    fn toluene() {
+        assert_eq!(both("CC1=CC=CC=C1"), "Cc(ccccc1)1")
+    }
+
+    #[test]
+    fn furan() {
+        assert_eq!(both("C1=CC=CO1"), "c(occc1)1")
+    }
+
+    #[test]
+    fn thiophene() {
+        assert_eq!(both("C1=CC=CS1"), "c(sccc1)1")
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(both("C1=CC=CN1"), "c([nH]ccc1)1")
+    }
+
+    #[test]
+    fn n_methylpyrrole() {
+        assert_eq!(both("CN1C=CC=C1"), "Cn(cccc1)1")
+    }
+
+    #[test]
+    fn pyridine() {
+        assert_eq!(both("C1=CC=NC=C1"), "c(ccncc1)1")
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(both("C1=CC=C2C=CC=CC2=C1"), "c(cc(ccccc(cc1)2)2)1")
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(
+            both("C1=CC=C(C=C1)C2=CC=CC=C2"),
+            "c(ccc(cc1)-c(ccccc2)2)1"
+        )
+    }
+
+    #[test]
+    fn cyclopentadienyl_anion() {
+        assert_eq!(both("[CH-]1C=CC=C1"), "[cH-](cccc1)1")
+    }
+
+    #[test]
+    fn tropylium() {
+        assert_eq!(both("[CH+]1C=CC=CC=C1"), "[cH+](cccccc1)1")
+    }
+
+    #[test]
+    fn cyclohexene() {
+        assert_eq!(both("C1=CCCCC1"), "C(CCCCC=1)=1")
+    }
+
+    #[test]
+    fn cyclopentadiene() {
+        assert_eq!(both("C1=CC=CC1"), "C(CC=CC=1)=1")
+    }
+
+    #[test]
+    fn cyclooctatetraene() {
+        assert_eq!(both("C1=CC=CC=CC=C1"), "C(C=CC=CC=CC=1)=1")
+    }
+
+    #[test]
+    fn already_aromatic() {
+        assert_eq!(both("c1ccccc1"), "c(ccccc1)1")
+    }
+
+    #[test]
+    fn pyridone_opensmiles() {
+        assert_eq!(
+            perceive("O=C1C=CC=CN1", &AromaticityModel::OpenSmiles),
+            "O=C(NC=CC=C1)1"
+        )
+    }
+
+    #[test]
+    fn pyridone_daylight() {
+        assert_eq!(
+            perceive("O=C1C=CC=CN1", &AromaticityModel::Daylight),
+            "O=c([nH]cccc1)1"
+        )
+    }
+
+    #[test]
+    fn methylenecyclopentadiene() {
+        assert_eq!(both("C=C1C=CC=C1"), "C=C(C=CC=C1)1")
+    }
+}
\ No newline at end of file
@@ -9,9 +9,8 @@ pub fn reconcile(
         } else {
             Some((left, right))
         }
-    } else if left == BondKind::Up && right == BondKind::Down {
-        Some((left, right))
-    } else if left == BondKind::Down && right == BondKind::Up {
+    } else if (left == BondKind::Up && right == BondKind::Down) ||
+        (left == BondKind::Down && right == BondKind::Up) {
         Some((left, right))
     } else if left == BondKind::Elided {
         match right {
@@ -123,6 +122,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn quadruple_elided() {
+        assert_eq!(
+            reconcile(BondKind::Quadruple, BondKind::Elided),
+            Some((BondKind::Quadruple, BondKind::Quadruple))
+        )
+    }
+
     #[test]
     fn single_elided() {
         assert_eq!(
@@ -130,4 +137,25 @@ mod tests {
             Some((BondKind::Single, BondKind::Single))
         )
     }
+
+    #[test]
+    fn aromatic_elided() {
+        assert_eq!(
+            reconcile(BondKind::Aromatic, BondKind::Elided),
+            Some((BondKind::Aromatic, BondKind::Aromatic))
+        )
+    }
+
+    #[test]
+    fn elided_aromatic() {
+        assert_eq!(
+            reconcile(BondKind::Elided, BondKind::Aromatic),
+            Some((BondKind::Aromatic, BondKind::Aromatic))
+        )
+    }
+
+    #[test]
+    fn aromatic_single() {
+        assert_eq!(reconcile(BondKind::Aromatic, BondKind::Single), None)
+    }
 }
\ No newline at end of file
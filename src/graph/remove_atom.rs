@@ -0,0 +1,100 @@
+use super::Atom;
+
+/// Removes the atom at `id`, dropping bonds to it from its neighbors and
+/// decrementing all bond targets greater than `id`.
+/// 
+/// # Panics
+/// 
+/// Panics if `id` is out of bounds.
+pub fn remove_atom(atoms: &mut Vec<Atom>, id: usize) {
+    atoms.remove(id);
+
+    for atom in atoms.iter_mut() {
+        atom.bonds.retain(|bond| bond.tid != id);
+
+        for bond in atom.bonds.iter_mut() {
+            if bond.tid > id {
+                bond.tid -= 1
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::Bond;
+    use crate::feature::{ AtomKind, BondKind, Aliphatic };
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let mut atoms = build("C");
+
+        remove_atom(&mut atoms, 1)
+    }
+
+    #[test]
+    fn terminal() {
+        let mut atoms = build("CCO");
+
+        remove_atom(&mut atoms, 2);
+
+        assert_eq!(atoms, vec![
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0)
+                ]
+            }
+        ])
+    }
+
+    #[test]
+    fn head() {
+        let mut atoms = build("OCC");
+
+        remove_atom(&mut atoms, 0);
+
+        assert_eq!(atoms, vec![
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0)
+                ]
+            }
+        ])
+    }
+
+    #[test]
+    fn middle() {
+        let mut atoms = build("CCC");
+
+        remove_atom(&mut atoms, 1);
+
+        assert_eq!(atoms, vec![
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ ]
+            }
+        ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+use std::mem;
+
+use crate::feature::{ AtomKind, Configuration };
+use super::{ Atom, stereocenter, symmetry_classes };
+
+/// Clears the tetrahedral configuration of every atom that fails
+/// `is_stereocenter`, debracketing it where possible. Centers that are
+/// stereogenic only together, such as the ring centers of
+/// 1,4-dimethylcyclohexane, are kept. Other configuration classes are left
+/// alone.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn remove_nonstereogenic(atoms: &mut [Atom]) {
+    let classes = symmetry_classes(atoms, false);
+    let ids = (0..atoms.len()).filter(|&id| {
+        is_tetrahedral(&atoms[id].kind) && !stereocenter(atoms, id, &classes)
+    }).collect::<Vec<_>>();
+
+    for id in ids {
+        let atom = &mut atoms[id];
+        let bond_order_sum = atom.bonds.iter()
+            .fold(0, |sum, bond| sum + bond.order());
+
+        if let AtomKind::Bracket { configuration, .. } = &mut atom.kind {
+            configuration.take();
+        }
+
+        atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+            .debracket(bond_order_sum)
+    }
+}
+
+fn is_tetrahedral(kind: &AtomKind) -> bool {
+    match kind {
+        AtomKind::Bracket { configuration: Some(configuration), .. } =>
+            *configuration == Configuration::TH1
+                || *configuration == Configuration::TH2,
+        _ => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::walk::walk;
+    use crate::write::Writer;
+    use crate::test_support::build;
+    use super::*;
+
+    fn normalize(smiles: &str) -> String {
+        let mut writer = Writer::new();
+        let mut atoms = build(smiles);
+
+        remove_nonstereogenic(&mut atoms);
+        walk(atoms, &mut writer).unwrap();
+
+        writer.write()
+    }
+
+    #[test]
+    fn empty() {
+        let mut atoms = vec![ ];
+
+        remove_nonstereogenic(&mut atoms);
+
+        assert_eq!(atoms, vec![ ])
+    }
+
+    #[test]
+    fn two_methyls() {
+        assert_eq!(normalize("[C@](C)(C)(F)Cl"), "C(C)(C)(F)Cl")
+    }
+
+    #[test]
+    fn two_hydrogens() {
+        assert_eq!(normalize("F[C@H2]Cl"), "FCCl")
+    }
+
+    #[test]
+    fn genuine() {
+        assert_eq!(normalize("[C@](F)(Cl)(Br)I"), "[C@](F)(Cl)(Br)I")
+    }
+
+    #[test]
+    fn genuine_with_hydrogen() {
+        assert_eq!(normalize("N[C@@H](C)C(=O)O"), "N[C@@H](C)C(=O)O")
+    }
+
+    #[test]
+    fn charged_neighbor() {
+        assert_eq!(normalize("C[C@H](C)[NH3+]"), "CC(C)[NH3+]")
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(
+            normalize("C[C@H](C)[C@@H](F)Cl"),
+            "CC(C)[C@@H](F)Cl"
+        )
+    }
+
+    #[test]
+    fn ring_cis() {
+        assert_eq!(
+            normalize("C[C@H]1CC[C@@H](C)CC1"),
+            "C[C@H](CC[C@@H](CC1)C)1"
+        )
+    }
+
+    #[test]
+    fn ring_trans() {
+        assert_eq!(
+            normalize("C[C@H]1CC[C@H](C)CC1"),
+            "C[C@H](CC[C@H](CC1)C)1"
+        )
+    }
+
+    #[test]
+    fn ring_partner_unmarked() {
+        assert_eq!(normalize("C[C@H]1CCC(C)CC1"), "CC(CCC(CC1)C)1")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use std::mem;
+
+use crate::feature::{ AtomKind, BondKind };
+use super::Atom;
+
+/// Clears every atom configuration, debracketing atoms where possible, and
+/// replaces directional bonds with non-directional ones. A directional
+/// bond becomes `Single` between two aromatic atoms, where an elided bond
+/// would read as aromatic, and `Elided` otherwise.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn remove_stereo(atoms: &mut [Atom]) {
+    let aromatic = atoms.iter()
+        .map(|atom| atom.is_aromatic()).collect::<Vec<_>>();
+
+    for (sid, atom) in atoms.iter_mut().enumerate() {
+        for bond in atom.bonds.iter_mut() {
+            if bond.kind == BondKind::Up || bond.kind == BondKind::Down {
+                bond.kind = if aromatic[sid] && aromatic[bond.tid] {
+                    BondKind::Single
+                } else {
+                    BondKind::Elided
+                }
+            }
+        }
+
+        match &mut atom.kind {
+            AtomKind::Bracket { configuration, .. }
+                if configuration.is_some() => configuration.take(),
+            _ => continue
+        };
+
+        let bond_order_sum = atom.bonds.iter()
+            .fold(0, |sum, bond| sum + bond.order());
+
+        atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+            .debracket(bond_order_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use crate::test_support::build;
+    use super::*;
+
+    fn flatten(smiles: &str) -> String {
+        let mut atoms = build(smiles);
+
+        remove_stereo(&mut atoms);
+
+        to_smiles(&atoms).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        let mut atoms = vec![ ];
+
+        remove_stereo(&mut atoms);
+
+        assert_eq!(atoms, vec![ ])
+    }
+
+    #[test]
+    fn trans_alkene() {
+        assert_eq!(flatten("F/C=C/F"), "FC=CF")
+    }
+
+    #[test]
+    fn cis_alkene() {
+        assert_eq!(flatten("F/C=C\\F"), "FC=CF")
+    }
+
+    #[test]
+    fn tetrahedral() {
+        assert_eq!(flatten("[C@](F)(Cl)(Br)I"), "C(F)(Cl)(Br)I")
+    }
+
+    #[test]
+    fn tetrahedral_hydrogen() {
+        assert_eq!(flatten("N[C@@H](C)C(=O)O"), "NC(C)C(=O)O")
+    }
+
+    #[test]
+    fn bracket_kept() {
+        assert_eq!(flatten("[13C@H](F)(Cl)Br"), "[13CH](F)(Cl)Br")
+    }
+
+    #[test]
+    fn directional_ring_closure() {
+        assert_eq!(flatten("C/1=C/CCCC1"), "C(CCCCC=1)=1")
+    }
+
+    #[test]
+    fn directional_between_aromatics() {
+        assert_eq!(flatten("c1ccccc1/c1ccccc1"), "c(c(cccc1)-c(ccccc2)2)1")
+    }
+
+    #[test]
+    fn no_stereo() {
+        assert_eq!(flatten("CC(=O)O"), "CC(=O)O")
+    }
+}
\ No newline at end of file
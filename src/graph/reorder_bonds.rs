@@ -0,0 +1,33 @@
+use crate::feature::AtomKind;
+use super::Atom;
+
+/// Reorders the bonds of `atom` so that the bond at position `i` was
+/// previously at position `order[i]`, updating its configuration to
+/// describe the same spatial arrangement as `AtomKind::permute_neighbors`
+/// allows. A virtual hydrogen keeps its place ahead of the bonds.
+///
+/// # Panics
+///
+/// Panics given an `order` that is not a permutation of the bond
+/// positions.
+pub(crate) fn reorder_bonds(atom: &mut Atom, order: &[usize]) {
+    let permutation = if has_virtual_hydrogen(&atom.kind) {
+        std::iter::once(0).chain(order.iter().map(|index| index + 1))
+            .collect::<Vec<_>>()
+    } else {
+        order.to_vec()
+    };
+    let mut bonds = std::mem::take(&mut atom.bonds).into_iter()
+        .map(Some).collect::<Vec<_>>();
+
+    atom.kind.permute_neighbors(&permutation);
+    atom.bonds = order.iter()
+        .map(|&index| bonds[index].take().expect("bond")).collect()
+}
+
+fn has_virtual_hydrogen(kind: &AtomKind) -> bool {
+    match kind {
+        AtomKind::Bracket { hcount: Some(hcount), .. } => !hcount.is_zero(),
+        _ => false
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use super::{ Atom, breadth_first };
+
+/// Returns the number of rings: the cyclomatic number, bonds minus atoms
+/// plus connected components. This equals the size of `sssr`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn ring_count(atoms: &[Atom]) -> usize {
+    let bonds = atoms.iter().enumerate().map(|(sid, atom)| {
+        atom.bonds.iter().filter(|bond| sid < bond.tid).count()
+    }).sum::<usize>();
+
+    (bonds + components(atoms)).saturating_sub(atoms.len())
+}
+
+fn components(atoms: &[Atom]) -> usize {
+    let mut visited = vec![ false; atoms.len() ];
+    let mut result = 0;
+
+    for start in 0..atoms.len() {
+        if visited[start] {
+            continue
+        }
+
+        breadth_first(atoms, start, &mut visited);
+
+        result += 1
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn count(smiles: &str) -> usize {
+        ring_count(&build(smiles))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ring_count(&[ ]), 0)
+    }
+
+    #[test]
+    fn hexane() {
+        assert_eq!(count("CCCCCC"), 0)
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(count("c1ccc2ccccc2c1"), 2)
+    }
+
+    #[test]
+    fn decalin() {
+        assert_eq!(count("C1CCC2CCCCC2C1"), 2)
+    }
+
+    #[test]
+    fn two_components() {
+        assert_eq!(count("C1CC1.C1CCC1.C"), 2)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+use crate::feature::{ BondKind, Element };
+use super::{ Atom, Bond };
+
+/// Returns the rotatable bonds of `atoms` as `(sid, tid)` pairs with
+/// `sid < tid`, in order. A rotatable bond is a single bond outside of
+/// every ring in `rings` that joins two heavy atoms, each of which has at
+/// least one other heavy neighbor. Bonds between two aromatic atoms count
+/// only when written explicitly, as in biphenyl. Stars count as heavy
+/// atoms. `rings` is typically the result of `sssr`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn rotatable_bonds(
+    atoms: &[Atom], rings: &[Vec<usize>]
+) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            let tid = bond.tid;
+
+            if sid < tid
+                && is_single(atoms, sid, bond)
+                && !in_ring(rings, sid, tid)
+                && is_internal(atoms, sid)
+                && is_internal(atoms, tid) {
+                result.push((sid, tid))
+            }
+        }
+    }
+
+    result
+}
+
+fn is_single(atoms: &[Atom], sid: usize, bond: &Bond) -> bool {
+    match bond.kind {
+        BondKind::Single | BondKind::Up | BondKind::Down => true,
+        BondKind::Elided =>
+            !(atoms[sid].is_aromatic() && atoms[bond.tid].is_aromatic()),
+        _ => false
+    }
+}
+
+fn in_ring(rings: &[Vec<usize>], sid: usize, tid: usize) -> bool {
+    rings.iter().any(|ring| {
+        (0..ring.len()).any(|index| {
+            let left = ring[index];
+            let right = ring[(index + 1) % ring.len()];
+
+            (left == sid && right == tid) || (left == tid && right == sid)
+        })
+    })
+}
+
+fn is_heavy(atom: &Atom) -> bool {
+    atom.kind.element() != Some(Element::H)
+}
+
+fn is_internal(atoms: &[Atom], id: usize) -> bool {
+    is_heavy(&atoms[id]) && atoms[id].bonds.iter()
+        .filter(|bond| is_heavy(&atoms[bond.tid])).count() > 1
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::sssr;
+    use crate::test_support::build;
+    use super::*;
+
+    fn rotatable(smiles: &str) -> Vec<(usize, usize)> {
+        let atoms = build(smiles);
+
+        rotatable_bonds(&atoms, &sssr(&atoms))
+    }
+
+    #[test]
+    fn ethane() {
+        assert_eq!(rotatable("CC"), vec![ ])
+    }
+
+    #[test]
+    fn butane() {
+        assert_eq!(rotatable("CCCC"), vec![ (1, 2) ])
+    }
+
+    #[test]
+    fn butane_explicit_hydrogens() {
+        assert_eq!(rotatable("[H]CCCC[H]"), vec![ (2, 3) ])
+    }
+
+    #[test]
+    fn butene() {
+        assert_eq!(rotatable("CC=CC"), vec![ ])
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(rotatable("c1ccccc1"), vec![ ])
+    }
+
+    #[test]
+    fn cyclohexane() {
+        assert_eq!(rotatable("C1CCCCC1"), vec![ ])
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(rotatable("c1ccccc1-c1ccccc1"), vec![ (5, 6) ])
+    }
+
+    #[test]
+    fn ethylbenzene() {
+        assert_eq!(rotatable("CCc1ccccc1"), vec![ (1, 2) ])
+    }
+}
\ No newline at end of file
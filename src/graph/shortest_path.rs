@@ -0,0 +1,101 @@
+use super::{ Atom, breadth_first };
+
+/// Returns the atom ids along a shortest path from `sid` to `tid`,
+/// inclusive, ignoring bond order. Returns `None` if no path exists or
+/// either id is outside of `atoms`.
+/// 
+/// ```
+/// use purr::graph::{ Builder, shortest_path };
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("C1CCCC1", &mut builder, None)?;
+/// 
+///     let atoms = builder.build().expect("atoms");
+/// 
+///     assert_eq!(shortest_path(&atoms, 1, 4), Some(vec![ 1, 0, 4 ]));
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn shortest_path(
+    atoms: &[Atom], sid: usize, tid: usize
+) -> Option<Vec<usize>> {
+    if sid >= atoms.len() || tid >= atoms.len() {
+        return None
+    }
+
+    let mut parents = vec![ None; atoms.len() ];
+
+    for (id, parent, _) in breadth_first(
+        atoms, sid, &mut vec![ false; atoms.len() ]
+    ) {
+        parents[id] = Some(parent)
+    }
+
+    let mut result = vec![ tid ];
+    let mut head = tid;
+
+    while head != sid {
+        head = parents[head]?;
+
+        result.push(head)
+    }
+
+    result.reverse();
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn unknown_source() {
+        assert_eq!(shortest_path(&build("CC"), 2, 0), None)
+    }
+
+    #[test]
+    fn unknown_target() {
+        assert_eq!(shortest_path(&build("CC"), 0, 2), None)
+    }
+
+    #[test]
+    fn same() {
+        assert_eq!(shortest_path(&build("CC"), 1, 1), Some(vec![ 1 ]))
+    }
+
+    #[test]
+    fn butane() {
+        assert_eq!(
+            shortest_path(&build("CCCC"), 0, 3),
+            Some(vec![ 0, 1, 2, 3 ])
+        )
+    }
+
+    #[test]
+    fn butane_reversed() {
+        assert_eq!(
+            shortest_path(&build("CCCC"), 3, 0),
+            Some(vec![ 3, 2, 1, 0 ])
+        )
+    }
+
+    #[test]
+    fn branched() {
+        assert_eq!(
+            shortest_path(&build("CC(CC)CO"), 3, 5),
+            Some(vec![ 3, 2, 1, 4, 5 ])
+        )
+    }
+
+    #[test]
+    fn disconnected() {
+        assert_eq!(shortest_path(&build("C.C"), 0, 1), None)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use super::{ Atom, breadth_first, ring_count };
+
+/// Returns a smallest set of smallest rings: a minimum cycle basis with
+/// as many rings as the graph's cyclomatic number. Each ring lists atom
+/// ids in bonding order, starting from its lowest id and continuing toward
+/// the lower of that atom's two ring neighbors. Rings are ordered by size,
+/// then by their atom ids.
+/// 
+/// Candidate rings are Horton cycles, so the result is a minimum basis.
+/// Where several exist, as with the faces of cubane, the choice among
+/// equally small rings is arbitrary but deterministic.
+/// 
+/// ```
+/// use purr::graph::{ Builder, sssr };
+/// use purr::read::{ read, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("C1CC2CCC1C2", &mut builder, None)?;
+/// 
+///     let atoms = builder.build().expect("atoms");
+/// 
+///     assert_eq!(sssr(&atoms), vec![
+///         vec![ 0, 1, 2, 6, 5 ],
+///         vec![ 2, 3, 4, 5, 6 ]
+///     ]);
+/// 
+///     Ok(())
+/// }
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn sssr(atoms: &[Atom]) -> Vec<Vec<usize>> {
+    let mut edges = BTreeMap::new();
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            if sid < bond.tid {
+                let index = edges.len();
+
+                edges.entry((sid, bond.tid)).or_insert(index);
+            }
+        }
+    }
+
+    let size = ring_count(atoms);
+    let mut candidates = Vec::new();
+
+    if size == 0 {
+        return candidates
+    }
+
+    for root in 0..atoms.len() {
+        let parents = parents(atoms, root);
+
+        for &(sid, tid) in edges.keys() {
+            if let Some(ring) = horton(&parents, root, sid, tid) {
+                candidates.push(normalize(ring))
+            }
+        }
+    }
+
+    candidates.sort_unstable_by(|left, right| {
+        left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+    });
+    candidates.dedup();
+
+    let mut rows = vec![ None; edges.len() ];
+    let mut result = Vec::new();
+
+    for ring in candidates {
+        let mut vector = vec![ false; edges.len() ];
+
+        for (index, &sid) in ring.iter().enumerate() {
+            let tid = ring[(index + 1) % ring.len()];
+
+            vector[edges[&(sid.min(tid), sid.max(tid))]] = true
+        }
+
+        if reduce(&mut rows, vector) {
+            result.push(ring);
+
+            if result.len() == size {
+                break
+            }
+        }
+    }
+
+    result
+}
+
+fn parents(atoms: &[Atom], root: usize) -> Vec<Option<usize>> {
+    let mut result = vec![ None; atoms.len() ];
+
+    for (id, parent, _) in breadth_first(
+        atoms, root, &mut vec![ false; atoms.len() ]
+    ) {
+        result[id] = Some(parent)
+    }
+
+    result
+}
+
+// The ring formed by the tree paths from root to sid and tid, closed by
+// the sid-tid bond, if the paths meet only at root.
+fn horton(
+    parents: &[Option<usize>], root: usize, sid: usize, tid: usize
+) -> Option<Vec<usize>> {
+    if parents[sid] == Some(tid) || parents[tid] == Some(sid) {
+        return None
+    }
+
+    let left = path(parents, root, sid)?;
+    let right = path(parents, root, tid)?;
+
+    if left[1..].iter().any(|id| right[1..].contains(id)) {
+        return None
+    }
+
+    let mut result = left;
+
+    result.extend(right.into_iter().skip(1).rev());
+
+    Some(result)
+}
+
+// The tree path from root to id, inclusive.
+fn path(
+    parents: &[Option<usize>], root: usize, id: usize
+) -> Option<Vec<usize>> {
+    let mut result = vec![ id ];
+    let mut head = id;
+
+    while head != root {
+        head = parents[head]?;
+
+        result.push(head)
+    }
+
+    result.reverse();
+
+    Some(result)
+}
+
+fn normalize(mut ring: Vec<usize>) -> Vec<usize> {
+    let start = ring.iter().enumerate()
+        .min_by_key(|(_, &id)| id).map(|(index, _)| index).expect("start");
+
+    ring.rotate_left(start);
+
+    if ring.len() > 2 && ring[ring.len() - 1] < ring[1] {
+        ring[1..].reverse()
+    }
+
+    ring
+}
+
+// Adds vector to the row-reduced basis rows, returning false if it is a
+// linear combination of rows already present.
+fn reduce(rows: &mut [Option<Vec<bool>>], mut vector: Vec<bool>) -> bool {
+    while let Some(pivot) = vector.iter().position(|&bit| bit) {
+        match &rows[pivot] {
+            Some(row) => {
+                for (bit, other) in vector.iter_mut().zip(row.iter()) {
+                    *bit ^= other
+                }
+            },
+            None => {
+                rows[pivot] = Some(vector);
+
+                return true
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn rings(smiles: &str) -> Vec<Vec<usize>> {
+        sssr(&build(smiles))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sssr(&[ ]), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn acyclic() {
+        assert_eq!(rings("CC(C)CO.N"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn cyclopropane() {
+        assert_eq!(rings("C1CC1"), vec![ vec![ 0, 1, 2 ] ])
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(rings("c1ccccc1"), vec![ vec![ 0, 1, 2, 3, 4, 5 ] ])
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(rings("c1ccc2ccccc2c1"), vec![
+            vec![ 0, 1, 2, 3, 8, 9 ],
+            vec![ 3, 4, 5, 6, 7, 8 ]
+        ])
+    }
+
+    #[test]
+    fn bicyclobutane() {
+        assert_eq!(rings("C1C2C1C2"), vec![
+            vec![ 0, 1, 2 ],
+            vec![ 1, 2, 3 ]
+        ])
+    }
+
+    #[test]
+    fn spiro() {
+        assert_eq!(rings("C1CC12CC2"), vec![
+            vec![ 0, 1, 2 ],
+            vec![ 2, 3, 4 ]
+        ])
+    }
+
+    #[test]
+    fn cubane() {
+        let result = rings("C12C3C4C1C5C2C3C45");
+
+        assert_eq!(result.len(), 5);
+        assert!(result.iter().all(|ring| ring.len() == 4))
+    }
+
+    #[test]
+    fn two_components() {
+        assert_eq!(rings("C1CC1.C1CC1"), vec![
+            vec![ 0, 1, 2 ],
+            vec![ 3, 4, 5 ]
+        ])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,276 @@
+use crate::feature::{ AtomKind, BondKind };
+use super::{ Atom, Bond, SubstructureOptions };
+
+/// A query graph that `search` maps onto a haystack. Query atoms are
+/// identified by index, and each has an ordered list of bonded targets.
+pub(crate) trait Query {
+    /// Returns the number of query atoms.
+    fn size(&self) -> usize;
+
+    /// Returns the number of bonds on query atom `qid`.
+    fn degree(&self, qid: usize) -> usize;
+
+    /// Returns the target of bond `index` on query atom `qid`.
+    fn target(&self, qid: usize, index: usize) -> usize;
+
+    /// Returns true if query atom `qid` matches haystack atom `hid`.
+    fn atom_matches(&self, qid: usize, haystack: &[Atom], hid: usize) -> bool;
+
+    /// Returns true if bond `index` on query atom `qid` matches `bond` on
+    /// haystack atom `hid`.
+    fn bond_matches(
+        &self,
+        qid: usize,
+        index: usize,
+        haystack: &[Atom],
+        hid: usize,
+        bond: &Bond
+    ) -> bool;
+}
+
+/// Calls `found` with each mapping from `pattern` atom index to `haystack`
+/// atom index, stopping early when `found` returns false. Atoms match on
+/// element, charge, isotope (if given in the pattern), and optionally
+/// aromaticity. Pattern stars match any atom. Bonds match on order and
+/// optionally aromaticity; without aromaticity, aromatic bonds match
+/// single or double bonds.
+pub(crate) fn substructure(
+    haystack: &[Atom],
+    pattern: &[Atom],
+    options: &SubstructureOptions,
+    found: &mut dyn FnMut(&[usize]) -> bool
+) {
+    search(haystack, &Pattern { atoms: pattern, options }, found)
+}
+
+/// Calls `found` with each mapping from `query` atom index to `haystack`
+/// atom index, stopping early when `found` returns false.
+pub(crate) fn search(
+    haystack: &[Atom],
+    query: &dyn Query,
+    found: &mut dyn FnMut(&[usize]) -> bool
+) {
+    let order = order(query);
+    let mut mapping = vec![ None; query.size() ];
+    let mut used = vec![ false; haystack.len() ];
+
+    extend(haystack, query, &order, &mut mapping, &mut used, found);
+}
+
+/// Returns true if `bond` on atom `sid` is aromatic, whether written with
+/// `:` or elided between two aromatic atoms.
+pub(crate) fn is_aromatic_bond(
+    atoms: &[Atom], sid: usize, bond: &Bond
+) -> bool {
+    match bond.kind {
+        BondKind::Aromatic => true,
+        BondKind::Elided =>
+            atoms[sid].is_aromatic() && atoms[bond.tid].is_aromatic(),
+        _ => false
+    }
+}
+
+struct Pattern<'a> {
+    atoms: &'a [Atom],
+    options: &'a SubstructureOptions
+}
+
+impl<'a> Query for Pattern<'a> {
+    fn size(&self) -> usize {
+        self.atoms.len()
+    }
+
+    fn degree(&self, qid: usize) -> usize {
+        self.atoms[qid].bonds.len()
+    }
+
+    fn target(&self, qid: usize, index: usize) -> usize {
+        self.atoms[qid].bonds[index].tid
+    }
+
+    fn atom_matches(&self, qid: usize, haystack: &[Atom], hid: usize) -> bool {
+        atoms_match(&self.atoms[qid], &haystack[hid], self.options)
+    }
+
+    fn bond_matches(
+        &self,
+        qid: usize,
+        index: usize,
+        haystack: &[Atom],
+        hid: usize,
+        bond: &Bond
+    ) -> bool {
+        bonds_match(
+            self.atoms, qid, &self.atoms[qid].bonds[index], haystack, hid,
+            bond, self.options
+        )
+    }
+}
+
+fn order(query: &dyn Query) -> Vec<usize> {
+    let mut visited = vec![ false; query.size() ];
+    let mut result = Vec::with_capacity(query.size());
+
+    for root in 0..query.size() {
+        if visited[root] {
+            continue
+        }
+
+        visited[root] = true;
+        result.push(root);
+
+        let mut index = result.len() - 1;
+
+        while index < result.len() {
+            let qid = result[index];
+
+            for bond in 0..query.degree(qid) {
+                let tid = query.target(qid, bond);
+
+                if !visited[tid] {
+                    visited[tid] = true;
+                    result.push(tid)
+                }
+            }
+
+            index += 1
+        }
+    }
+
+    result
+}
+
+fn extend(
+    haystack: &[Atom],
+    query: &dyn Query,
+    order: &[usize],
+    mapping: &mut Vec<Option<usize>>,
+    used: &mut Vec<bool>,
+    found: &mut dyn FnMut(&[usize]) -> bool
+) -> bool {
+    let depth = mapping.iter().filter(|id| id.is_some()).count();
+    let qid = match order.get(depth) {
+        Some(qid) => *qid,
+        None => {
+            let result = mapping.iter().map(|id| id.expect("mapped"))
+                .collect::<Vec<_>>();
+
+            return found(&result)
+        }
+    };
+    let anchor = (0..query.degree(qid))
+        .find_map(|index| mapping[query.target(qid, index)]);
+    let candidates = match anchor {
+        Some(hid) => haystack[hid].bonds.iter()
+            .map(|bond| bond.tid).collect::<Vec<_>>(),
+        None => (0..haystack.len()).collect()
+    };
+
+    for hid in candidates {
+        if used[hid] || !feasible(haystack, query, mapping, qid, hid) {
+            continue
+        }
+
+        mapping[qid] = Some(hid);
+        used[hid] = true;
+
+        let proceed = extend(haystack, query, order, mapping, used, found);
+
+        mapping[qid] = None;
+        used[hid] = false;
+
+        if !proceed {
+            return false
+        }
+    }
+
+    true
+}
+
+fn feasible(
+    haystack: &[Atom],
+    query: &dyn Query,
+    mapping: &[Option<usize>],
+    qid: usize,
+    hid: usize
+) -> bool {
+    if !query.atom_matches(qid, haystack, hid) {
+        return false
+    }
+
+    for index in 0..query.degree(qid) {
+        let target = match mapping[query.target(qid, index)] {
+            Some(target) => target,
+            None => continue
+        };
+        let other = match haystack[hid].bonds.iter()
+            .find(|other| other.tid == target) {
+            Some(other) => other,
+            None => return false
+        };
+
+        if !query.bond_matches(qid, index, haystack, hid, other) {
+            return false
+        }
+    }
+
+    true
+}
+
+fn atoms_match(
+    pattern: &Atom, haystack: &Atom, options: &SubstructureOptions
+) -> bool {
+    let symbol = pattern.kind.symbol();
+
+    if symbol == "*" {
+        return true
+    } else if symbol != haystack.kind.symbol() {
+        return false
+    }
+
+    if options.aromaticity && pattern.is_aromatic() != haystack.is_aromatic() {
+        return false
+    }
+
+    let (pattern_isotope, pattern_charge) = isotope_and_charge(&pattern.kind);
+    let (haystack_isotope, haystack_charge) =
+        isotope_and_charge(&haystack.kind);
+
+    if pattern_charge != haystack_charge {
+        return false
+    }
+
+    pattern_isotope.is_none() || pattern_isotope == haystack_isotope
+}
+
+fn bonds_match(
+    pattern: &[Atom],
+    pid: usize,
+    pattern_bond: &Bond,
+    haystack: &[Atom],
+    hid: usize,
+    haystack_bond: &Bond,
+    options: &SubstructureOptions
+) -> bool {
+    let pattern_aromatic = is_aromatic_bond(pattern, pid, pattern_bond);
+    let haystack_aromatic = is_aromatic_bond(haystack, hid, haystack_bond);
+
+    if options.aromaticity {
+        pattern_aromatic == haystack_aromatic
+            && pattern_bond.order() == haystack_bond.order()
+    } else if pattern_aromatic || haystack_aromatic {
+        pattern_bond.order() <= 2 && haystack_bond.order() <= 2
+    } else {
+        pattern_bond.order() == haystack_bond.order()
+    }
+}
+
+fn isotope_and_charge(kind: &AtomKind) -> (Option<u16>, i8) {
+    match kind {
+        AtomKind::Bracket { isotope, charge, .. } => (
+            isotope.as_ref().map(|isotope| isotope.into()),
+            charge.as_ref().map_or(0, |charge| charge.into())
+        ),
+        _ => (None, 0)
+    }
+}
\ No newline at end of file
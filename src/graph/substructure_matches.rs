@@ -0,0 +1,104 @@
+use super::{ Atom, SubstructureOptions, substructure };
+
+/// Returns each match of `pattern` within `haystack` as a mapping from
+/// pattern atom index to haystack atom index, using default
+/// `SubstructureOptions`. Matches covering the same set of haystack atoms
+/// are reported once, using the first mapping found. Matching follows
+/// `has_substructure`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `pattern`.
+pub fn substructure_matches(
+    haystack: &[Atom], pattern: &[Atom]
+) -> Vec<Vec<usize>> {
+    substructure_matches_with(
+        haystack, pattern, &SubstructureOptions::default()
+    )
+}
+
+/// Returns each match of `pattern` within `haystack` under `options`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `pattern`.
+pub fn substructure_matches_with(
+    haystack: &[Atom], pattern: &[Atom], options: &SubstructureOptions
+) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut seen = Vec::new();
+
+    substructure(haystack, pattern, options, &mut |mapping| {
+        let mut ids = mapping.to_vec();
+
+        ids.sort_unstable();
+
+        if !seen.contains(&ids) {
+            seen.push(ids);
+            result.push(mapping.to_vec())
+        }
+
+        true
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn matches(haystack: &str, pattern: &str) -> Vec<Vec<usize>> {
+        substructure_matches(&build(haystack), &build(pattern))
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(
+            substructure_matches(&build("C"), &[ ]),
+            vec![ Vec::<usize>::new() ]
+        )
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(matches("CCO", "CN"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn ethane_in_propane() {
+        assert_eq!(matches("CCC", "CC"), vec![ vec![ 0, 1 ], vec![ 1, 2 ] ])
+    }
+
+    #[test]
+    fn methanol_in_ethanol() {
+        assert_eq!(matches("CCO", "OC"), vec![ vec![ 2, 1 ] ])
+    }
+
+    #[test]
+    fn hydroxyls() {
+        assert_eq!(
+            matches("OCC(O)CO", "CO"),
+            vec![ vec![ 1, 0 ], vec![ 2, 3 ], vec![ 4, 5 ] ]
+        )
+    }
+
+    #[test]
+    fn benzene_ring() {
+        assert_eq!(matches("c1ccccc1", "c1ccccc1").len(), 1)
+    }
+
+    #[test]
+    fn aromaticity_ignored() {
+        let options = SubstructureOptions { aromaticity: false };
+
+        assert_eq!(
+            substructure_matches_with(
+                &build("c1ccccc1C"), &build("CC"), &options
+            ).len(),
+            7
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+/// Options controlling how substructure search compares atoms and bonds.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct SubstructureOptions {
+    /// When true, aromatic pattern atoms and bonds only match aromatic
+    /// haystack atoms and bonds, and likewise for aliphatic ones. When
+    /// false, aromatic bonds match single or double bonds, so a Kekulé
+    /// ring matches its aromatic form.
+    pub aromaticity: bool
+}
+
+impl Default for SubstructureOptions {
+    fn default() -> Self {
+        Self {
+            aromaticity: true
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,294 @@
+use std::fmt::Write;
+
+use crate::feature::{ AtomKind, BondKind };
+use super::{ Atom, Bond, Error, MolblockOptions, kekulize };
+
+/// Returns a minimal MDL V2000 mol block for `atoms`. All coordinates are
+/// zero. Charges and isotopes are written as `M  CHG` and `M  ISO`
+/// properties, and atom maps use the atom-atom mapping field. Aromatic
+/// bonds, whether written with `:` or elided between two aromatic atoms,
+/// use bond type 4. Quadruple bonds, which V2000 cannot express, return
+/// `Error::UnsupportedBond`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn to_molblock(atoms: &[Atom]) -> Result<String, Error> {
+    write_molblock(atoms)
+}
+
+/// Returns a mol block for `atoms` as `to_molblock` does, configured by
+/// `options`. With `kekulize` set, a copy of `atoms` is kekulized first,
+/// returning `Error::Kekulization` if that fails.
+///
+/// ```
+/// use purr::graph::{ Builder, MolblockOptions, to_molblock_with };
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("c1ccccc1", &mut builder, None)?;
+///
+///     let atoms = builder.build().expect("atoms");
+///     let options = MolblockOptions { kekulize: true };
+///     let molblock = to_molblock_with(&atoms, &options).expect("molblock");
+///
+///     assert_eq!(molblock.lines().nth(10), Some("  1  6  2  0  0  0  0"));
+///     assert_eq!(molblock.lines().nth(11), Some("  1  2  1  0  0  0  0"));
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn to_molblock_with(
+    atoms: &[Atom], options: &MolblockOptions
+) -> Result<String, Error> {
+    if options.kekulize {
+        let mut atoms = atoms.to_vec();
+
+        kekulize(&mut atoms)?;
+
+        write_molblock(&atoms)
+    } else {
+        write_molblock(atoms)
+    }
+}
+
+fn write_molblock(atoms: &[Atom]) -> Result<String, Error> {
+    let mut bonds = Vec::new();
+    let mut charges = Vec::new();
+    let mut isotopes = Vec::new();
+    let mut result = String::new();
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in atom.bonds.iter() {
+            if bond.tid > sid {
+                bonds.push((sid, bond.tid, bond_type(atoms, sid, bond)?))
+            }
+        }
+    }
+
+    result.push_str("\n  purr\n\n");
+    writeln!(
+        result, "{:>3}{:>3}  0  0  0  0  0  0  0  0999 V2000",
+        atoms.len(), bonds.len()
+    ).expect("write");
+
+    for (id, atom) in atoms.iter().enumerate() {
+        let mut map = 0;
+
+        if let AtomKind::Bracket {
+            isotope, charge, map: atom_map, ..
+        } = &atom.kind {
+            if let Some(isotope) = isotope {
+                isotopes.push((id, u16::from(isotope).to_string()))
+            }
+
+            if let Some(charge) = charge {
+                charges.push((id, i8::from(charge).to_string()))
+            }
+
+            if let Some(atom_map) = atom_map {
+                map = u16::from(atom_map)
+            }
+        }
+
+        writeln!(
+            result,
+            concat!(
+                "    0.0000    0.0000    0.0000 {:<3} 0  0  0  0  0  0  0  0",
+                "  0{:>3}  0  0"
+            ),
+            atom.kind.symbol(), map
+        ).expect("write");
+    }
+
+    for (sid, tid, kind) in bonds {
+        writeln!(
+            result, "{:>3}{:>3}{:>3}  0  0  0  0", sid + 1, tid + 1, kind
+        ).expect("write");
+    }
+
+    write_properties(&mut result, "CHG", &charges);
+    write_properties(&mut result, "ISO", &isotopes);
+    result.push_str("M  END\n");
+
+    Ok(result)
+}
+
+fn bond_type(atoms: &[Atom], sid: usize, bond: &Bond) -> Result<u8, Error> {
+    Ok(match bond.kind {
+        BondKind::Elided => {
+            if atoms[sid].is_aromatic() && atoms[bond.tid].is_aromatic() {
+                4
+            } else {
+                1
+            }
+        },
+        BondKind::Single |
+        BondKind::Up |
+        BondKind::Down => 1,
+        BondKind::Double => 2,
+        BondKind::Triple => 3,
+        BondKind::Aromatic => 4,
+        BondKind::Quadruple =>
+            return Err(Error::UnsupportedBond(sid, bond.tid))
+    })
+}
+
+fn write_properties(
+    result: &mut String, name: &str, properties: &[(usize, String)]
+) {
+    for chunk in properties.chunks(8) {
+        write!(result, "M  {}{:>3}", name, chunk.len()).expect("write");
+
+        for (id, value) in chunk {
+            write!(result, " {:>3} {:>3}", id + 1, value).expect("write");
+        }
+
+        result.push('\n')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn counts(molblock: &str) -> &str {
+        molblock.lines().nth(3).expect("counts line")
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_molblock(&[ ]).unwrap(), concat!(
+            "\n",
+            "  purr\n",
+            "\n",
+            "  0  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "M  END\n"
+        ))
+    }
+
+    #[test]
+    fn ethanol_counts() {
+        assert_eq!(
+            counts(&to_molblock(&build("CCO")).unwrap()),
+            "  3  2  0  0  0  0  0  0  0  0999 V2000"
+        )
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(to_molblock(&build("CCO")).unwrap(), concat!(
+            "\n",
+            "  purr\n",
+            "\n",
+            "  3  2  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "    0.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "  1  2  1  0  0  0  0\n",
+            "  2  3  1  0  0  0  0\n",
+            "M  END\n"
+        ))
+    }
+
+    #[test]
+    fn acetate_labeled_mapped() {
+        assert_eq!(to_molblock(&build("[13CH3:1]C(=O)[O-]")).unwrap(), concat!(
+            "\n",
+            "  purr\n",
+            "\n",
+            "  4  3  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  1  0  0\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "    0.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "    0.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "  1  2  1  0  0  0  0\n",
+            "  2  3  2  0  0  0  0\n",
+            "  2  4  1  0  0  0  0\n",
+            "M  CHG  1   4  -1\n",
+            "M  ISO  1   1  13\n",
+            "M  END\n"
+        ))
+    }
+
+    #[test]
+    fn benzene_aromatic_bonds() {
+        let molblock = to_molblock(&build("c1ccccc1")).unwrap();
+        let bonds = molblock.lines().skip(10).take(6).collect::<Vec<_>>();
+
+        assert_eq!(counts(&molblock), "  6  6  0  0  0  0  0  0  0  0999 V2000");
+        assert_eq!(bonds, vec![
+            "  1  6  4  0  0  0  0",
+            "  1  2  4  0  0  0  0",
+            "  2  3  4  0  0  0  0",
+            "  3  4  4  0  0  0  0",
+            "  4  5  4  0  0  0  0",
+            "  5  6  4  0  0  0  0"
+        ])
+    }
+
+    #[test]
+    fn star() {
+        let molblock = to_molblock(&build("*C")).unwrap();
+
+        assert_eq!(
+            molblock.lines().nth(4),
+            Some("    0.0000    0.0000    0.0000 *   0  0  0  0  0  0  0  0  0  0  0  0")
+        )
+    }
+
+    #[test]
+    fn quadruple() {
+        assert_eq!(
+            to_molblock(&build("C[Re]$[Re]")),
+            Err(Error::UnsupportedBond(1, 2))
+        )
+    }
+
+    #[test]
+    fn benzene_kekulized() {
+        let options = MolblockOptions { kekulize: true };
+        let molblock = to_molblock_with(&build("c1ccccc1"), &options).unwrap();
+        let atoms = molblock.lines().skip(4).take(6)
+            .filter(|line| line.contains(" C  ")).count();
+        let bonds = molblock.lines().skip(10).take(6).collect::<Vec<_>>();
+
+        assert_eq!(atoms, 6);
+        assert_eq!(bonds, vec![
+            "  1  6  2  0  0  0  0",
+            "  1  2  1  0  0  0  0",
+            "  2  3  2  0  0  0  0",
+            "  3  4  1  0  0  0  0",
+            "  4  5  2  0  0  0  0",
+            "  5  6  1  0  0  0  0"
+        ])
+    }
+
+    #[test]
+    fn benzene_default_options() {
+        let atoms = build("c1ccccc1");
+
+        assert_eq!(
+            to_molblock_with(&atoms, &MolblockOptions::default()),
+            to_molblock(&atoms)
+        )
+    }
+
+    #[test]
+    fn kekulization_error() {
+        let options = MolblockOptions { kekulize: true };
+
+        assert_eq!(
+            to_molblock_with(&build("c1cccc1"), &options),
+            Err(Error::Kekulization(0))
+        )
+    }
+}
\ No newline at end of file
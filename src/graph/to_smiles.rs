@@ -0,0 +1,66 @@
+use crate::walk::{ walk, Error };
+use crate::write::Writer;
+use super::Atom;
+
+/// Walks `atoms` with a fresh `Writer`, returning the SMILES string.
+/// 
+/// ```
+/// use purr::graph::{ Builder, to_smiles };
+/// use purr::read::read;
+/// 
+/// fn main() {
+///     let mut builder = Builder::new();
+/// 
+///     read("CCO", &mut builder, None).expect("read");
+/// 
+///     assert_eq!(to_smiles(&builder.build().expect("atoms")), Ok(
+///         "CCO".to_string()
+///     ))
+/// }
+/// ```
+pub fn to_smiles(atoms: &[Atom]) -> Result<String, Error> {
+    let mut writer = Writer::new();
+
+    walk(atoms.to_vec(), &mut writer)?;
+
+    Ok(writer.write())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ AtomKind, Aliphatic, BondKind };
+    use crate::read::read;
+    use crate::graph::{ Builder, Bond };
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_smiles(&[ ]), Ok("".to_string()))
+    }
+
+    #[test]
+    fn ethanol() {
+        let mut builder = Builder::new();
+
+        read("CCO", &mut builder, None).unwrap();
+
+        assert_eq!(to_smiles(&builder.build().unwrap()), Ok("CCO".to_string()))
+    }
+
+    #[test]
+    fn half_bond() {
+        let atoms = vec![
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ Bond::new(BondKind::Elided, 1) ]
+            },
+            Atom {
+                kind: AtomKind::Aliphatic(Aliphatic::C),
+                bonds: vec![ ]
+            }
+        ];
+
+        assert_eq!(to_smiles(&atoms), Err(Error::HalfBond(0, 1)))
+    }
+}
\ No newline at end of file
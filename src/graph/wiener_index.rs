@@ -0,0 +1,66 @@
+use super::{ Atom, breadth_first };
+
+/// Returns the Wiener index: the sum of shortest path lengths, in bonds,
+/// over all pairs of atoms. Pairs in different components are skipped.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `atoms`.
+pub fn wiener_index(atoms: &[Atom]) -> u64 {
+    let mut result = 0;
+
+    for sid in 0..atoms.len() {
+        let mut visited = vec![ false; atoms.len() ];
+
+        for (tid, _, distance) in breadth_first(atoms, sid, &mut visited) {
+            if tid > sid {
+                result += distance as u64
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn methane() {
+        assert_eq!(wiener_index(&build("C")), 0)
+    }
+
+    #[test]
+    fn propane() {
+        assert_eq!(wiener_index(&build("CCC")), 4)
+    }
+
+    #[test]
+    fn linear_alkanes() {
+        let mut smiles = String::new();
+
+        for n in 1..=10u64 {
+            smiles.push('C');
+
+            assert_eq!(wiener_index(&build(&smiles)), (n * n * n - n) / 6)
+        }
+    }
+
+    #[test]
+    fn isobutane() {
+        assert_eq!(wiener_index(&build("CC(C)C")), 9)
+    }
+
+    #[test]
+    fn cyclohexane() {
+        assert_eq!(wiener_index(&build("C1CCCCC1")), 27)
+    }
+
+    #[test]
+    fn disconnected() {
+        assert_eq!(wiener_index(&build("CC.CC")), 2)
+    }
+}
\ No newline at end of file
@@ -16,6 +16,15 @@ pub mod write;
 pub mod walk;
 /// SMILES adjacency list representation.
 pub mod graph;
+/// Minimal SMARTS query parsing.
+pub mod smarts;
+/// Valence and hydrogen count maintenance.
+pub mod valence;
+/// An owned molecule with descriptor methods.
+pub mod molecule;
+
+#[cfg(test)]
+mod test_support;
 
 // https://github.com/rust-lang/cargo/issues/383#issuecomment-720873790
 #[cfg(doctest)]
@@ -0,0 +1,95 @@
+use super::{ Molecule, PurrError };
+
+/// Returns the canonical string of each of `inputs`, in order. A failure
+/// affects only its own entry. Each input is handled independently, so
+/// callers wanting parallelism can instead map `Molecule::from_smiles` and
+/// `Molecule::canonical_smiles` over their own parallel iterator.
+///
+/// ```
+/// use purr::molecule::{ canonicalize_all, PurrError };
+/// use purr::read::Error;
+///
+/// let result = canonicalize_all(&[ "OCC", "C(" ]);
+///
+/// assert_eq!(result[0], Ok("CCO".to_string()));
+/// assert_eq!(result[1], Err(PurrError::Read(Error::EndOfLine)));
+/// ```
+pub fn canonicalize_all(inputs: &[&str]) -> Vec<Result<String, PurrError>> {
+    let mut result = Vec::with_capacity(inputs.len());
+
+    for smiles in inputs {
+        result.push(
+            Molecule::from_smiles(smiles)
+                .and_then(|molecule| molecule.canonical_smiles())
+        )
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::{ read, graph };
+    use crate::write::canonical;
+    use crate::test_support::{ build, chirality };
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(canonicalize_all(&[ ]), vec![ ])
+    }
+
+    #[test]
+    fn batch() {
+        let inputs = [ "OCC", "CCO", "c1ccccc1", "[Na+].[Cl-]" ];
+        let result = canonicalize_all(&inputs);
+
+        assert_eq!(result.len(), 4);
+
+        for (smiles, result) in inputs.iter().zip(result) {
+            assert_eq!(result.unwrap(), canonical(smiles).unwrap())
+        }
+    }
+
+    #[test]
+    fn invalid_entries() {
+        assert_eq!(canonicalize_all(&[ "C", "C?", "C1CC", "O" ]), vec![
+            Ok("C".to_string()),
+            Err(PurrError::Read(read::Error::Character(1))),
+            Err(PurrError::Graph(graph::Error::Rnum(0))),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn mixed_batch() {
+        let inputs = [
+            "[F:1][C@:2]([Cl:3])([CH3:4])[CH2:5][CH3:6]",
+            "C(",
+            "F[As@TB1](Cl)(Br)(I)N",
+            "F[Fe@OH1](Cl)(Br)(I)(N)O",
+            "F[Pt@SP1](Cl)(Br)I"
+        ];
+        let result = canonicalize_all(&inputs);
+
+        assert_eq!(result.len(), 5);
+        assert_eq!(
+            chirality(&build(result[0].as_ref().unwrap())),
+            chirality(&build(inputs[0]))
+        );
+        assert_eq!(result[1], Err(PurrError::Read(read::Error::EndOfLine)));
+        assert!(result[2..].iter().all(|result| result.is_ok()));
+        assert!(result[3].as_ref().unwrap().contains("@OH"))
+    }
+
+    #[test]
+    fn symmetric_stereocenters() {
+        let result = canonicalize_all(&[
+            "C[C@H](CC[C@@H](CC1)O)1",
+            "C([C@H](C)CC[C@@H](C1)O)1"
+        ]);
+
+        assert_eq!(result[0], result[1])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+mod molecule;
+mod canonicalize_all;
+
+pub use molecule::Molecule;
+pub use crate::write::Error as PurrError;
+pub use canonicalize_all::canonicalize_all;
\ No newline at end of file
@@ -0,0 +1,304 @@
+use std::ops::Index;
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+use crate::read::read;
+use crate::graph::{
+    Atom, Builder, canonical_smiles_with_order, molecular_formula,
+    molecular_weight, sssr, to_smiles
+};
+use super::PurrError;
+
+/// An owned graph representation. Atoms are indexed and iterated as in
+/// the wrapped `Vec`, and common descriptors are available as methods.
+/// Molecules hold no shared or interior-mutable state, so they are `Send`
+/// and `Sync`.
+///
+/// ```
+/// use purr::graph::Builder;
+/// use purr::molecule::Molecule;
+/// use purr::read::{ read, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("c1ccccc1O", &mut builder, None)?;
+///
+///     let molecule = Molecule::from(builder.build().expect("atoms"));
+///
+///     assert_eq!(molecule.len(), 7);
+///     assert_eq!(molecule.formula(), "C6H6O");
+///     assert_eq!(molecule.rings(), vec![ vec![ 0, 1, 2, 3, 4, 5 ] ]);
+///     assert_eq!(molecule[6].bonds.len(), 1);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,PartialEq,Eq,Hash,Clone,Default)]
+pub struct Molecule(pub Vec<Atom>);
+
+impl Molecule {
+    /// Reads and builds a Molecule from `smiles`.
+    ///
+    /// ```
+    /// use purr::molecule::{ Molecule, PurrError };
+    /// use purr::write::canonical;
+    ///
+    /// fn main() -> Result<(), PurrError> {
+    ///     let molecule = Molecule::from_smiles("OCC")?;
+    ///
+    ///     assert_eq!(molecule.to_smiles()?, "OCC");
+    ///     assert_eq!(molecule.canonical_smiles()?, canonical("CCO")?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_smiles(smiles: &str) -> Result<Self, PurrError> {
+        let mut builder = Builder::with_capacity(smiles.len() / 2);
+
+        read(smiles, &mut builder, None)?;
+
+        Ok(Molecule(builder.build()?))
+    }
+
+    /// Writes a SMILES string following atom order. See
+    /// `graph::to_smiles`.
+    pub fn to_smiles(&self) -> Result<String, PurrError> {
+        Ok(to_smiles(&self.0)?)
+    }
+
+    /// Writes a SMILES string that is the same for any atom order of an
+    /// equivalent graph, including one with symmetric stereocenters. See
+    /// `write::canonical`.
+    pub fn canonical_smiles(&self) -> Result<String, PurrError> {
+        let (result, _) = canonical_smiles_with_order(&self.0)?;
+
+        Ok(result)
+    }
+
+    /// Returns the number of atoms.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no atoms.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the atoms.
+    pub fn iter(&self) -> Iter<'_, Atom> {
+        self.0.iter()
+    }
+
+    /// Returns the molecular formula in Hill order. See
+    /// `graph::molecular_formula`.
+    pub fn formula(&self) -> String {
+        molecular_formula(&self.0)
+    }
+
+    /// Returns the average molecular weight in daltons. See
+    /// `graph::molecular_weight`.
+    pub fn weight(&self) -> f64 {
+        molecular_weight(&self.0)
+    }
+
+    /// Returns a smallest set of smallest rings. See `graph::sssr`.
+    pub fn rings(&self) -> Vec<Vec<usize>> {
+        sssr(&self.0)
+    }
+}
+
+impl From<Vec<Atom>> for Molecule {
+    fn from(atoms: Vec<Atom>) -> Self {
+        Molecule(atoms)
+    }
+}
+
+impl Index<usize> for Molecule {
+    type Output = Atom;
+
+    fn index(&self, index: usize) -> &Atom {
+        &self.0[index]
+    }
+}
+
+impl IntoIterator for Molecule {
+    type Item = Atom;
+    type IntoIter = IntoIter<Atom>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Molecule {
+    type Item = &'a Atom;
+    type IntoIter = Iter<'a, Atom>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ AtomKind, Aliphatic };
+    use crate::read;
+    use crate::test_support::{ build, chirality };
+    use super::*;
+
+    fn molecule(smiles: &str) -> Molecule {
+        Molecule::from_smiles(smiles).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        let molecule = Molecule::default();
+
+        assert_eq!(molecule.len(), 0);
+        assert!(molecule.is_empty())
+    }
+
+    #[test]
+    fn len() {
+        let molecule = molecule("CCO");
+
+        assert_eq!(molecule.len(), 3);
+        assert!(!molecule.is_empty())
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(
+            molecule("CCO")[2].kind,
+            AtomKind::Aliphatic(Aliphatic::O)
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _ = &molecule("CCO")[3];
+    }
+
+    #[test]
+    fn iterate_borrowed() {
+        let molecule = molecule("CCO");
+        let mut degrees = Vec::new();
+
+        for atom in &molecule {
+            degrees.push(atom.bonds.len())
+        }
+
+        assert_eq!(degrees, vec![ 1, 2, 1 ]);
+        assert_eq!(molecule.iter().count(), 3)
+    }
+
+    #[test]
+    fn iterate_owned() {
+        let kinds = molecule("CO").into_iter()
+            .map(|atom| atom.kind).collect::<Vec<_>>();
+
+        assert_eq!(kinds, vec![
+            AtomKind::Aliphatic(Aliphatic::C),
+            AtomKind::Aliphatic(Aliphatic::O)
+        ])
+    }
+
+    #[test]
+    fn formula() {
+        assert_eq!(molecule("CCO").formula(), "C2H6O")
+    }
+
+    #[test]
+    fn weight() {
+        assert!((molecule("O").weight() - 18.015).abs() < 1e-9)
+    }
+
+    #[test]
+    fn rings() {
+        assert_eq!(molecule("C1CC1").rings(), vec![ vec![ 0, 1, 2 ] ])
+    }
+
+    #[test]
+    fn from_smiles_read_error() {
+        assert_eq!(
+            Molecule::from_smiles("C("),
+            Err(PurrError::Read(read::Error::EndOfLine))
+        )
+    }
+
+    #[test]
+    fn from_smiles_graph_error() {
+        assert_eq!(
+            Molecule::from_smiles("C1CC"),
+            Err(PurrError::Graph(crate::graph::Error::Rnum(0)))
+        )
+    }
+
+    #[test]
+    fn to_smiles_empty() {
+        assert_eq!(Molecule::default().to_smiles(), Ok("".to_string()))
+    }
+
+    #[test]
+    fn to_smiles_keeps_order() {
+        assert_eq!(molecule("OCC").to_smiles(), Ok("OCC".to_string()))
+    }
+
+    #[test]
+    fn canonical_smiles_tetrahedral() {
+        assert_eq!(
+            molecule("[C@](F)(Cl)(Br)I").canonical_smiles(),
+            molecule("F[C@](Cl)(Br)I").canonical_smiles()
+        )
+    }
+
+    #[test]
+    fn canonical_smiles_enantiomers() {
+        let left = molecule("F[C@](Cl)(C)CC").canonical_smiles().unwrap();
+        let right = molecule("F[C@@](Cl)(C)CC").canonical_smiles().unwrap();
+
+        assert_eq!(left, "CC[C@](C)(Cl)F");
+        assert_eq!(right, "CC[C@@](C)(Cl)F")
+    }
+
+    #[test]
+    fn canonical_smiles_chirality() {
+        let smiles = "[CH3:1][C@H:2]1[CH2:3][CH2:4][C@@H:5]([OH:6])[O:7]1";
+        let written = molecule(smiles).canonical_smiles().unwrap();
+
+        assert_eq!(chirality(&build(&written)), chirality(&build(smiles)))
+    }
+
+    #[test]
+    fn canonical_smiles_order_independent() {
+        assert_eq!(
+            molecule("OCC").canonical_smiles(),
+            molecule("CCO").canonical_smiles()
+        )
+    }
+
+    #[test]
+    fn canonical_smiles_symmetric_stereocenters() {
+        assert_eq!(
+            molecule("[C@H]([C@H](Cl)F)(Cl)F").canonical_smiles(),
+            molecule("[C@@H]([C@@H](Cl)F)(Cl)F").canonical_smiles()
+        )
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() { }
+
+        assert_send_sync::<Molecule>();
+        assert_send_sync::<PurrError>();
+        assert_send_sync::<Atom>();
+        assert_send_sync::<Builder>();
+        assert_send_sync::<crate::write::Writer>();
+        assert_send_sync::<crate::read::Trace>();
+        assert_send_sync::<crate::read::StreamingParser>()
+    }
+}
\ No newline at end of file
@@ -1,6 +1,96 @@
+use std::fmt;
+
 /// An error that occurs when reading a SMILES string.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq)]
 pub enum Error {
     EndOfLine,
-    Character(usize)
+    Character(usize),
+    TooLarge(usize),
+    TooDeep(usize)
+}
+
+impl Error {
+    /// Returns a multi-line message showing `input` with a caret under the
+    /// offending character. End of line errors point to the last character.
+    /// 
+    /// ```
+    /// use purr::read::Error;
+    /// 
+    /// assert_eq!(
+    ///     Error::Character(3).render("OCCXC"),
+    ///     "unexpected character at 3\nOCCXC\n   ^"
+    /// )
+    /// ```
+    pub fn render(&self, input: &str) -> String {
+        let column = match self {
+            Error::EndOfLine => input.chars().count().saturating_sub(1),
+            Error::Character(cursor) |
+            Error::TooLarge(cursor) |
+            Error::TooDeep(cursor) => *cursor
+        };
+
+        format!("{}\n{}\n{}^", self, input, " ".repeat(column))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::EndOfLine => write!(f, "unexpected end of line"),
+            Error::Character(cursor) =>
+                write!(f, "unexpected character at {}", cursor),
+            Error::TooLarge(cursor) =>
+                write!(f, "atom limit exceeded at {}", cursor),
+            Error::TooDeep(cursor) =>
+                write!(f, "branch depth limit exceeded at {}", cursor)
+        }
+    }
+}
+
+impl std::error::Error for Error { }
+
+#[cfg(test)]
+mod render {
+    use pretty_assertions::assert_eq;
+    use crate::write::Writer;
+    use crate::read::read;
+
+    fn render(smiles: &str) -> String {
+        let mut writer = Writer::new();
+
+        read(smiles, &mut writer, None).unwrap_err().render(smiles)
+    }
+
+    #[test]
+    fn blank() {
+        assert_eq!(render(""), "unexpected end of line\n\n^")
+    }
+
+    #[test]
+    fn open_paren_eol() {
+        assert_eq!(render("C("), "unexpected end of line\nC(\n ^")
+    }
+
+    #[test]
+    fn leading_character() {
+        assert_eq!(render("X"), "unexpected character at 0\nX\n^")
+    }
+
+    #[test]
+    fn inner_character() {
+        assert_eq!(render("OCCXC"), "unexpected character at 3\nOCCXC\n   ^")
+    }
+
+    #[test]
+    fn empty_bracket() {
+        assert_eq!(render("C[]C"), "unexpected character at 2\nC[]C\n  ^")
+    }
+
+    #[test]
+    fn bracket_character() {
+        assert_eq!(
+            render("C[C+-]"),
+            "unexpected character at 4\nC[C+-]\n    ^"
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,469 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::feature::{
+    AtomKind, Aliphatic, Aromatic, BondKind, BracketAromatic, BracketSymbol,
+    Charge, Number, VirtualHydrogen
+};
+use crate::graph::{ Atom, Bond };
+use super::{ Error, Scanner, read_symbol };
+
+/// Reads a minimal MDL V2000 mol block into a graph-like representation.
+/// Coordinates are ignored. Charges come from `M  CHG` properties, or from
+/// the atom block if none are present. Isotopes come from `M  ISO`
+/// properties, and atom maps from the atom-atom mapping field. Implicit
+/// hydrogens follow the default valence model, so hydrogens on aromatic
+/// atoms such as pyrrole nitrogen are not recovered.
+///
+/// Atoms joined by bond type 4 become aromatic, and the bonds between them
+/// are elided. Query bond types such as 8 (any) are rejected. Errors report
+/// the character offset of the offending field within `molblock`.
+///
+/// ```
+/// use purr::read::from_molblock;
+/// use purr::walk::walk;
+/// use purr::write::Writer;
+///
+/// fn main() {
+///     let molblock = concat!(
+///         "\n  purr\n\n",
+///         "  2  1  0  0  0  0  0  0  0  0999 V2000\n",
+///         "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+///         "    0.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0\n",
+///         "  1  2  1  0  0  0  0\n",
+///         "M  END\n"
+///     );
+///     let atoms = from_molblock(molblock).unwrap();
+///     let mut writer = Writer::new();
+///
+///     walk(atoms, &mut writer).unwrap();
+///
+///     assert_eq!(writer.write(), "CO")
+/// }
+/// ```
+pub fn from_molblock(molblock: &str) -> Result<Vec<Atom>, Error> {
+    let mut lines = lines(molblock).into_iter().skip(3);
+    let counts = lines.next().ok_or(Error::EndOfLine)?;
+    let atom_count: usize = field(&counts, 0, 3)?;
+    let bond_count: usize = field(&counts, 3, 6)?;
+    let mut entries = Vec::with_capacity(atom_count);
+    let mut bonds = Vec::with_capacity(bond_count);
+
+    for _ in 0..atom_count {
+        entries.push(read_entry(&lines.next().ok_or(Error::EndOfLine)?)?)
+    }
+
+    for _ in 0..bond_count {
+        bonds.push(read_bond(&lines.next().ok_or(Error::EndOfLine)?, &entries)?)
+    }
+
+    let mut charges = None;
+
+    for line in lines {
+        if line.text.starts_with("M  END") {
+            break
+        } else if line.text.starts_with("M  CHG") {
+            let charges = charges.get_or_insert_with(
+                || vec![ 0; entries.len() ]
+            );
+
+            for (id, value) in read_properties::<i8>(&line, &entries)? {
+                if value != 0 && Charge::try_from(value).is_err() {
+                    return Err(Error::Character(line.offset))
+                }
+
+                charges[id] = value
+            }
+        } else if line.text.starts_with("M  ISO") {
+            for (id, value) in read_properties::<u16>(&line, &entries)? {
+                entries[id].isotope = Some(Number::try_from(value)
+                    .map_err(|_| Error::Character(line.offset))?)
+            }
+        }
+    }
+
+    if let Some(charges) = charges {
+        for (entry, charge) in entries.iter_mut().zip(charges) {
+            entry.charge = charge
+        }
+    }
+
+    for &(sid, tid, order) in bonds.iter() {
+        if order == 4 {
+            entries[sid].aromatic = true;
+            entries[tid].aromatic = true;
+        }
+    }
+
+    let mut atoms = entries.iter()
+        .map(|_| Atom::new(AtomKind::Star)).collect::<Vec<_>>();
+
+    for &(sid, tid, order) in bonds.iter() {
+        let kind = match order {
+            1 => BondKind::Elided,
+            2 => BondKind::Double,
+            3 => BondKind::Triple,
+            4 => if is_aromatic(&entries[sid]) && is_aromatic(&entries[tid]) {
+                BondKind::Elided
+            } else {
+                BondKind::Aromatic
+            },
+            _ => unreachable!("bond order")
+        };
+
+        atoms[sid].bonds.push(Bond::new(kind.clone(), tid));
+        atoms[tid].bonds.push(Bond::new(kind, sid));
+    }
+
+    for (atom, entry) in atoms.iter_mut().zip(entries) {
+        atom.kind = atom_kind(atom, entry)
+    }
+
+    Ok(atoms)
+}
+
+struct Line<'a> {
+    offset: usize,
+    text: &'a str
+}
+
+struct Entry {
+    symbol: BracketSymbol,
+    isotope: Option<Number>,
+    charge: i8,
+    map: Option<Number>,
+    aromatic: bool
+}
+
+fn lines(molblock: &str) -> Vec<Line<'_>> {
+    let mut offset = 0;
+    let mut result = Vec::new();
+
+    for text in molblock.split_inclusive('\n') {
+        result.push(Line {
+            offset,
+            text: text.trim_end_matches(&[ '\r', '\n' ][..])
+        });
+        offset += text.chars().count();
+    }
+
+    result
+}
+
+fn field<T: FromStr + Default>(
+    line: &Line, start: usize, end: usize
+) -> Result<T, Error> {
+    let length = line.text.len();
+    let text = match line.text.get(start.min(length)..end.min(length)) {
+        Some(text) => text.trim(),
+        None => return Err(Error::Character(line.offset + start))
+    };
+
+    if text.is_empty() {
+        Ok(T::default())
+    } else {
+        text.parse().map_err(|_| Error::Character(line.offset + start))
+    }
+}
+
+fn read_entry(line: &Line) -> Result<Entry, Error> {
+    let text = line.text.get(31..34.min(line.text.len()))
+        .ok_or(Error::Character(line.offset + 31))?.trim();
+    let mut scanner = Scanner::new(text);
+    let symbol = match read_symbol(&mut scanner) {
+        Ok(BracketSymbol::Aromatic(_)) | Err(_) =>
+            return Err(Error::Character(line.offset + 31)),
+        Ok(symbol) => symbol
+    };
+
+    if !scanner.is_done() {
+        return Err(Error::Character(line.offset + 31))
+    }
+
+    let charge = match field::<u8>(line, 36, 39)? {
+        0 | 4 => 0,
+        code @ 1..=3 | code @ 5..=7 => 4 - code as i8,
+        _ => return Err(Error::Character(line.offset + 36))
+    };
+    let map = match field::<u16>(line, 60, 63)? {
+        0 => None,
+        map => Some(Number::try_from(map)
+            .map_err(|_| Error::Character(line.offset + 60))?)
+    };
+
+    Ok(Entry { symbol, isotope: None, charge, map, aromatic: false })
+}
+
+fn read_bond(
+    line: &Line, entries: &[Entry]
+) -> Result<(usize, usize, u8), Error> {
+    let sid = read_id(line, 0, entries)?;
+    let tid = read_id(line, 3, entries)?;
+
+    if sid == tid {
+        return Err(Error::Character(line.offset + 3))
+    }
+
+    match field::<u8>(line, 6, 9)? {
+        order @ 1..=4 => Ok((sid, tid, order)),
+        _ => Err(Error::Character(line.offset + 6))
+    }
+}
+
+fn read_id(line: &Line, start: usize, entries: &[Entry]) -> Result<usize, Error> {
+    match field::<usize>(line, start, start + 3)? {
+        0 => Err(Error::Character(line.offset + start)),
+        id if id > entries.len() => Err(Error::Character(line.offset + start)),
+        id => Ok(id - 1)
+    }
+}
+
+fn read_properties<T: FromStr + Default>(
+    line: &Line, entries: &[Entry]
+) -> Result<Vec<(usize, T)>, Error> {
+    let count: usize = field(line, 6, 9)?;
+    let mut result = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start = 9 + i * 8;
+
+        result.push((
+            read_id(line, start + 1, entries)?,
+            field(line, start + 5, start + 8)?
+        ))
+    }
+
+    Ok(result)
+}
+
+fn is_aromatic(entry: &Entry) -> bool {
+    match &entry.symbol {
+        BracketSymbol::Element(element) =>
+            entry.aromatic && BracketAromatic::try_from(element).is_ok(),
+        _ => false
+    }
+}
+
+fn atom_kind(atom: &Atom, entry: Entry) -> AtomKind {
+    let aromatic = is_aromatic(&entry);
+    let Entry { symbol, isotope, charge, map, .. } = entry;
+    let bare = isotope.is_none() && charge == 0 && map.is_none();
+    let symbol = match symbol {
+        BracketSymbol::Star => if bare {
+            return AtomKind::Star
+        } else {
+            BracketSymbol::Star
+        },
+        BracketSymbol::Element(element) => if aromatic {
+            let aromatic = BracketAromatic::try_from(&element)
+                .expect("bracket aromatic");
+
+            if bare {
+                if let Ok(aromatic) = Aromatic::try_from(&aromatic) {
+                    return AtomKind::Aromatic(aromatic)
+                }
+            }
+
+            BracketSymbol::Aromatic(aromatic)
+        } else {
+            if bare {
+                if let Ok(aliphatic) = Aliphatic::try_from(&element) {
+                    return AtomKind::Aliphatic(aliphatic)
+                }
+            }
+
+            BracketSymbol::Element(element)
+        },
+        BracketSymbol::Aromatic(_) => unreachable!("aromatic symbol")
+    };
+    let mut result = Atom::new(AtomKind::Bracket {
+        isotope,
+        symbol,
+        configuration: None,
+        hcount: None,
+        charge: Charge::try_from(charge).ok(),
+        map
+    });
+
+    result.bonds = atom.bonds.iter()
+        .map(|bond| Bond::new(bond.kind.clone(), bond.tid)).collect();
+
+    let mut hcount = result.subvalence();
+
+    if aromatic {
+        hcount = hcount.saturating_sub(1)
+    }
+
+    if let AtomKind::Bracket { hcount: virtual_hydrogen, .. } = &mut result.kind {
+        if hcount > 0 {
+            *virtual_hydrogen = VirtualHydrogen::try_from(hcount).ok()
+        }
+    }
+
+    result.kind
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_molblock;
+    use crate::walk::walk;
+    use crate::write::Writer;
+    use crate::test_support::build;
+    use super::*;
+
+    fn sorted(mut atoms: Vec<Atom>) -> Vec<Atom> {
+        for atom in atoms.iter_mut() {
+            atom.bonds.sort_by_key(|bond| bond.tid)
+        }
+
+        atoms
+    }
+
+    fn smiles(atoms: Vec<Atom>) -> String {
+        let mut writer = Writer::new();
+
+        walk(atoms, &mut writer).unwrap();
+
+        writer.write()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(from_molblock(&to_molblock(&[ ]).unwrap()), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn missing_counts() {
+        assert_eq!(from_molblock("\n  purr\n\n"), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn missing_atom() {
+        let molblock = "\n\n\n  2  0  0  0  0  0  0  0  0  0999 V2000\n";
+
+        assert_eq!(from_molblock(molblock), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn bad_count() {
+        let molblock = "\n\n\n  x  0  0  0  0  0  0  0  0  0999 V2000\n";
+
+        assert_eq!(from_molblock(molblock), Err(Error::Character(3)))
+    }
+
+    #[test]
+    fn unknown_symbol() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 Xx  0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "M  END\n"
+        );
+
+        assert_eq!(from_molblock(molblock), Err(Error::Character(74)))
+    }
+
+    #[test]
+    fn bond_to_unknown_atom() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  1  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "  1  2  1  0  0  0  0\n",
+            "M  END\n"
+        );
+
+        assert_eq!(from_molblock(molblock), Err(Error::Character(116)))
+    }
+
+    #[test]
+    fn any_bond() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  2  1  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "  1  2  8  0  0  0  0\n",
+            "M  END\n"
+        );
+
+        assert_eq!(from_molblock(molblock), Err(Error::Character(189)))
+    }
+
+    #[test]
+    fn atom_block_charge() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 N   0  3  0  0  0  0  0  0  0  0  0  0\n",
+            "M  END\n"
+        );
+
+        assert_eq!(smiles(from_molblock(molblock).unwrap()), "[NH4+]")
+    }
+
+    #[test]
+    fn property_charge_overrides_atom_block() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 N   0  5  0  0  0  0  0  0  0  0  0  0\n",
+            "M  CHG  1   1   1\n",
+            "M  END\n"
+        );
+
+        assert_eq!(smiles(from_molblock(molblock).unwrap()), "[NH4+]")
+    }
+
+    #[test]
+    fn property_charge_out_of_range() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 N   0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "M  CHG  1   1  16\n",
+            "M  END\n"
+        );
+
+        assert_eq!(from_molblock(molblock), Err(Error::Character(113)))
+    }
+
+    #[test]
+    fn metal() {
+        let molblock = concat!(
+            "\n\n\n",
+            "  1  0  0  0  0  0  0  0  0  0999 V2000\n",
+            "    0.0000    0.0000    0.0000 Fe  0  0  0  0  0  0  0  0  0  0  0  0\n",
+            "M  CHG  1   1   2\n",
+            "M  END\n"
+        );
+
+        assert_eq!(smiles(from_molblock(molblock).unwrap()), "[Fe+2]")
+    }
+
+    #[test]
+    fn round_trip() {
+        let tests = vec![
+            "CCO",
+            "[13CH3:1]C(=O)[O-]",
+            "c1ccccc1",
+            "c1ccncc1",
+            "c1cc[se]c1",
+            "C1CC1*",
+            "C#N.[Na+].[Cl-]",
+            "[NH4+]"
+        ];
+
+        for smiles in tests {
+            let atoms = build(smiles);
+            let molblock = to_molblock(&atoms).unwrap();
+
+            assert_eq!(
+                sorted(from_molblock(&molblock).unwrap()),
+                sorted(atoms),
+                "{}", smiles
+            )
+        }
+    }
+}
\ No newline at end of file
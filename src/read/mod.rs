@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod read;
 mod read_bond;
 mod trace;
@@ -10,12 +11,26 @@ mod read_bracket;
 mod read_symbol;
 mod read_charge;
 mod read_configuration;
+mod read_options;
+mod from_molblock;
+mod token_kind;
+mod token;
+mod tokenize;
+mod read_bytes;
+mod streaming_parser;
 
-pub use read::read;
+pub use read::{ read, read_with_options };
+pub use read_bytes::read_bytes;
+pub use streaming_parser::StreamingParser;
+pub use read_options::ReadOptions;
+pub use from_molblock::from_molblock;
 pub use error::Error;
 pub use trace::Trace;
+pub use scanner::{ Scanner, Checkpoint };
+pub use token_kind::TokenKind;
+pub use token::Token;
+pub use tokenize::tokenize;
 pub(crate) use read_bond::read_bond;
-pub(crate) use scanner::Scanner;
 pub(crate) use missing_character::missing_character;
 pub(crate) use read_rnum::read_rnum;
 pub(crate) use read_organic::read_organic;
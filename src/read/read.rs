@@ -1,11 +1,12 @@
 use crate::walk::Follower;
 use crate::feature::{ AtomKind, BondKind };
 use super::{
-    Scanner, Trace, Error, missing_character, read_rnum, read_bond,
-    read_organic, read_bracket
+    Scanner, Trace, Error, ReadOptions, missing_character, read_rnum,
+    read_bond, read_organic, read_bracket
 };
 
-/// Reads a string using a `Follower` and optional `Trace`.
+/// Reads a string using a `Follower` and optional `Trace`, with
+/// `ReadOptions::default()`. Branches nest at most 256 deep.
 /// 
 /// ```
 /// use purr::write::Writer;
@@ -24,11 +25,24 @@ use super::{
 /// }
 /// ```
 pub fn read<F: Follower>(
-    smiles: &str, follower: &mut F, mut trace: Option<&mut Trace>
+    smiles: &str, follower: &mut F, trace: Option<&mut Trace>
+) -> Result<(), Error> {
+    read_with_options(smiles, follower, trace, &ReadOptions::default())
+}
+
+/// Reads a string using a `Follower`, optional `Trace`, and `ReadOptions`.
+pub fn read_with_options<F: Follower>(
+    smiles: &str,
+    follower: &mut F,
+    mut trace: Option<&mut Trace>,
+    options: &ReadOptions
 ) -> Result<(), Error> {
     let mut scanner = Scanner::new(smiles);
+    let mut context = Context { options, atoms: 0, depth: 0 };
 
-    if read_smiles(None, &mut scanner, follower, &mut trace)?.is_some() {
+    if read_smiles(
+        None, &mut scanner, follower, &mut trace, &mut context
+    )?.is_some() {
         if scanner.is_done() {
             Ok(())
         } else {
@@ -43,19 +57,60 @@ pub fn read<F: Follower>(
     }
 }
 
+// Mutable state shared across a single read.
+struct Context<'a> {
+    options: &'a ReadOptions,
+    atoms: usize,
+    depth: usize
+}
+
 // <smiles> ::= <atom> <body>*
+//
+// Atoms chained by <split> and <union> are read by this loop rather than by
+// recursion, so stack use grows only with branch depth.
 fn read_smiles<F: Follower>(
     input: Option<BondKind>,
     scanner: &mut Scanner,
     follower: &mut F,
-    trace: &mut Option<&mut Trace>
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
 ) -> Result<Option<usize>, Error> {
+    if !read_link(input, scanner, follower, trace, context)? {
+        return Ok(None)
+    }
+
+    let mut result = 1;
+
+    loop {
+        match read_body(scanner, follower, trace, context)? {
+            Some(length) => result += length,
+            None => break Ok(Some(result))
+        }
+    }
+}
+
+// Reads an atom, reporting it as a root or as bonded by input.
+fn read_link<F: Follower>(
+    input: Option<BondKind>,
+    scanner: &mut Scanner,
+    follower: &mut F,
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
+) -> Result<bool, Error> {
     let cursor = scanner.cursor();
-    let atom_kind = match read_atom(scanner)? {
+    let atom_kind = match read_atom(scanner, context.options)? {
         Some(kind) => kind,
-        None => return Ok(None)
+        None => return Ok(false)
     };
 
+    context.atoms += 1;
+
+    if let Some(max_atoms) = context.options.max_atoms {
+        if context.atoms > max_atoms {
+            return Err(Error::TooLarge(cursor))
+        }
+    }
+
     match input {
         Some(bond_kind) => {
             if let Some(trace) = trace {
@@ -77,25 +132,18 @@ fn read_smiles<F: Follower>(
         }
     }
 
-    let mut result = 1;
-
-    loop {
-        match read_body(scanner, follower, trace)? {
-            Some(length) => result += length,
-            None => break Ok(Some(result))
-        }
-    }
+    Ok(true)
 }
 
 // <atom> ::= <organic> | <bracket> | <star>
 fn read_atom(
-    scanner: &mut Scanner
+    scanner: &mut Scanner, options: &ReadOptions
 ) -> Result<Option<AtomKind>, Error> {
     if let Some(organic) = read_organic(scanner)? {
         return Ok(Some(organic))
     }
 
-    if let Some(bracket) = read_bracket(scanner)? {
+    if let Some(bracket) = read_bracket(scanner, options)? {
         return Ok(Some(bracket))
     }
 
@@ -104,23 +152,31 @@ fn read_atom(
 
 // <body> ::= <branch> | <split> | <union>
 fn read_body<F: Follower>(
-    scanner: &mut Scanner, follower: &mut F, trace: &mut Option<&mut Trace>
+    scanner: &mut Scanner,
+    follower: &mut F,
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
 ) -> Result<Option<usize>, Error> {
-    if read_branch(scanner, follower, trace)? {
+    if read_branch(scanner, follower, trace, context)? {
         return Ok(Some(0))
     }
 
-    if let Some(length) = read_split(scanner, follower, trace)? {
+    if let Some(length) = read_split(scanner, follower, trace, context)? {
         return Ok(Some(length))
     }
 
-    read_union(scanner, follower, trace)
+    read_union(scanner, follower, trace, context)
 }
 
 // <branch> ::= "(" ( <dot> | <bond> )? <smiles> ")"
 fn read_branch<F: Follower>(
-    scanner: &mut Scanner, follower: &mut F, trace: &mut Option<&mut Trace>
+    scanner: &mut Scanner,
+    follower: &mut F,
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
 ) -> Result<bool, Error> {
+    let cursor = scanner.cursor();
+
     match scanner.peek() {
         Some('(') => {
             scanner.pop();
@@ -128,11 +184,19 @@ fn read_branch<F: Follower>(
         _ => return Ok(false)
     }
 
+    context.depth += 1;
+
+    if let Some(max_branch_depth) = context.options.max_branch_depth {
+        if context.depth > max_branch_depth {
+            return Err(Error::TooDeep(cursor))
+        }
+    }
+
     let length = match scanner.peek() {
         Some('.') => {
             scanner.pop();
 
-            match read_smiles(None, scanner, follower, trace)? {
+            match read_smiles(None, scanner, follower, trace, context)? {
                 Some(length) => length,
                 None => return Err(missing_character(scanner))
             }
@@ -140,7 +204,9 @@ fn read_branch<F: Follower>(
         _ => {
             let bond_kind = read_bond(scanner);
 
-            match read_smiles(Some(bond_kind), scanner, follower, trace)? {
+            match read_smiles(
+                Some(bond_kind), scanner, follower, trace, context
+            )? {
                 Some(length) => length,
                 None => return Err(missing_character(scanner))
             }
@@ -151,6 +217,7 @@ fn read_branch<F: Follower>(
         Some(')') => {
             scanner.pop();
             follower.pop(length);
+            context.depth -= 1;
 
             if let Some(trace) = trace {
                 trace.pop(length)
@@ -162,9 +229,12 @@ fn read_branch<F: Follower>(
     }
 }
 
-// <split> ::= <dot> <smiles>
+// <split> ::= <dot> <smiles>, reading only the leading atom of <smiles>
 fn read_split<F: Follower>(
-    scanner: &mut Scanner, follower: &mut F, trace: &mut Option<&mut Trace>
+    scanner: &mut Scanner,
+    follower: &mut F,
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
 ) -> Result<Option<usize>, Error> {
     match scanner.peek() {
         Some('.') => {
@@ -173,23 +243,26 @@ fn read_split<F: Follower>(
         _ => return Ok(None)
     }
 
-    match read_smiles(None, scanner, follower, trace)? {
-        Some(length) => Ok(Some(length)),
-        None => Err(missing_character(scanner))
+    if read_link(None, scanner, follower, trace, context)? {
+        Ok(Some(1))
+    } else {
+        Err(missing_character(scanner))
     }
 }
 
-// <union> ::= <bond>? ( <smiles> | <rnum> )
+// <union> ::= <bond>? ( <smiles> | <rnum> ), reading only the leading atom
+// of <smiles>
 fn read_union<F: Follower>(
-    scanner: &mut Scanner, follower: &mut F, trace: &mut Option<&mut Trace>
+    scanner: &mut Scanner,
+    follower: &mut F,
+    trace: &mut Option<&mut Trace>,
+    context: &mut Context
 ) -> Result<Option<usize>, Error> {
     let bond_cursor = scanner.cursor();
     let bond_kind = read_bond(scanner);
 
-    if let Some(length) = read_smiles(
-        Some(bond_kind.clone()), scanner, follower, trace
-    )? {
-        return Ok(Some(length))
+    if read_link(Some(bond_kind.clone()), scanner, follower, trace, context)? {
+        return Ok(Some(1))
     }
 
     let cursor = scanner.cursor();
@@ -227,9 +300,12 @@ fn read_star(
 }
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod read {
     use pretty_assertions::assert_eq;
     use crate::write::Writer;
+    use crate::graph::{ Bond, Builder };
+    use crate::walk::walk;
     use super::*;
 
     #[test]
@@ -252,6 +328,16 @@ mod read {
         )
     }
 
+    #[test]
+    fn non_ascii_tail() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("C[C]é", &mut writer, None),
+            Err(Error::Character(4))
+        )
+    }
+
     #[test]
     fn invalid_tail() {
         let mut writer = Writer::new();
@@ -382,6 +468,26 @@ mod read {
         )
     }
 
+    #[test]
+    fn charge_plus_minus() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("C[C+-]C", &mut writer, None),
+            Err(Error::Character(4))
+        )
+    }
+
+    #[test]
+    fn charge_minus_plus() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("C[C-+]C", &mut writer, None),
+            Err(Error::Character(4))
+        )
+    }
+
     #[test]
     fn p1() {
         let mut writer = Writer::new();
@@ -418,6 +524,44 @@ mod read {
         assert_eq!(writer.write(), "[CH4]")
     }
 
+    #[test]
+    fn aromatic_selenium_bare() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("c1ccsec1", &mut writer, None),
+            Err(Error::Character(5))
+        )
+    }
+
+    #[test]
+    fn aromatic_selenium_bracket() {
+        let mut writer = Writer::new();
+
+        read("c1cc[se]c1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "c1cc[se]c1")
+    }
+
+    #[test]
+    fn aromatic_arsenic_bare() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("c1ccasc1", &mut writer, None),
+            Err(Error::Character(4))
+        )
+    }
+
+    #[test]
+    fn aromatic_arsenic_bracket() {
+        let mut writer = Writer::new();
+
+        read("c1cc[as]c1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "c1cc[as]c1")
+    }
+
     #[test]
     fn elided_rnum() {
         let mut writer = Writer::new();
@@ -436,6 +580,76 @@ mod read {
         assert_eq!(writer.write(), "*-1")
     }
 
+    #[test]
+    fn bond_rnum_both_ends() {
+        let mut writer = Writer::new();
+
+        read("C=1CCCCC=1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C=1CCCCC=1")
+    }
+
+    #[test]
+    fn bond_rnum_open() {
+        let mut writer = Writer::new();
+
+        read("C-1CCCCC1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C-1CCCCC1")
+    }
+
+    #[test]
+    fn bond_rnum_close() {
+        let mut writer = Writer::new();
+
+        read("C1CCCCC-1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C1CCCCC-1")
+    }
+
+    #[test]
+    fn bond_percent_rnum() {
+        let mut writer = Writer::new();
+
+        read("C-%10CCCCC%10", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C-%10CCCCC%10")
+    }
+
+    #[test]
+    fn bond_percent_rnum_both_ends() {
+        let mut builder = Builder::new();
+
+        read("C=%10CCCCC=%10", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Double, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Double, 0))
+    }
+
+    #[test]
+    fn bond_percent_rnum_close() {
+        let mut builder = Builder::new();
+
+        read("C%10CCCCC-%10", &mut builder, None).unwrap();
+
+        let atoms = builder.build().unwrap();
+
+        assert_eq!(atoms[0].bonds[0], Bond::new(BondKind::Single, 5));
+        assert_eq!(atoms[5].bonds[1], Bond::new(BondKind::Single, 0))
+    }
+
+    #[test]
+    fn bond_percent_missing_digit() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("C-%1CCCCC%10", &mut writer, None),
+            Err(Error::Character(4))
+        )
+    }
+
     #[test]
     fn p1_p1() {
         let mut writer = Writer::new();
@@ -454,6 +668,35 @@ mod read {
         assert_eq!(writer.write(), "*(.*)*")
     }
 
+    #[test]
+    fn p1_p3_branched_inner() {
+        let mut writer = Writer::new();
+
+        read("C(.NCC)O", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C(.NCC)O")
+    }
+
+    #[test]
+    fn p1_p3_branched_inner_branched() {
+        let mut writer = Writer::new();
+
+        read("C(.NC(C)C)O", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "C(.NC(C)C)O")
+    }
+
+    #[test]
+    fn p1_p3_branched_inner_graph() {
+        let mut builder = Builder::new();
+        let mut writer = Writer::new();
+
+        read("C(.NCC)O", &mut builder, None).unwrap();
+        walk(builder.build().unwrap(), &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "CO.NCC")
+    }
+
     #[test]
     fn p2() {
         let mut writer = Writer::new();
@@ -463,6 +706,15 @@ mod read {
         assert_eq!(writer.write(), "*-*")
     }
 
+    #[test]
+    fn p2_quadruple() {
+        let mut writer = Writer::new();
+
+        read("[Re]$[Re]", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "[Re]$[Re]")
+    }
+
     #[test]
     fn p3() {
         let mut writer = Writer::new();
@@ -534,6 +786,129 @@ mod read {
 
         assert_eq!(writer.write(), "C(F)Cl")
     }
+
+    #[test]
+    fn max_atoms_long_chain() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            max_atoms: Some(10),
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_with_options(&"C".repeat(1000), &mut writer, None, &options),
+            Err(Error::TooLarge(10))
+        )
+    }
+
+    #[test]
+    fn max_atoms_branch_and_split() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            max_atoms: Some(3),
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_with_options("C(C)C.C", &mut writer, None, &options),
+            Err(Error::TooLarge(6))
+        )
+    }
+
+    #[test]
+    fn max_atoms_at_limit() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            max_atoms: Some(4),
+            ..ReadOptions::default()
+        };
+
+        read_with_options("C(C)C.C", &mut writer, None, &options).unwrap();
+
+        assert_eq!(writer.write(), "C(C)C.C")
+    }
+
+    #[test]
+    fn long_chain() {
+        let mut writer = Writer::new();
+        let smiles = "C".repeat(100000);
+
+        read(&smiles, &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), smiles)
+    }
+
+    #[test]
+    fn long_split() {
+        let mut writer = Writer::new();
+        let smiles = vec![ "C"; 100000 ].join(".");
+
+        read(&smiles, &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), smiles)
+    }
+
+    #[test]
+    fn max_branch_depth_default() {
+        let mut writer = Writer::new();
+        let smiles = format!("{}C{}", "C(".repeat(5000), ")".repeat(5000));
+
+        assert_eq!(
+            read(&smiles, &mut writer, None),
+            Err(Error::TooDeep(513))
+        )
+    }
+
+    #[test]
+    fn max_branch_depth_exceeded() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            max_branch_depth: Some(2),
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_with_options("C(C(C(C)))", &mut writer, None, &options),
+            Err(Error::TooDeep(5))
+        )
+    }
+
+    #[test]
+    fn max_branch_depth_siblings() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            max_branch_depth: Some(1),
+            ..ReadOptions::default()
+        };
+
+        read_with_options("C(C)(C)(C)C", &mut writer, None, &options).unwrap();
+
+        assert_eq!(writer.write(), "C(C)(C)(C)C")
+    }
+
+    #[test]
+    fn hydrogen_isotope_symbols_strict() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read("[D]O[D]", &mut writer, None),
+            Err(Error::Character(2))
+        )
+    }
+
+    #[test]
+    fn hydrogen_isotope_symbols_lenient() {
+        let mut writer = Writer::new();
+        let options = ReadOptions {
+            allow_hydrogen_isotope_symbols: true,
+            ..ReadOptions::default()
+        };
+
+        read_with_options("[D]O[D].[T]C", &mut writer, None, &options)
+            .unwrap();
+
+        assert_eq!(writer.write(), "[2H]O[2H].[3H]C")
+    }
 }
 
 #[cfg(test)]
@@ -612,4 +987,51 @@ mod trace {
         assert_eq!(trace.bond(2, 1), Some(3));
         assert_eq!(trace.bond(2, 0), Some(4));
     }
+
+    #[test]
+    fn c3_percent() {
+        let mut trace = Trace::new();
+        let mut writer = Writer::new();
+
+        //    012345678
+        read("*%10**%10", &mut writer, Some(&mut trace)).unwrap();
+
+        assert_eq!(trace.atom(0), Some(0..1));
+        assert_eq!(trace.atom(1), Some(4..5));
+        assert_eq!(trace.atom(2), Some(5..6));
+        assert_eq!(trace.rnum(0), Some(1..4));
+        assert_eq!(trace.rnum(1), Some(6..9));
+        assert_eq!(trace.bond(0, 2), Some(1));
+        assert_eq!(trace.bond(2, 0), Some(6))
+    }
+
+    #[test]
+    fn c3_bond_percent() {
+        let mut trace = Trace::new();
+        let mut writer = Writer::new();
+
+        //    01234567890
+        read("*=%10**=%10", &mut writer, Some(&mut trace)).unwrap();
+
+        assert_eq!(trace.atom(1), Some(5..6));
+        assert_eq!(trace.rnum(0), Some(2..5));
+        assert_eq!(trace.rnum(1), Some(8..11));
+        assert_eq!(trace.bond(0, 2), Some(1));
+        assert_eq!(trace.bond(2, 0), Some(7))
+    }
+
+    #[test]
+    fn c3_percent_mixed_with_digit() {
+        let mut trace = Trace::new();
+        let mut writer = Writer::new();
+
+        //    01234567890
+        read("*%101**1%10", &mut writer, Some(&mut trace)).unwrap();
+
+        assert_eq!(trace.rnum(0), Some(1..4));
+        assert_eq!(trace.rnum(1), Some(4..5));
+        assert_eq!(trace.rnum(2), Some(7..8));
+        assert_eq!(trace.rnum(3), Some(8..11));
+        assert_eq!(trace.atom(2), Some(6..7))
+    }
 }
\ No newline at end of file
@@ -1,28 +1,44 @@
-use std::convert::TryInto;
+use std::convert::{ TryFrom, TryInto };
 
-use crate::feature::{ AtomKind, VirtualHydrogen, Number };
+use crate::feature::{
+    AtomKind, BracketSymbol, Charge, Element, VirtualHydrogen, Number
+};
 use super::{
     scanner::Scanner,
     read_symbol,
     read_charge,
     read_configuration,
     missing_character,
-    Error
+    Error,
+    ReadOptions
 };
 
-pub fn read_bracket(scanner: &mut Scanner) -> Result<Option<AtomKind>, Error> {
+pub fn read_bracket(
+    scanner: &mut Scanner, options: &ReadOptions
+) -> Result<Option<AtomKind>, Error> {
     if let Some('[') = scanner.peek() {
         scanner.pop();
     } else {
         return Ok(None);
     }
 
-    let isotope = read_isotope(scanner)?;
-    let symbol = read_symbol(scanner)?;
+    let cursor = scanner.cursor();
+    let isotope = read_isotope(scanner, options)?;
+    let (isotope, symbol) = match read_hydrogen_isotope(scanner, options) {
+        Some(_) if isotope.is_some() => return Err(Error::Character(cursor)),
+        Some(mass) => (Some(mass), BracketSymbol::Element(Element::H)),
+        None => (isotope, read_symbol(scanner)?)
+    };
     let configuration = read_configuration(scanner)?;
     let hcount = read_hcount(scanner)?;
+    let charge_cursor = scanner.cursor();
     let charge = read_charge(scanner)?;
-    let map = read_map(scanner)?;
+
+    if options.validate_charges && !is_plausible(&symbol, &charge) {
+        return Err(Error::Character(charge_cursor))
+    }
+
+    let map = read_map(scanner, options)?;
 
     match scanner.peek() {
         Some(']') => {
@@ -37,6 +53,47 @@ pub fn read_bracket(scanner: &mut Scanner) -> Result<Option<AtomKind>, Error> {
     }
 }
 
+fn is_plausible(symbol: &BracketSymbol, charge: &Option<Charge>) -> bool {
+    let element = match symbol {
+        BracketSymbol::Star => return true,
+        BracketSymbol::Element(element) => element.clone(),
+        BracketSymbol::Aromatic(aromatic) => Element::from(aromatic)
+    };
+
+    match charge {
+        Some(charge) => element.charge_range().contains(&charge.into()),
+        None => true
+    }
+}
+
+fn read_hydrogen_isotope(
+    scanner: &mut Scanner, options: &ReadOptions
+) -> Option<Number> {
+    if !options.allow_hydrogen_isotope_symbols {
+        return None
+    }
+
+    let checkpoint = scanner.checkpoint();
+    let mass = match scanner.pop() {
+        Some('D') => 2,
+        Some('T') => 3,
+        _ => {
+            scanner.restore(checkpoint);
+
+            return None
+        }
+    };
+
+    match scanner.peek() {
+        Some('a'..='z') => {
+            scanner.restore(checkpoint);
+
+            None
+        },
+        _ => Some(Number::try_from(mass).expect("mass"))
+    }
+}
+
 fn read_hcount(
     scanner: &mut Scanner
 ) -> Result<Option<VirtualHydrogen>, Error> {
@@ -65,10 +122,12 @@ fn read_hcount(
     }
 }
 
-fn read_isotope(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
+fn read_isotope(
+    scanner: &mut Scanner, options: &ReadOptions
+) -> Result<Option<Number>, Error> {
     let mut digits = String::new();
 
-    for _ in 0..3 {
+    for _ in 0..options.number_digits() {
         match scanner.peek() {
             Some('0'..='9') => digits.push(*scanner.pop().expect("digit")),
             _ => break
@@ -82,7 +141,9 @@ fn read_isotope(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
     }
 }
 
-fn read_map(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
+fn read_map(
+    scanner: &mut Scanner, options: &ReadOptions
+) -> Result<Option<Number>, Error> {
     match scanner.peek() {
         Some(':') => {
             scanner.pop();
@@ -98,7 +159,7 @@ fn read_map(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
                 None => return Err(missing_character(scanner))
             }
  
-            for _ in 0..2 {
+            for _ in 1..options.number_digits() {
                 match scanner.peek() {
                     Some('0'..='9') =>
                         digits.push(*scanner.pop().expect("digit")),
@@ -106,7 +167,13 @@ fn read_map(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
                 }
             }
 
-            Ok(Some(digits.try_into().expect("number")))
+            let map: Number = digits.try_into().expect("number");
+
+            if options.treat_zero_map_as_none && u16::from(&map) == 0 {
+                Ok(None)
+            } else {
+                Ok(Some(map))
+            }
         },
         _ => Ok(None)
     }
@@ -116,63 +183,222 @@ fn read_map(scanner: &mut Scanner) -> Result<Option<Number>, Error> {
 mod tests {
     use std::convert::TryInto;
     use pretty_assertions::assert_eq;
-    use crate::feature::{ BracketSymbol, Configuration, BracketAromatic, Charge };
+    use crate::feature::{
+        BracketSymbol, Configuration, BracketAromatic, Charge, Element
+    };
     use super::*;
 
+    #[test]
+    fn empty() {
+        let mut scanner = Scanner::new("[]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(1))
+        )
+    }
+
+    #[test]
+    fn open_only() {
+        let mut scanner = Scanner::new("[");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::EndOfLine)
+        )
+    }
+
+    #[test]
+    fn charge_without_symbol() {
+        let mut scanner = Scanner::new("[+]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(1))
+        )
+    }
+
+    #[test]
+    fn isotope_without_symbol() {
+        let mut scanner = Scanner::new("[13]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(3))
+        )
+    }
+
     #[test]
     fn overflow_map() {
         let mut scanner = Scanner::new("[*:1000]");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::Character(6)))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(6))
+        )
     }
 
     #[test]
     fn overflow_isotope() {
         let mut scanner = Scanner::new("[1000U]");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::Character(4)))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(4))
+        )
+    }
+
+    #[test]
+    fn isotope_four_digits() {
+        let mut scanner = Scanner::new("[1000U]");
+        let options = ReadOptions {
+            max_number_digits: 4,
+            ..ReadOptions::default()
+        };
+
+        let atom = read_bracket(&mut scanner, &options);
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: Some("1000".to_string().try_into().unwrap()),
+            symbol: BracketSymbol::Element(Element::U),
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: None
+        })))
+    }
+
+    #[test]
+    fn map_four_digits() {
+        let mut scanner = Scanner::new("[*:1000]");
+        let options = ReadOptions {
+            max_number_digits: 4,
+            ..ReadOptions::default()
+        };
+
+        let atom = read_bracket(&mut scanner, &options);
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Star,
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: Some("1000".to_string().try_into().unwrap())
+        })))
+    }
+
+    #[test]
+    fn map_zero_as_none() {
+        let mut scanner = Scanner::new("[*:00]");
+        let options = ReadOptions {
+            treat_zero_map_as_none: true,
+            ..ReadOptions::default()
+        };
+
+        let atom = read_bracket(&mut scanner, &options);
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Star,
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: None
+        })))
+    }
+
+    #[test]
+    fn map_nonzero_with_zero_as_none() {
+        let mut scanner = Scanner::new("[*:10]");
+        let options = ReadOptions {
+            treat_zero_map_as_none: true,
+            ..ReadOptions::default()
+        };
+
+        let atom = read_bracket(&mut scanner, &options);
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Star,
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: Some(10u16.try_into().unwrap())
+        })))
+    }
+
+    #[test]
+    fn overflow_isotope_five_digits() {
+        let mut scanner = Scanner::new("[10000U]");
+        let options = ReadOptions {
+            max_number_digits: 5,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_bracket(&mut scanner, &options),
+            Err(Error::Character(5))
+        )
     }
 
     #[test]
     fn bracket_invalid() {
         let mut scanner = Scanner::new("[Q]");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::Character(1)))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(1))
+        )
     }
 
     #[test]
     fn no_close() {
         let mut scanner = Scanner::new("[C");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::EndOfLine))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::EndOfLine)
+        )
     }
 
     #[test]
     fn colon_but_no_map() {
         let mut scanner = Scanner::new("[C:]");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::Character(3)))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(3))
+        )
     }
 
     #[test]
     fn colon_eol() {
         let mut scanner = Scanner::new("[C:");
 
-        assert_eq!(read_bracket(&mut scanner), Err(Error::EndOfLine))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::EndOfLine)
+        )
     }
 
     #[test]
     fn no_open() {
         let mut scanner = Scanner::new("?");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(None))
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Ok(None)
+        )
     }
 
     #[test]
     fn star() {
         let mut scanner = Scanner::new("[*]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Star,
             configuration: None,
@@ -186,7 +412,9 @@ mod tests {
     fn star_isotope() {
         let mut scanner = Scanner::new("[999*]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: Some(999.try_into().unwrap()),
             symbol: BracketSymbol::Star,
             configuration: None,
@@ -200,7 +428,9 @@ mod tests {
     fn star_configuration() {
         let mut scanner = Scanner::new("[*@]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Star,
             configuration: Some(Configuration::TH1),
@@ -214,7 +444,9 @@ mod tests {
     fn star_hcount() {
         let mut scanner = Scanner::new("[*H2]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Star,
             configuration: None,
@@ -228,7 +460,9 @@ mod tests {
     fn star_charge() {
         let mut scanner = Scanner::new("[*+]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Star,
             configuration: None,
@@ -242,7 +476,9 @@ mod tests {
     fn star_map() {
         let mut scanner = Scanner::new("[*:999]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Star,
             configuration: None,
@@ -252,11 +488,99 @@ mod tests {
         })))
     }
 
+    #[test]
+    fn star_map_zero() {
+        let mut scanner = Scanner::new("[*:0]");
+
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Star,
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: Some(0u16.try_into().unwrap())
+        })))
+    }
+
+    #[test]
+    fn charge_plus_plus_plus() {
+        let mut scanner = Scanner::new("[Fe+++]");
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::Fe),
+            configuration: None,
+            hcount: None,
+            charge: Some(Charge::Three),
+            map: None
+        })))
+    }
+
+    #[test]
+    fn charge_minus_minus_minus() {
+        let mut scanner = Scanner::new("[N---]");
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::N),
+            configuration: None,
+            hcount: None,
+            charge: Some(Charge::MinusThree),
+            map: None
+        })))
+    }
+
+    #[test]
+    fn charge_plus_minus() {
+        let mut scanner = Scanner::new("[C+-]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(3))
+        )
+    }
+
+    #[test]
+    fn charge_minus_plus() {
+        let mut scanner = Scanner::new("[C-+]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(3))
+        )
+    }
+
+    #[test]
+    fn charge_plus_plus_minus() {
+        let mut scanner = Scanner::new("[C++-]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(4))
+        )
+    }
+
+    #[test]
+    fn charge_plus_digit_minus() {
+        let mut scanner = Scanner::new("[C+2-]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(4))
+        )
+    }
+
     #[test]
     fn bracket_aromatic_charge() {
         let mut scanner = Scanner::new("[s+]");
 
-        assert_eq!(read_bracket(&mut scanner), Ok(Some(AtomKind::Bracket {
+        let atom = read_bracket(&mut scanner, &ReadOptions::default());
+
+        assert_eq!(atom, Ok(Some(AtomKind::Bracket {
             isotope: None,
             symbol: BracketSymbol::Aromatic(BracketAromatic::S),
             configuration: None,
@@ -265,4 +589,178 @@ mod tests {
             map: None
         })))
     }
+
+    #[test]
+    fn deuterium_strict() {
+        let mut scanner = Scanner::new("[D]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Err(Error::Character(2))
+        )
+    }
+
+    #[test]
+    fn deuterium_lenient() {
+        let mut scanner = Scanner::new("[D]");
+        let options = ReadOptions {
+            allow_hydrogen_isotope_symbols: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(read_bracket(&mut scanner, &options), Ok(Some(
+            AtomKind::Bracket {
+                isotope: Some(2.try_into().unwrap()),
+                symbol: BracketSymbol::Element(Element::H),
+                configuration: None,
+                hcount: None,
+                charge: None,
+                map: None
+            }
+        )))
+    }
+
+    #[test]
+    fn tritium_lenient_charge_map() {
+        let mut scanner = Scanner::new("[T+:1]");
+        let options = ReadOptions {
+            allow_hydrogen_isotope_symbols: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(read_bracket(&mut scanner, &options), Ok(Some(
+            AtomKind::Bracket {
+                isotope: Some(3.try_into().unwrap()),
+                symbol: BracketSymbol::Element(Element::H),
+                configuration: None,
+                hcount: None,
+                charge: Some(Charge::One),
+                map: Some(1.try_into().unwrap())
+            }
+        )))
+    }
+
+    #[test]
+    fn dysprosium_lenient() {
+        let mut scanner = Scanner::new("[Dy]");
+        let options = ReadOptions {
+            allow_hydrogen_isotope_symbols: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(read_bracket(&mut scanner, &options), Ok(Some(
+            AtomKind::Bracket {
+                isotope: None,
+                symbol: BracketSymbol::Element(Element::Dy),
+                configuration: None,
+                hcount: None,
+                charge: None,
+                map: None
+            }
+        )))
+    }
+
+    #[test]
+    fn deuterium_lenient_with_isotope() {
+        let mut scanner = Scanner::new("[2D]");
+        let options = ReadOptions {
+            allow_hydrogen_isotope_symbols: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_bracket(&mut scanner, &options),
+            Err(Error::Character(1))
+        )
+    }
+
+    #[test]
+    fn validate_charges_off() {
+        let mut scanner = Scanner::new("[C+10]");
+
+        assert_eq!(
+            read_bracket(&mut scanner, &ReadOptions::default()),
+            Ok(Some(AtomKind::Bracket {
+                isotope: None,
+                symbol: BracketSymbol::Element(Element::C),
+                configuration: None,
+                hcount: None,
+                charge: Some(Charge::Ten),
+                map: None
+            }))
+        )
+    }
+
+    #[test]
+    fn validate_charges_implausible() {
+        let mut scanner = Scanner::new("[CH2+10]");
+        let options = ReadOptions {
+            validate_charges: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_bracket(&mut scanner, &options),
+            Err(Error::Character(4))
+        )
+    }
+
+    #[test]
+    fn validate_charges_oxidation_state() {
+        let options = ReadOptions {
+            validate_charges: true,
+            ..ReadOptions::default()
+        };
+
+        for smiles in &[ "[C-4]", "[C+4]", "[Cl+7]", "[He+]", "[Na-]" ] {
+            let mut scanner = Scanner::new(smiles);
+
+            assert!(read_bracket(&mut scanner, &options).is_err(), "{}", smiles)
+        }
+    }
+
+    #[test]
+    fn validate_charges_plausible() {
+        let mut scanner = Scanner::new("[Fe+3]");
+        let options = ReadOptions {
+            validate_charges: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(read_bracket(&mut scanner, &options), Ok(Some(
+            AtomKind::Bracket {
+                isotope: None,
+                symbol: BracketSymbol::Element(Element::Fe),
+                configuration: None,
+                hcount: None,
+                charge: Some(Charge::Three),
+                map: None
+            }
+        )))
+    }
+
+    #[test]
+    fn validate_charges_aromatic() {
+        let mut scanner = Scanner::new("[o+3]");
+        let options = ReadOptions {
+            validate_charges: true,
+            ..ReadOptions::default()
+        };
+
+        assert_eq!(
+            read_bracket(&mut scanner, &options),
+            Err(Error::Character(2))
+        )
+    }
+
+    #[test]
+    fn validate_charges_star() {
+        let mut scanner = Scanner::new("[*+10]");
+        let options = ReadOptions {
+            validate_charges: true,
+            ..ReadOptions::default()
+        };
+
+        assert!(read_bracket(&mut scanner, &options).is_ok())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use crate::walk::Follower;
+use super::{ Trace, Error, read };
+
+/// Reads a byte buffer using a `Follower` and optional `Trace`, without
+/// first allocating a `String`. SMILES is ASCII, so any byte outside that
+/// range fails with `Error::Character` at its index. Because every
+/// accepted byte is one character, error positions match those of `read`.
+/// 
+/// ```
+/// use purr::write::Writer;
+/// use purr::read::{ read_bytes, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut writer = Writer::new();
+///
+///     read_bytes(b"CC(=O)N", &mut writer, None)?;
+/// 
+///     assert_eq!(writer.write(), "CC(=O)N");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_bytes<F: Follower>(
+    bytes: &[u8], follower: &mut F, trace: Option<&mut Trace>
+) -> Result<(), Error> {
+    if let Some(index) = bytes.iter().position(|byte| !byte.is_ascii()) {
+        return Err(Error::Character(index))
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(smiles) => read(smiles, follower, trace),
+        Err(_) => unreachable!("ascii")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::write::Writer;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let mut writer = Writer::new();
+
+        assert_eq!(read_bytes(b"", &mut writer, None), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn non_ascii_first() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read_bytes(&[ 0xc3, 0xa9 ], &mut writer, None),
+            Err(Error::Character(0))
+        )
+    }
+
+    #[test]
+    fn non_ascii_tail() {
+        let mut writer = Writer::new();
+        let bytes = [ b'C', b'C', 0xff ];
+
+        assert_eq!(
+            read_bytes(&bytes, &mut writer, None),
+            Err(Error::Character(2))
+        )
+    }
+
+    #[test]
+    fn invalid_ascii() {
+        let mut writer = Writer::new();
+
+        assert_eq!(
+            read_bytes(b"CC?", &mut writer, None),
+            Err(Error::Character(2))
+        )
+    }
+
+    #[test]
+    fn cco() {
+        let mut writer = Writer::new();
+        let bytes: &[u8] = "CCO".as_bytes();
+
+        read_bytes(bytes, &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "CCO")
+    }
+
+    #[test]
+    fn cco_trace() {
+        let mut writer = Writer::new();
+        let mut trace = Trace::new();
+
+        read_bytes(b"CCO", &mut writer, Some(&mut trace)).unwrap();
+
+        assert_eq!(trace.bond(1, 2), Some(2))
+    }
+}
\ No newline at end of file
@@ -8,42 +8,46 @@ pub fn read_charge(scanner: &mut Scanner) -> Result<Option<Charge>, Error> {
         Some('+') => {
             scanner.pop();
 
-            match fifteen(scanner) {
-                Some(value) => Ok(Some(value.try_into().expect("charge"))),
-                None => match scanner.peek() {
-                    Some('+') => {
-                        scanner.pop();
-
-                        Ok(Some(Charge::Two))
-                    },
-                    _ => Ok(Some(Charge::One))
-                }
-            }
+            let value = match fifteen(scanner) {
+                Some(value) => value,
+                None => run('+', scanner)
+            };
+
+            Ok(Some(value.try_into().expect("charge")))
         },
         Some('-') => {
             scanner.pop();
 
-            match fifteen(scanner) {
-                Some(value) => Ok(Some((-value).try_into().expect("charge"))),
-                None => match scanner.peek() {
-                    Some('-') => {
-                        scanner.pop();
-
-                        Ok(Some(Charge::MinusTwo))
-                    }
-                    _ => Ok(Some(Charge::MinusOne))
-                }
-            }
+            let value = match fifteen(scanner) {
+                Some(value) => value,
+                None => run('-', scanner)
+            };
+
+            Ok(Some((-value).try_into().expect("charge")))
         },
         _ => Ok(None)
     }
 }
 
+// Counts a run of `sign` following the one already popped, up to fifteen.
+fn run(sign: char, scanner: &mut Scanner) -> i8 {
+    let mut result = 1;
+
+    while result < 15 && scanner.peek() == Some(&sign) {
+        scanner.pop();
+
+        result += 1
+    }
+
+    result
+}
+
 fn fifteen(scanner: &mut Scanner) -> Option<i8> {
     match scanner.peek() {
         Some('1'..='9') => Some(match scanner.pop() {
             Some('1') => match scanner.peek() {
-                Some('1'..='5') => match scanner.pop() {
+                Some('0'..='5') => match scanner.pop() {
+                    Some('0') => 10,
                     Some('1') => 11,
                     Some('2') => 12,
                     Some('3') => 13,
@@ -107,6 +111,20 @@ mod tests {
         assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::MinusFifteen)))
     }
 
+    #[test]
+    fn minus_10_x() {
+        let mut scanner = Scanner::new("-10X");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::MinusTen)))
+    }
+
+    #[test]
+    fn plus_10_x() {
+        let mut scanner = Scanner::new("+10X");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::Ten)))
+    }
+
     #[test]
     fn plus_x() {
         let mut scanner = Scanner::new("+X");
@@ -121,6 +139,36 @@ mod tests {
         assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::Two)))
     }
 
+    #[test]
+    fn plus_plus_plus_x() {
+        let mut scanner = Scanner::new("+++X");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::Three)))
+    }
+
+    #[test]
+    fn minus_minus_minus_x() {
+        let mut scanner = Scanner::new("---X");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::MinusThree)))
+    }
+
+    #[test]
+    fn plus_times_sixteen() {
+        let mut scanner = Scanner::new("++++++++++++++++");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::Fifteen)));
+        assert_eq!(scanner.cursor(), 15)
+    }
+
+    #[test]
+    fn plus_minus() {
+        let mut scanner = Scanner::new("+-");
+
+        assert_eq!(read_charge(&mut scanner), Ok(Some(Charge::One)));
+        assert_eq!(scanner.cursor(), 1)
+    }
+
     #[test]
     fn plus_2_x() {
         let mut scanner = Scanner::new("+2X");
@@ -0,0 +1,65 @@
+/// Options controlling how `read_with_options` interprets a string.
+/// 
+/// ```
+/// use purr::write::Writer;
+/// use purr::read::{ read_with_options, ReadOptions, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut writer = Writer::new();
+///     let options = ReadOptions {
+///         max_number_digits: 4,
+///         ..ReadOptions::default()
+///     };
+///
+///     read_with_options("[1000C]", &mut writer, None, &options)?;
+/// 
+///     assert_eq!(writer.write(), "[1000C]");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct ReadOptions {
+    /// The maximum number of digits in an isotope or atom map. OpenSMILES
+    /// allows three. Values are backed by `u16`, so widths greater than
+    /// four are treated as four.
+    pub max_number_digits: usize,
+    /// The maximum number of atoms to read before failing with
+    /// `Error::TooLarge`. `None` means no limit.
+    pub max_atoms: Option<usize>,
+    /// The maximum nesting depth of branches before failing with
+    /// `Error::TooDeep`. Reading recurses once per open branch, so this
+    /// bounds stack use. `None` means no limit. Defaults to 256, which
+    /// also applies to `read`.
+    pub max_branch_depth: Option<usize>,
+    /// Whether an atom map of zero reads as no map, as some toolkits
+    /// expect. By default, `:0` is kept as a map of zero.
+    pub treat_zero_map_as_none: bool,
+    /// Whether the bracket symbols `D` and `T` read as hydrogen with an
+    /// isotope of 2 or 3, a lenient extension found in some inputs. The
+    /// atoms write back as `[2H]` and `[3H]`. By default, both are errors.
+    pub allow_hydrogen_isotope_symbols: bool,
+    /// Whether bracket atom charges outside of `Element::charge_range`,
+    /// such as `[C-4]`, fail with `Error::Character` at the charge. Off
+    /// by default, since SMILES allows any charge from -15 to +15.
+    pub validate_charges: bool
+}
+
+impl ReadOptions {
+    pub(crate) fn number_digits(&self) -> usize {
+        self.max_number_digits.min(4)
+    }
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_number_digits: 3,
+            max_atoms: None,
+            max_branch_depth: Some(256),
+            treat_zero_map_as_none: false,
+            allow_hydrogen_isotope_symbols: false,
+            validate_charges: false
+        }
+    }
+}
\ No newline at end of file
@@ -123,4 +123,21 @@ mod tests {
         
         assert_eq!(atom, Ok(Some(AtomKind::Aliphatic(Aliphatic::Cl))))
     }
+
+    #[test]
+    fn s_e() {
+        let mut scanner = Scanner::new("se");
+        let atom = read_organic(&mut scanner);
+
+        assert_eq!(atom, Ok(Some(AtomKind::Aromatic(Aromatic::S))));
+        assert_eq!(scanner.cursor(), 1)
+    }
+
+    #[test]
+    fn a_s() {
+        let mut scanner = Scanner::new("as");
+        let atom = read_organic(&mut scanner);
+
+        assert_eq!(atom, Ok(None))
+    }
 }
\ No newline at end of file
@@ -359,7 +359,7 @@ mod follower {
 
     #[test]
     fn upper_a_eol() {
-        let mut scanner = Scanner::new(&"A");
+        let mut scanner = Scanner::new("A");
 
         assert_eq!(read_symbol(&mut scanner), Err(Error::EndOfLine));
         assert_eq!(scanner.cursor(), 1);
@@ -367,7 +367,7 @@ mod follower {
     
     #[test]
     fn a_unknown() {
-        let mut scanner = Scanner::new(&"Ax");
+        let mut scanner = Scanner::new("Ax");
 
         assert_eq!(
             read_symbol(&mut scanner), Err(Error::Character(1))
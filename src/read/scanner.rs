@@ -1,14 +1,43 @@
+/// A character-by-character cursor over a string, used by the readers.
+/// 
+/// ```
+/// use purr::read::Scanner;
+/// 
+/// fn main() {
+///     let mut scanner = Scanner::new("CO");
+///     let checkpoint = scanner.checkpoint();
+/// 
+///     assert_eq!(scanner.pop(), Some(&'C'));
+/// 
+///     scanner.restore(checkpoint);
+/// 
+///     assert_eq!(scanner.cursor(), 0)
+/// }
+/// ```
 #[derive(Debug)]
-pub struct Scanner {
+pub struct Scanner<'a> {
     cursor: usize,
-    characters: Vec<char>
+    source: Source<'a>
 }
 
-impl Scanner {
-    pub fn new(string: &str) -> Self {
+// ASCII input, which includes every valid SMILES string, is scanned in
+// place. Anything else is decoded up front so cursors stay character
+// indexes.
+#[derive(Debug)]
+enum Source<'a> {
+    Ascii(&'a [u8]),
+    Unicode(Vec<char>)
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(string: &'a str) -> Self {
         Self {
             cursor: 0,
-            characters: string.chars().collect()
+            source: if string.is_ascii() {
+                Source::Ascii(string.as_bytes())
+            } else {
+                Source::Unicode(string.chars().collect())
+            }
         }
     }
 
@@ -17,23 +46,81 @@ impl Scanner {
     }
 
     pub fn is_done(&self) -> bool {
-        self.cursor == self.characters.len()
+        self.cursor == self.len()
     }
 
     pub fn peek(&self) -> Option<&char> {
-        self.characters.get(self.cursor)
+        match &self.source {
+            Source::Ascii(bytes) =>
+                bytes.get(self.cursor).map(|&byte| &ASCII[byte as usize]),
+            Source::Unicode(characters) => characters.get(self.cursor)
+        }
     }
 
     pub fn pop(&mut self) -> Option<&char> {
-        match self.characters.get(self.cursor) {
-            Some(result) => {
-                self.cursor = self.cursor + 1;
+        if self.is_done() {
+            return None
+        }
+
+        let result = match &self.source {
+            Source::Ascii(bytes) => &ASCII[bytes[self.cursor] as usize],
+            Source::Unicode(characters) => &characters[self.cursor]
+        };
+
+        self.cursor += 1;
+
+        Some(result)
+    }
 
-                Some(result)
-            },
-            None => None
+    /// Returns the byte offset into the scanned string of the character at
+    /// `char_index`. Indexes at or past the end give the string's length.
+    pub fn byte_offset(&self, char_index: usize) -> usize {
+        match &self.source {
+            Source::Ascii(bytes) => char_index.min(bytes.len()),
+            Source::Unicode(characters) => characters.iter().take(char_index)
+                .fold(0, |sum, character| sum + character.len_utf8())
         }
     }
+
+    fn len(&self) -> usize {
+        match &self.source {
+            Source::Ascii(bytes) => bytes.len(),
+            Source::Unicode(characters) => characters.len()
+        }
+    }
+
+    /// Returns a Checkpoint recording the current position.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            cursor: self.cursor
+        }
+    }
+
+    /// Returns to the position recorded by `checkpoint`, undoing any pops
+    /// made since.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.cursor = checkpoint.cursor
+    }
+}
+
+static ASCII: [char; 128] = ascii();
+
+const fn ascii() -> [char; 128] {
+    let mut result = [ '\0'; 128 ];
+    let mut index = 0;
+
+    while index < 128 {
+        result[index] = index as u8 as char;
+        index += 1
+    }
+
+    result
+}
+
+/// A saved Scanner position for speculative parsing.
+#[derive(Debug,PartialEq)]
+pub struct Checkpoint {
+    cursor: usize
 }
 
 #[cfg(test)]
@@ -42,14 +129,14 @@ mod tests {
 
     #[test]
     fn cursor_given_empty() {
-        let scanner = Scanner::new(&"");
+        let scanner = Scanner::new("");
 
         assert_eq!(scanner.cursor(), 0);
     }
 
     #[test]
     fn cursor_given_not_done() {
-        let mut scanner = Scanner::new(&"abc");
+        let mut scanner = Scanner::new("abc");
 
         assert_eq!(scanner.pop(), Some(&'a'));
         assert_eq!(scanner.cursor(), 1);
@@ -57,7 +144,7 @@ mod tests {
 
     #[test]
     fn cursor_given_done() {
-        let mut scanner = Scanner::new(&"abc");
+        let mut scanner = Scanner::new("abc");
 
         assert_eq!(scanner.pop(), Some(&'a'));
         assert_eq!(scanner.pop(), Some(&'b'));
@@ -67,21 +154,21 @@ mod tests {
 
     #[test]
     fn is_done_given_done() {
-        let scanner = Scanner::new(&"");
+        let scanner = Scanner::new("");
 
-        assert_eq!(scanner.is_done(), true);
+        assert!(scanner.is_done());
     }
 
     #[test]
     fn is_done_given_not_done() {
-        let scanner = Scanner::new(&"a");
+        let scanner = Scanner::new("a");
 
-        assert_eq!(scanner.is_done(), false);
+        assert!(!scanner.is_done());
     }
 
     #[test]
     fn peek_given_not_done() {
-        let mut scanner = Scanner::new(&"abc");
+        let mut scanner = Scanner::new("abc");
 
         assert_eq!(scanner.pop(), Some(&'a'));
         assert_eq!(scanner.peek(), Some(&'b'));
@@ -89,7 +176,7 @@ mod tests {
 
     #[test]
     fn peek_given_done() {
-        let mut scanner = Scanner::new(&"abc");
+        let mut scanner = Scanner::new("abc");
 
         assert_eq!(scanner.pop(), Some(&'a'));
         assert_eq!(scanner.pop(), Some(&'b'));
@@ -99,16 +186,86 @@ mod tests {
 
     #[test]
     fn pop_given_not_done() {
-        let mut scanner = Scanner::new(&"abc");
+        let mut scanner = Scanner::new("abc");
 
         assert_eq!(scanner.pop(), Some(&'a'));
     }
 
     #[test]
     fn pop_given_done() {
-        let mut scanner = Scanner::new(&"a");
+        let mut scanner = Scanner::new("a");
 
         assert_eq!(scanner.pop(), Some(&'a'));
         assert_eq!(scanner.pop(), None);
     }
+
+    #[test]
+    fn restore_given_pops() {
+        let mut scanner = Scanner::new("abc");
+
+        assert_eq!(scanner.pop(), Some(&'a'));
+
+        let checkpoint = scanner.checkpoint();
+
+        assert_eq!(scanner.pop(), Some(&'b'));
+        assert_eq!(scanner.pop(), Some(&'c'));
+
+        scanner.restore(checkpoint);
+
+        assert_eq!(scanner.cursor(), 1);
+        assert_eq!(scanner.peek(), Some(&'b'));
+    }
+
+    #[test]
+    fn restore_given_failed_parse() {
+        let mut scanner = Scanner::new("%1");
+        let checkpoint = scanner.checkpoint();
+
+        assert!(crate::read::read_rnum(&mut scanner).is_err());
+
+        scanner.restore(checkpoint);
+
+        assert_eq!(scanner.cursor(), 0);
+        assert_eq!(scanner.peek(), Some(&'%'));
+    }
+
+    #[test]
+    fn byte_offset_given_ascii() {
+        let scanner = Scanner::new("CCO");
+
+        assert_eq!(scanner.byte_offset(2), 2);
+    }
+
+    #[test]
+    fn byte_offset_given_leading_multibyte() {
+        let scanner = Scanner::new("éCO");
+
+        assert_eq!(scanner.byte_offset(0), 0);
+        assert_eq!(scanner.byte_offset(1), 2);
+        assert_eq!(scanner.byte_offset(2), 3);
+    }
+
+    #[test]
+    fn byte_offset_given_past_end() {
+        let scanner = Scanner::new("éC");
+
+        assert_eq!(scanner.byte_offset(5), 3);
+    }
+
+    #[test]
+    fn pop_given_multibyte() {
+        let mut scanner = Scanner::new("Cé");
+
+        assert_eq!(scanner.pop(), Some(&'C'));
+        assert_eq!(scanner.pop(), Some(&'é'));
+        assert_eq!(scanner.pop(), None);
+        assert!(scanner.is_done());
+    }
+
+    #[test]
+    fn ascii_table() {
+        for byte in 0..128u8 {
+            assert_eq!(ASCII[byte as usize], byte as char);
+        }
+    }
 }
\ No newline at end of file
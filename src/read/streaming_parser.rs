@@ -0,0 +1,372 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Atom, Builder };
+use super::{ Error, Trace, read };
+
+/// Reads SMILES arriving in arbitrary chunks, such as from a socket.
+/// Molecules are yielded as soon as they end, either at a newline or at a
+/// top-level dot. A dot only ends a molecule outside of branches, bracket
+/// atoms, and open ring closures, so `C1.C1` is read as one molecule.
+/// Blank lines are skipped.
+///
+/// Each molecule is reported separately, so one malformed record does not
+/// stop the stream. Error cursors count characters from the start of the
+/// stream, and a molecule cut short by its terminator reports the
+/// terminator's cursor rather than `Error::EndOfLine`.
+///
+/// ```
+/// use purr::read::StreamingParser;
+/// use purr::graph::to_smiles;
+///
+/// fn main() {
+///     let mut parser = StreamingParser::new();
+///     let mut smiles = Vec::new();
+///
+///     for chunk in &[ "CC", "O\nc1cc", "ccc1\n" ] {
+///         for molecule in parser.feed(chunk) {
+///             smiles.push(to_smiles(&molecule.unwrap()).unwrap());
+///         }
+///     }
+///
+///     assert_eq!(parser.finish(), None);
+///     assert_eq!(smiles, vec![ "CCO", "c(ccccc1)1" ])
+/// }
+/// ```
+#[derive(Debug,PartialEq)]
+pub struct StreamingParser {
+    buffer: String,
+    start: usize,
+    cursor: usize,
+    bracket: bool,
+    depth: usize,
+    percent: Option<String>,
+    opens: HashSet<u8>,
+    split: bool
+}
+
+impl StreamingParser {
+    pub fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            start: 0,
+            cursor: 0,
+            bracket: false,
+            depth: 0,
+            percent: None,
+            opens: HashSet::new(),
+            split: false
+        }
+    }
+
+    /// Appends `chunk` to the stream, returning the molecules it completes
+    /// in the order they end. Characters of an unfinished molecule are
+    /// buffered until a later chunk ends it, or until `finish`.
+    pub fn feed(&mut self, chunk: &str) -> Vec<Result<Vec<Atom>, Error>> {
+        let mut result = Vec::new();
+
+        for character in chunk.chars() {
+            if self.bracket {
+                if character == ']' {
+                    self.bracket = false
+                }
+
+                self.buffer.push(character)
+            } else if character == '\n' {
+                if !self.buffer.is_empty() {
+                    result.push(self.flush(Some(self.cursor)))
+                } else if self.split {
+                    result.push(Err(Error::Character(self.cursor)))
+                }
+
+                self.split = false;
+                self.reset()
+            } else if character == '.' && self.is_closed() {
+                if self.buffer.is_empty() {
+                    result.push(Err(Error::Character(self.cursor)))
+                } else {
+                    result.push(self.flush(Some(self.cursor)))
+                }
+
+                self.split = true
+            } else {
+                self.scan(character);
+                self.buffer.push(character)
+            }
+
+            self.cursor += 1;
+
+            if self.buffer.is_empty() {
+                self.start = self.cursor
+            }
+        }
+
+        result
+    }
+
+    /// Ends the stream, returning the buffered molecule, if any. A
+    /// trailing dot without a molecule after it is reported as
+    /// `Error::EndOfLine`.
+    pub fn finish(mut self) -> Option<Result<Vec<Atom>, Error>> {
+        if !self.buffer.is_empty() {
+            Some(self.flush(None))
+        } else if self.split {
+            Some(Err(Error::EndOfLine))
+        } else {
+            None
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.depth == 0 && self.opens.is_empty() && self.percent.is_none()
+    }
+
+    fn scan(&mut self, character: char) {
+        match character {
+            '[' => self.bracket = true,
+            '(' => self.depth += 1,
+            ')' => self.depth = self.depth.saturating_sub(1),
+            '%' => {
+                self.percent = Some(String::new());
+
+                return
+            },
+            '0'..='9' => {
+                let rnum = match &mut self.percent {
+                    Some(digits) => {
+                        digits.push(character);
+
+                        if digits.len() < 2 {
+                            return
+                        }
+
+                        digits.parse::<u8>().expect("two digits")
+                    },
+                    None => character as u8 - b'0'
+                };
+
+                if !self.opens.remove(&rnum) {
+                    self.opens.insert(rnum);
+                }
+            },
+            _ => ()
+        }
+
+        self.percent = None
+    }
+
+    fn reset(&mut self) {
+        self.bracket = false;
+        self.depth = 0;
+        self.percent = None;
+        self.opens.clear()
+    }
+
+    fn flush(&mut self, end: Option<usize>) -> Result<Vec<Atom>, Error> {
+        let smiles = std::mem::take(&mut self.buffer);
+        let start = self.start;
+        let mut builder = Builder::with_capacity(smiles.len() / 2);
+        let mut trace = Trace::new();
+
+        self.reset();
+
+        let error = match read(&smiles, &mut builder, Some(&mut trace)) {
+            Ok(()) => match builder.build() {
+                Ok(atoms) => return Ok(atoms),
+                Err(error) => Error::Character(
+                    trace.locate(&error).expect("traced graph error")
+                )
+            },
+            Err(error) => error
+        };
+
+        Err(match error {
+            Error::EndOfLine => match end {
+                Some(end) => Error::Character(end),
+                None => Error::EndOfLine
+            },
+            Error::Character(cursor) => Error::Character(start + cursor),
+            Error::TooLarge(cursor) => Error::TooLarge(start + cursor),
+            Error::TooDeep(cursor) => Error::TooDeep(start + cursor)
+        })
+    }
+}
+
+impl Default for StreamingParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::graph::to_smiles;
+    use super::*;
+
+    fn smiles(
+        molecules: Vec<Result<Vec<Atom>, Error>>
+    ) -> Vec<Result<String, Error>> {
+        molecules.into_iter().map(|molecule| {
+            molecule.map(|atoms| to_smiles(&atoms).unwrap())
+        }).collect()
+    }
+
+    fn split(input: &str, at: &[usize]) -> Vec<Result<String, Error>> {
+        let mut parser = StreamingParser::new();
+        let mut result = Vec::new();
+        let mut last = 0;
+
+        for &index in at {
+            result.extend(smiles(parser.feed(&input[last..index])));
+            last = index;
+        }
+
+        result.extend(smiles(parser.feed(&input[last..])));
+        result.extend(smiles(parser.finish().into_iter().collect()));
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(split("", &[ ]), vec![ ])
+    }
+
+    #[test]
+    fn blank_lines() {
+        assert_eq!(split("\n\nC\n\n", &[ ]), vec![ Ok("C".to_string()) ])
+    }
+
+    #[test]
+    fn every_split() {
+        let input = "CCO\nc1ccccc1\n";
+        let expected = vec![
+            Ok("CCO".to_string()),
+            Ok("c(ccccc1)1".to_string())
+        ];
+
+        for i in 0..=input.len() {
+            for j in i..=input.len() {
+                assert_eq!(split(input, &[ i, j ]), expected)
+            }
+        }
+    }
+
+    #[test]
+    fn one_character_chunks() {
+        let input = "CCO\nc1ccccc1\n";
+
+        assert_eq!(split(input, &(1..input.len()).collect::<Vec<_>>()), vec![
+            Ok("CCO".to_string()),
+            Ok("c(ccccc1)1".to_string())
+        ])
+    }
+
+    #[test]
+    fn unterminated() {
+        assert_eq!(split("CCO\nC", &[ 4 ]), vec![
+            Ok("CCO".to_string()),
+            Ok("C".to_string())
+        ])
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(split("CC.O\n", &[ 3 ]), vec![
+            Ok("CC".to_string()),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn dot_in_branch() {
+        assert_eq!(split("C(.O)C\n", &[ 2 ]), vec![
+            Ok("CC.O".to_string())
+        ])
+    }
+
+    #[test]
+    fn dot_in_open_ring() {
+        assert_eq!(split("C1.C1", &[ 2 ]), vec![ Ok("CC".to_string()) ])
+    }
+
+    #[test]
+    fn dot_in_open_percent_ring() {
+        assert_eq!(split("C%12.C%12", &[ 3 ]), vec![ Ok("CC".to_string()) ])
+    }
+
+    #[test]
+    fn dot_after_closed_ring() {
+        assert_eq!(split("C1CC1.O", &[ 5 ]), vec![
+            Ok("C(CC1)1".to_string()),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn bracket_digits() {
+        assert_eq!(split("[13CH4].O", &[ 2 ]), vec![
+            Ok("[13CH4]".to_string()),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn leading_dot() {
+        assert_eq!(split("C\n.O", &[ ]), vec![
+            Ok("C".to_string()),
+            Err(Error::Character(2)),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn trailing_dot_at_newline() {
+        assert_eq!(split("C.\nO", &[ ]), vec![
+            Ok("C".to_string()),
+            Err(Error::Character(2)),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn trailing_dot_at_finish() {
+        assert_eq!(split("C.", &[ 1 ]), vec![
+            Ok("C".to_string()),
+            Err(Error::EndOfLine)
+        ])
+    }
+
+    #[test]
+    fn error_recovers() {
+        assert_eq!(split("CCO\nC?C\nO\n", &[ 5 ]), vec![
+            Ok("CCO".to_string()),
+            Err(Error::Character(5)),
+            Ok("O".to_string())
+        ])
+    }
+
+    #[test]
+    fn open_branch_at_newline() {
+        assert_eq!(split("CC\nC(C\n", &[ 5 ]), vec![
+            Ok("CC".to_string()),
+            Err(Error::Character(6))
+        ])
+    }
+
+    #[test]
+    fn open_ring_at_newline() {
+        assert_eq!(split("CC\nC1CC\n", &[ 5 ]), vec![
+            Ok("CC".to_string()),
+            Err(Error::Character(4))
+        ])
+    }
+
+    #[test]
+    fn conflicting_join() {
+        assert_eq!(split("O\nC=1CC#1\n", &[ ]), vec![
+            Ok("O".to_string()),
+            Err(Error::Character(7))
+        ])
+    }
+}
\ No newline at end of file
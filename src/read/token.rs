@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+use super::TokenKind;
+
+/// A lexical unit of a SMILES string and the cursor range it spans.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Range<usize>
+}
+
+impl Token {
+    pub fn new(kind: TokenKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use crate::feature::{ AtomKind, BondKind, Rnum };
+
+/// The lexical categories of a SMILES string.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub enum TokenKind {
+    Atom(AtomKind),
+    Bond(BondKind),
+    Open,
+    Close,
+    Rnum(Rnum),
+    Dot
+}
\ No newline at end of file
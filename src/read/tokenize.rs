@@ -0,0 +1,160 @@
+use crate::feature::{ AtomKind, BondKind };
+use super::{
+    Error, ReadOptions, Scanner, Token, TokenKind, read_bond, read_bracket,
+    read_organic, read_rnum
+};
+
+/// Splits `smiles` into tokens without building a representation. Each
+/// token is validated on its own, but their arrangement is not, so
+/// strings such as `C)(` tokenize successfully. Spans are character
+/// cursors, as elsewhere in `read`.
+/// 
+/// Returns `Error::Character` at the first character that starts no token,
+/// and `Error::EndOfLine` given a truncated bracket atom or ring number.
+/// 
+/// ```
+/// use purr::read::{ tokenize, Token, TokenKind, Error };
+/// use purr::feature::{ AtomKind, Aliphatic, BondKind };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let carbon = AtomKind::Aliphatic(Aliphatic::C);
+///     let oxygen = AtomKind::Aliphatic(Aliphatic::O);
+/// 
+///     assert_eq!(tokenize("C=O")?, vec![
+///         Token::new(TokenKind::Atom(carbon), 0..1),
+///         Token::new(TokenKind::Bond(BondKind::Double), 1..2),
+///         Token::new(TokenKind::Atom(oxygen), 2..3)
+///     ]);
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn tokenize(smiles: &str) -> Result<Vec<Token>, Error> {
+    let mut scanner = Scanner::new(smiles);
+    let options = ReadOptions::default();
+    let mut result = Vec::new();
+
+    while !scanner.is_done() {
+        let cursor = scanner.cursor();
+        let kind = match scanner.peek() {
+            Some('(') => {
+                scanner.pop();
+
+                TokenKind::Open
+            },
+            Some(')') => {
+                scanner.pop();
+
+                TokenKind::Close
+            },
+            Some('.') => {
+                scanner.pop();
+
+                TokenKind::Dot
+            },
+            Some('*') => {
+                scanner.pop();
+
+                TokenKind::Atom(AtomKind::Star)
+            },
+            Some('0'..='9') | Some('%') =>
+                TokenKind::Rnum(read_rnum(&mut scanner)?.expect("rnum")),
+            _ => match read_bond(&mut scanner) {
+                BondKind::Elided => match read_atom(&mut scanner, &options)? {
+                    Some(atom_kind) => TokenKind::Atom(atom_kind),
+                    None => return Err(Error::Character(cursor))
+                },
+                bond_kind => TokenKind::Bond(bond_kind)
+            }
+        };
+
+        result.push(Token::new(kind, cursor..scanner.cursor()))
+    }
+
+    Ok(result)
+}
+
+fn read_atom(
+    scanner: &mut Scanner, options: &ReadOptions
+) -> Result<Option<AtomKind>, Error> {
+    match read_organic(scanner)? {
+        Some(organic) => Ok(Some(organic)),
+        None => read_bracket(scanner, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ Aliphatic, Rnum };
+    use super::*;
+
+    fn atom(aliphatic: Aliphatic) -> TokenKind {
+        TokenKind::Atom(AtomKind::Aliphatic(aliphatic))
+    }
+
+    #[test]
+    fn blank() {
+        assert_eq!(tokenize(""), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn acetic_acid() {
+        assert_eq!(tokenize("CC(=O)O"), Ok(vec![
+            Token::new(atom(Aliphatic::C), 0..1),
+            Token::new(atom(Aliphatic::C), 1..2),
+            Token::new(TokenKind::Open, 2..3),
+            Token::new(TokenKind::Bond(BondKind::Double), 3..4),
+            Token::new(atom(Aliphatic::O), 4..5),
+            Token::new(TokenKind::Close, 5..6),
+            Token::new(atom(Aliphatic::O), 6..7)
+        ]))
+    }
+
+    #[test]
+    fn bracket_rnum_dot() {
+        let tokens = tokenize("[NH4+].C%12*").unwrap();
+
+        assert_eq!(
+            tokens.iter().map(|token| token.span.clone()).collect::<Vec<_>>(),
+            vec![ 0..6, 6..7, 7..8, 8..11, 11..12 ]
+        );
+        assert_eq!(tokens[1].kind, TokenKind::Dot);
+        assert_eq!(tokens[3].kind, TokenKind::Rnum(Rnum::R12));
+        assert_eq!(tokens[4].kind, TokenKind::Atom(AtomKind::Star))
+    }
+
+    #[test]
+    fn unbalanced_parens() {
+        assert_eq!(tokenize("C)(").unwrap().len(), 3)
+    }
+
+    #[test]
+    fn invalid_character() {
+        assert_eq!(tokenize("C?"), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn truncated_bracket() {
+        assert_eq!(tokenize("C[N"), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn truncated_rnum() {
+        assert_eq!(tokenize("C%1"), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn non_ascii_position() {
+        assert_eq!(tokenize("CCé"), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn large_input() {
+        let smiles = "CC(=O)O.".repeat(12_500);
+        let tokens = tokenize(&smiles).unwrap();
+
+        assert_eq!(tokens.len(), 100_000);
+        assert_eq!(tokens[99_999].span, 99_999..100_000)
+    }
+}
\ No newline at end of file
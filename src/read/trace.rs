@@ -2,6 +2,7 @@ use std::ops::Range;
 use std::collections::{ HashMap, hash_map::Entry };
 
 use crate::feature::Rnum;
+use crate::graph;
 
 /// Maps the features of an ajacency representation to cursors within a
 /// string representation.
@@ -27,7 +28,7 @@ impl Trace {
 
     /// Returns the cursor range associated with atom identifier `id`.
     pub fn atom(&self, id: usize) -> Option<Range<usize>> {
-        self.atoms.get(id).map(|cursor| cursor.clone())
+        self.atoms.get(id).cloned()
     }
 
     /// Returns the cursor associated with the bond between
@@ -36,13 +37,31 @@ impl Trace {
     /// two different cursors will be reported for (`sid`, `tid`)
     /// and (`tid`, `sid`).
     pub fn bond(&self, sid: usize, tid: usize) -> Option<usize> {
-        self.bonds.get(&(sid, tid)).map(|cursor| *cursor)
+        self.bonds.get(&(sid, tid)).copied()
     }
 
     /// Returns the `Rnum` associated with ring closure digit
     /// identifier `rid`.
     pub fn rnum(&self, rid: usize) -> Option<Range<usize>> {
-        self.rnums.get(rid).map(|cursor| cursor.clone())
+        self.rnums.get(rid).cloned()
+    }
+
+    /// Returns the cursor responsible for a `graph::Error` raised while
+    /// building from the traced string. An unmatched ring closure reports
+    /// the cursor of the digit that opened it, and an incompatible join
+    /// reports the cursor of the closing bond. A ring closure digit opened
+    /// and closed on the same atom reports the cursor of the opening bond.
+    /// An atom with too many bonds reports the start of the atom. Errors
+    /// that reading cannot produce return `None`.
+    pub fn locate(&self, error: &graph::Error) -> Option<usize> {
+        match error {
+            graph::Error::Rnum(rid) => self.rnum(*rid).map(|range| range.start),
+            graph::Error::Join(sid, tid) => self.bond(*sid, *tid),
+            graph::Error::Loop(sid) => self.bond(*sid, *sid),
+            graph::Error::TooManyBonds(id) =>
+                self.atom(*id).map(|range| range.start),
+            _ => None
+        }
     }
 
     /// Adds a root atom.
@@ -104,6 +123,12 @@ impl Trace {
     }
 }
 
+impl Default for Trace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug,PartialEq)]
 struct Open {
     sid: usize,
@@ -187,4 +212,77 @@ mod tests {
         assert_eq!(trace.bond(2, 0), Some(4));
         assert_eq!(trace.rnum(0), Some(1..2))
     }
+
+    #[test]
+    fn locate_unbalanced_rnum() {
+        let mut builder = graph::Builder::new();
+        let mut trace = Trace::new();
+
+        crate::read::read("C1CC", &mut builder, Some(&mut trace)).unwrap();
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(error, graph::Error::Rnum(0));
+        assert_eq!(trace.locate(&error), Some(1))
+    }
+
+    #[test]
+    fn locate_unbalanced_second_rnum() {
+        let mut builder = graph::Builder::new();
+        let mut trace = Trace::new();
+
+        crate::read::read("C1CC1C%12C", &mut builder, Some(&mut trace))
+            .unwrap();
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(trace.locate(&error), Some(6))
+    }
+
+    #[test]
+    fn locate_join() {
+        let mut builder = graph::Builder::new();
+        let mut trace = Trace::new();
+
+        crate::read::read("C=1CC#1", &mut builder, Some(&mut trace)).unwrap();
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(error, graph::Error::Join(2, 0));
+        assert_eq!(trace.locate(&error), Some(5))
+    }
+
+    #[test]
+    fn locate_loop_without_trace() {
+        let trace = Trace::new();
+
+        assert_eq!(trace.locate(&graph::Error::Loop(0)), None)
+    }
+
+    #[test]
+    fn locate_loop() {
+        let mut builder = graph::Builder::new();
+        let mut trace = Trace::new();
+
+        crate::read::read("CC11C", &mut builder, Some(&mut trace)).unwrap();
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(error, graph::Error::Loop(1));
+        assert_eq!(trace.locate(&error), Some(2))
+    }
+
+    #[test]
+    fn locate_too_many_bonds() {
+        let mut builder = graph::Builder::with_max_degree(2);
+        let mut trace = Trace::new();
+
+        crate::read::read("CC[Si](C)C", &mut builder, Some(&mut trace))
+            .unwrap();
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(error, graph::Error::TooManyBonds(2));
+        assert_eq!(trace.locate(&error), Some(2))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::feature::BracketSymbol;
+use super::Bond;
+
+/// Atom used in a SMARTS-lite query graph.
+#[derive(Debug,PartialEq,Eq)]
+pub struct Atom {
+    /// Alternative symbols, any of which matches. `BracketSymbol::Star`
+    /// matches any atom.
+    pub symbols: Vec<BracketSymbol>,
+    /// Ring membership: `Some(true)` for `R`, `Some(false)` for `R0`, and
+    /// `None` when unconstrained.
+    pub ring: Option<bool>,
+    pub bonds: Vec<Bond>
+}
+
+impl Atom {
+    /// Constructs an Atom without bonds or ring constraint.
+    pub fn new(symbols: Vec<BracketSymbol>) -> Self {
+        Self {
+            symbols,
+            ring: None,
+            bonds: vec![ ]
+        }
+    }
+}
\ No newline at end of file
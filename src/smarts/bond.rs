@@ -0,0 +1,19 @@
+use crate::feature::BondKind;
+
+/// A bond from a query Atom to an Atom ID. A `kind` of `None` matches any
+/// bond (`~`). `BondKind::Elided` matches single or aromatic bonds.
+#[derive(Debug,PartialEq,Eq)]
+pub struct Bond {
+    pub kind: Option<BondKind>,
+    pub tid: usize
+}
+
+impl Bond {
+    /// Constructs a Bond.
+    pub fn new(kind: Option<BondKind>, tid: usize) -> Self {
+        Self {
+            kind,
+            tid
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use crate::graph::{ self, search };
+use super::{ Atom, Matcher };
+
+/// Returns true if the SMARTS `query` occurs as a subgraph of `haystack`.
+/// A query atom matches when any of its symbols does: element symbols
+/// match aliphatic atoms, aromatic symbols match aromatic atoms, and `*`
+/// matches any atom. `R` and `R0` require the atom to be in a ring or not.
+/// Query bonds match as described by `Bond`; single and double bonds do not
+/// match aromatic bonds. Charges, isotopes, and hydrogen counts are
+/// ignored.
+/// 
+/// ```
+/// use purr::graph::Builder;
+/// use purr::read::read;
+/// use purr::smarts;
+///
+/// fn main() {
+///     let mut builder = Builder::new();
+///
+///     read("c1ccncc1", &mut builder, None).expect("read");
+///
+///     let pyridine = builder.build().expect("atoms");
+///
+///     assert!(smarts::has_substructure(
+///         &pyridine, &smarts::read("[c,n]1ccccc1").expect("query")
+///     ));
+///     assert!(!smarts::has_substructure(
+///         &pyridine, &smarts::read("[C,N]~*").expect("query")
+///     ))
+/// }
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `query`.
+pub fn has_substructure(haystack: &[graph::Atom], query: &[Atom]) -> bool {
+    let mut result = false;
+
+    search(haystack, &Matcher::new(query, haystack), &mut |_| {
+        result = true;
+
+        false
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::build;
+    use crate::smarts::read;
+    use super::*;
+
+    fn matches(haystack: &str, query: &str) -> bool {
+        has_substructure(&build(haystack), &read(query).expect("query"))
+    }
+
+    #[test]
+    fn empty_query() {
+        assert!(has_substructure(&build("C"), &[ ]))
+    }
+
+    #[test]
+    fn symbol_list() {
+        assert!(matches("CCO", "C[N,O]"));
+        assert!(!matches("CCF", "C[N,O]"))
+    }
+
+    #[test]
+    fn aliphatic_symbol() {
+        assert!(matches("CCO", "CC"));
+        assert!(!matches("c1ccccc1", "C"))
+    }
+
+    #[test]
+    fn aromatic_symbol() {
+        assert!(matches("c1ccccc1", "cc"));
+        assert!(!matches("C1CCCCC1", "c"))
+    }
+
+    #[test]
+    fn star() {
+        assert!(matches("c1ccccc1O", "*O"));
+        assert!(!matches("O", "*O"))
+    }
+
+    #[test]
+    fn any_bond() {
+        assert!(matches("CC=O", "C~O"));
+        assert!(matches("CC#N", "C~N"))
+    }
+
+    #[test]
+    fn elided_bond() {
+        assert!(matches("CCO", "CO"));
+        assert!(matches("c1ccccc1", "[c][c]"));
+        assert!(!matches("CC=O", "CO"))
+    }
+
+    #[test]
+    fn single_bond() {
+        assert!(matches("CCO", "C-O"));
+        assert!(matches("F/C=C/F", "F-C"));
+        assert!(!matches("c1ccccc1", "c-c"))
+    }
+
+    #[test]
+    fn aromatic_bond() {
+        assert!(matches("c1ccccc1", "c:c"));
+        assert!(!matches("C1=CC=CC=C1", "C:C"))
+    }
+
+    #[test]
+    fn double_bond() {
+        assert!(matches("CC=O", "C=O"));
+        assert!(!matches("c1ccccc1", "c=c"))
+    }
+
+    #[test]
+    fn ring() {
+        assert!(matches("C1CC1C", "[C;R][C;R]"));
+        assert!(!matches("C1CC1C", "[C;R0][C;R0]"));
+        assert!(matches("C1CC1C", "[C;R][C;R0]"))
+    }
+
+    #[test]
+    fn ring_closure() {
+        assert!(matches("C1CCCCC1", "C1CCCCC1"));
+        assert!(!matches("CCCCCC", "C1CCCCC1"))
+    }
+}
\ No newline at end of file
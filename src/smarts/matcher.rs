@@ -0,0 +1,91 @@
+use crate::feature::{ BondKind, BracketSymbol, Element };
+use crate::graph::{ self, Query, is_aromatic_bond, sssr };
+use super::Atom;
+
+/// Adapts a SMARTS query graph for substructure search over one haystack.
+pub(crate) struct Matcher<'a> {
+    query: &'a [Atom],
+    in_ring: Vec<bool>
+}
+
+impl<'a> Matcher<'a> {
+    /// Constructs a Matcher, finding the ring atoms of `haystack` once for
+    /// any `R` or `R0` constraints in `query`.
+    pub(crate) fn new(query: &'a [Atom], haystack: &[graph::Atom]) -> Self {
+        let mut in_ring = vec![ false; haystack.len() ];
+
+        if query.iter().any(|atom| atom.ring.is_some()) {
+            for id in sssr(haystack).into_iter().flatten() {
+                in_ring[id] = true
+            }
+        }
+
+        Self { query, in_ring }
+    }
+}
+
+impl<'a> Query for Matcher<'a> {
+    fn size(&self) -> usize {
+        self.query.len()
+    }
+
+    fn degree(&self, qid: usize) -> usize {
+        self.query[qid].bonds.len()
+    }
+
+    fn target(&self, qid: usize, index: usize) -> usize {
+        self.query[qid].bonds[index].tid
+    }
+
+    fn atom_matches(
+        &self, qid: usize, haystack: &[graph::Atom], hid: usize
+    ) -> bool {
+        let atom = &self.query[qid];
+
+        if let Some(ring) = atom.ring {
+            if ring != self.in_ring[hid] {
+                return false
+            }
+        }
+
+        atom.symbols.iter()
+            .any(|symbol| symbol_matches(symbol, &haystack[hid]))
+    }
+
+    fn bond_matches(
+        &self,
+        qid: usize,
+        index: usize,
+        haystack: &[graph::Atom],
+        hid: usize,
+        bond: &graph::Bond
+    ) -> bool {
+        let aromatic = is_aromatic_bond(haystack, hid, bond);
+
+        match &self.query[qid].bonds[index].kind {
+            None => true,
+            Some(BondKind::Elided) => aromatic || bond.order() == 1,
+            Some(BondKind::Aromatic) => aromatic,
+            Some(BondKind::Single) |
+            Some(BondKind::Up) |
+            Some(BondKind::Down) => !aromatic && bond.order() == 1,
+            Some(BondKind::Double) => bond.order() == 2,
+            Some(BondKind::Triple) => bond.order() == 3,
+            Some(BondKind::Quadruple) => bond.order() == 4
+        }
+    }
+}
+
+// Element symbols match aliphatic atoms and aromatic symbols match
+// aromatic atoms. A haystack star only matches a query star.
+fn symbol_matches(symbol: &BracketSymbol, atom: &graph::Atom) -> bool {
+    match symbol {
+        BracketSymbol::Star => true,
+        BracketSymbol::Element(element) =>
+            !atom.is_aromatic()
+                && atom.kind.element().as_ref() == Some(element),
+        BracketSymbol::Aromatic(aromatic) =>
+            atom.is_aromatic()
+                && atom.kind.element() == Some(Element::from(aromatic))
+    }
+}
\ No newline at end of file
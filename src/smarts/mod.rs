@@ -0,0 +1,14 @@
+mod atom;
+mod bond;
+#[allow(clippy::module_inception)]
+mod read;
+mod matcher;
+mod has_substructure;
+mod substructure_matches;
+
+pub use atom::Atom;
+pub use bond::Bond;
+pub use read::read;
+pub use has_substructure::has_substructure;
+pub use substructure_matches::substructure_matches;
+pub(crate) use matcher::Matcher;
\ No newline at end of file
@@ -0,0 +1,416 @@
+use std::collections::HashMap;
+
+use crate::feature::{
+    AtomKind, BondKind, BracketAromatic, BracketSymbol, Element, Rnum
+};
+use crate::read::{
+    Error, Scanner, missing_character, read_bond, read_organic, read_rnum,
+    read_symbol
+};
+use super::{ Atom, Bond };
+
+/// Reads a restricted SMARTS string into a query graph. The supported
+/// subset is:
+///
+/// - organic subset atoms, aliphatic or aromatic, and `*` for any atom
+/// - bracket atoms holding a comma-separated symbol list such as `[C,N]`,
+///   optionally followed by `R` (in a ring) or `R0` (not in a ring), with
+///   or without a separating `;`
+/// - the SMILES bonds plus `~` for any bond; an elided bond matches single
+///   or aromatic bonds
+/// - branches, ring closures, and `.`
+///
+/// Other SMARTS primitives, logical operators, and recursive SMARTS are not
+/// supported.
+///
+/// ```
+/// use purr::smarts::read;
+/// use purr::feature::{ BracketSymbol, Element };
+///
+/// fn main() {
+///     let atoms = read("[C,N;R]~*").unwrap();
+///
+///     assert_eq!(atoms[0].symbols, vec![
+///         BracketSymbol::Element(Element::C),
+///         BracketSymbol::Element(Element::N)
+///     ]);
+///     assert_eq!(atoms[0].ring, Some(true));
+///     assert_eq!(atoms[0].bonds[0].kind, None)
+/// }
+/// ```
+pub fn read(smarts: &str) -> Result<Vec<Atom>, Error> {
+    let mut scanner = Scanner::new(smarts);
+    let mut atoms: Vec<Atom> = Vec::new();
+    let mut branches = Vec::new();
+    let mut previous = None;
+    let mut bond = None;
+    let mut rings = HashMap::new();
+
+    while !scanner.is_done() {
+        let cursor = scanner.cursor();
+
+        match scanner.peek() {
+            Some('(') => {
+                if previous.is_none() || bond.is_some() {
+                    return Err(Error::Character(cursor))
+                }
+
+                scanner.pop();
+                branches.push(previous);
+            },
+            Some(')') => {
+                if bond.is_some() || branches.is_empty() {
+                    return Err(Error::Character(cursor))
+                }
+
+                scanner.pop();
+                previous = branches.pop().expect("branch");
+            },
+            Some('.') => {
+                if previous.is_none() || bond.is_some() {
+                    return Err(Error::Character(cursor))
+                }
+
+                scanner.pop();
+                previous = None;
+            },
+            Some('~') => {
+                if previous.is_none() || bond.is_some() {
+                    return Err(Error::Character(cursor))
+                }
+
+                scanner.pop();
+                bond = Some(None);
+            },
+            Some('0'..='9') | Some('%') => {
+                let rnum = read_rnum(&mut scanner)?.expect("rnum");
+                let sid = previous.ok_or(Error::Character(cursor))?;
+                let kind = bond.take().unwrap_or(Some(BondKind::Elided));
+
+                close(&mut atoms, &mut rings, rnum, sid, kind, cursor)?
+            },
+            _ => {
+                let bond_kind = read_bond(&mut scanner);
+
+                if bond_kind != BondKind::Elided {
+                    if previous.is_none() || bond.is_some() {
+                        return Err(Error::Character(cursor))
+                    }
+
+                    bond = Some(Some(bond_kind));
+
+                    continue
+                }
+
+                let atom = read_atom(&mut scanner)?
+                    .ok_or(Error::Character(cursor))?;
+                let tid = atoms.len();
+
+                atoms.push(atom);
+
+                if let Some(sid) = previous {
+                    let kind = bond.take().unwrap_or(Some(BondKind::Elided));
+
+                    connect(&mut atoms, sid, tid, kind)
+                }
+
+                previous = Some(tid)
+            }
+        }
+    }
+
+    if atoms.is_empty() || bond.is_some() || !branches.is_empty() {
+        return Err(Error::EndOfLine)
+    }
+
+    match rings.values().map(|(_, _, cursor)| *cursor).min() {
+        Some(cursor) => Err(Error::Character(cursor)),
+        None => Ok(atoms)
+    }
+}
+
+fn close(
+    atoms: &mut [Atom],
+    rings: &mut HashMap<Rnum, (usize, Option<BondKind>, usize)>,
+    rnum: Rnum,
+    sid: usize,
+    kind: Option<BondKind>,
+    cursor: usize
+) -> Result<(), Error> {
+    let (tid, open, _) = match rings.remove(&rnum) {
+        Some(ring) => ring,
+        None => {
+            rings.insert(rnum, (sid, kind, cursor));
+
+            return Ok(())
+        }
+    };
+    let kind = if open == Some(BondKind::Elided) {
+        kind
+    } else if kind == Some(BondKind::Elided) || kind == open {
+        open
+    } else {
+        return Err(Error::Character(cursor))
+    };
+
+    if tid == sid {
+        return Err(Error::Character(cursor))
+    }
+
+    connect(atoms, tid, sid, kind);
+
+    Ok(())
+}
+
+fn connect(
+    atoms: &mut [Atom], sid: usize, tid: usize, kind: Option<BondKind>
+) {
+    let reverse = kind.as_ref().map(|kind| kind.reverse());
+
+    atoms[sid].bonds.push(Bond::new(kind, tid));
+    atoms[tid].bonds.push(Bond::new(reverse, sid));
+}
+
+fn read_atom(scanner: &mut Scanner) -> Result<Option<Atom>, Error> {
+    if let Some(kind) = read_organic(scanner)? {
+        return Ok(Some(Atom::new(vec![ match kind {
+            AtomKind::Aliphatic(aliphatic) =>
+                BracketSymbol::Element(Element::from(&aliphatic)),
+            AtomKind::Aromatic(aromatic) =>
+                BracketSymbol::Aromatic(BracketAromatic::from(&aromatic)),
+            _ => unreachable!("organic")
+        } ])))
+    }
+
+    match scanner.peek() {
+        Some('*') => {
+            scanner.pop();
+
+            Ok(Some(Atom::new(vec![ BracketSymbol::Star ])))
+        },
+        Some('[') => {
+            scanner.pop();
+
+            read_bracket(scanner).map(Some)
+        },
+        _ => Ok(None)
+    }
+}
+
+fn read_bracket(scanner: &mut Scanner) -> Result<Atom, Error> {
+    let mut symbols = Vec::new();
+
+    match scanner.peek() {
+        Some('R') | Some(';') => (),
+        _ => loop {
+            symbols.push(read_symbol(scanner)?);
+
+            match scanner.peek() {
+                Some(',') => {
+                    scanner.pop();
+                },
+                _ => break
+            }
+        }
+    }
+
+    let ring = read_ring(scanner)?;
+
+    if symbols.is_empty() {
+        symbols.push(BracketSymbol::Star)
+    }
+
+    match scanner.peek() {
+        Some(']') => {
+            scanner.pop();
+
+            let mut atom = Atom::new(symbols);
+
+            atom.ring = ring;
+
+            Ok(atom)
+        },
+        _ => Err(missing_character(scanner))
+    }
+}
+
+fn read_ring(scanner: &mut Scanner) -> Result<Option<bool>, Error> {
+    match scanner.peek() {
+        Some(';') => {
+            scanner.pop();
+
+            match scanner.peek() {
+                Some('R') => (),
+                _ => return Err(missing_character(scanner))
+            }
+        },
+        Some('R') => (),
+        _ => return Ok(None)
+    }
+
+    scanner.pop();
+
+    match scanner.peek() {
+        Some('0') => {
+            scanner.pop();
+
+            Ok(Some(false))
+        },
+        Some('1'..='9') => Err(missing_character(scanner)),
+        _ => Ok(Some(true))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod read {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use crate::smarts::has_substructure;
+    use super::*;
+
+    fn aromatic(aromatic: BracketAromatic) -> BracketSymbol {
+        BracketSymbol::Aromatic(aromatic)
+    }
+
+    fn elided(tid: usize) -> Bond {
+        Bond::new(Some(BondKind::Elided), tid)
+    }
+
+    #[test]
+    fn blank() {
+        assert_eq!(read(""), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn aromatic_list_ring() {
+        let atoms = read("[c,n]1cccc1").unwrap();
+
+        assert_eq!(atoms, vec![
+            Atom {
+                symbols: vec![
+                    aromatic(BracketAromatic::C),
+                    aromatic(BracketAromatic::N)
+                ],
+                ring: None,
+                bonds: vec![ elided(1), elided(4) ]
+            },
+            Atom {
+                symbols: vec![ aromatic(BracketAromatic::C) ],
+                ring: None,
+                bonds: vec![ elided(0), elided(2) ]
+            },
+            Atom {
+                symbols: vec![ aromatic(BracketAromatic::C) ],
+                ring: None,
+                bonds: vec![ elided(1), elided(3) ]
+            },
+            Atom {
+                symbols: vec![ aromatic(BracketAromatic::C) ],
+                ring: None,
+                bonds: vec![ elided(2), elided(4) ]
+            },
+            Atom {
+                symbols: vec![ aromatic(BracketAromatic::C) ],
+                ring: None,
+                bonds: vec![ elided(3), elided(0) ]
+            }
+        ])
+    }
+
+    #[test]
+    fn any_atom_any_bond() {
+        assert_eq!(read("*~C").unwrap(), vec![
+            Atom {
+                symbols: vec![ BracketSymbol::Star ],
+                ring: None,
+                bonds: vec![ Bond::new(None, 1) ]
+            },
+            Atom {
+                symbols: vec![ BracketSymbol::Element(Element::C) ],
+                ring: None,
+                bonds: vec![ Bond::new(None, 0) ]
+            }
+        ])
+    }
+
+    #[test]
+    fn ring_membership() {
+        let atoms = read("[CR][N;R0][R]").unwrap();
+
+        assert_eq!(
+            atoms.iter().map(|atom| atom.ring).collect::<Vec<_>>(),
+            vec![ Some(true), Some(false), Some(true) ]
+        );
+        assert_eq!(atoms[2].symbols, vec![ BracketSymbol::Star ])
+    }
+
+    #[test]
+    fn branch_and_double() {
+        let atoms = read("C(=O)O").unwrap();
+
+        assert_eq!(atoms[0].bonds, vec![
+            Bond::new(Some(BondKind::Double), 1),
+            elided(2)
+        ])
+    }
+
+    #[test]
+    fn ring_bond_kind_from_closing() {
+        let atoms = read("C1CC=1").unwrap();
+
+        assert_eq!(atoms[2].bonds[1], Bond::new(Some(BondKind::Double), 0))
+    }
+
+    #[test]
+    fn split() {
+        let atoms = read("C.N").unwrap();
+
+        assert_eq!(atoms[0].bonds, vec![ ]);
+        assert_eq!(atoms[1].bonds, vec![ ])
+    }
+
+    #[test]
+    fn empty_bracket() {
+        assert_eq!(read("[]"), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn ring_count_unsupported() {
+        assert_eq!(read("[CR2]"), Err(Error::Character(3)))
+    }
+
+    #[test]
+    fn unclosed_branch() {
+        assert_eq!(read("C(C"), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn dangling_bond() {
+        assert_eq!(read("C~"), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        assert_eq!(read("C1CC"), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn mismatched_ring_bonds() {
+        assert_eq!(read("C=1CC#1"), Err(Error::Character(6)))
+    }
+
+    #[test]
+    fn leading_bond() {
+        assert_eq!(read("=C"), Err(Error::Character(0)))
+    }
+
+    #[test]
+    fn matches_pyridine() {
+        let query = read("[c,n]1ccccc1").unwrap();
+
+        assert!(has_substructure(&build("c1ccncc1"), &query));
+        assert!(has_substructure(&build("c1ccccc1"), &query));
+        assert!(!has_substructure(&build("C1CCNCC1"), &query))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::graph::{ self, search };
+use super::{ Atom, Matcher };
+
+/// Returns each match of the SMARTS `query` within `haystack` as a mapping
+/// from query atom index to haystack atom index. Matches covering the same
+/// set of haystack atoms are reported once, using the first mapping found.
+/// Matching follows `has_substructure`.
+/// 
+/// # Panics
+/// 
+/// Panics given a bond to an atom outside of `haystack` or `query`.
+pub fn substructure_matches(
+    haystack: &[graph::Atom], query: &[Atom]
+) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut seen = Vec::new();
+
+    search(haystack, &Matcher::new(query, haystack), &mut |mapping| {
+        let mut ids = mapping.to_vec();
+
+        ids.sort_unstable();
+
+        if !seen.contains(&ids) {
+            seen.push(ids);
+            result.push(mapping.to_vec())
+        }
+
+        true
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use crate::smarts::read;
+    use super::*;
+
+    fn matches(haystack: &str, query: &str) -> Vec<Vec<usize>> {
+        substructure_matches(&build(haystack), &read(query).expect("query"))
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(matches("CCO", "CN"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn symbol_list() {
+        assert_eq!(
+            matches("OCCN", "[N,O]C"),
+            vec![ vec![ 0, 1 ], vec![ 3, 2 ] ]
+        )
+    }
+
+    #[test]
+    fn ring_atoms() {
+        assert_eq!(matches("C1CC1C", "[C;R0]"), vec![ vec![ 3 ] ])
+    }
+}
\ No newline at end of file
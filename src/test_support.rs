@@ -0,0 +1,59 @@
+use crate::read::read;
+use crate::feature::{ AtomKind, Configuration };
+use crate::graph::{ Atom, Builder };
+
+/// Reads and builds `smiles`, panicking on any error.
+pub fn build(smiles: &str) -> Vec<Atom> {
+    let mut builder = Builder::new();
+
+    read(smiles, &mut builder, None).unwrap();
+
+    builder.build().unwrap()
+}
+
+/// Returns the map and handedness of each tetrahedral center, sorted by
+/// map. Handedness is reported for neighbors sorted by map, with a virtual
+/// hydrogen first, so it is independent of atom and bond order. Every
+/// center and neighbor must carry a map.
+pub fn chirality(atoms: &[Atom]) -> Vec<(u16, bool)> {
+    let mut result = Vec::new();
+
+    for atom in atoms {
+        let (clockwise, hydrogen, map) = match &atom.kind {
+            AtomKind::Bracket {
+                configuration: Some(configuration), hcount, map, ..
+            } => (
+                match configuration {
+                    Configuration::TH1 => false,
+                    Configuration::TH2 => true,
+                    _ => continue
+                },
+                matches!(hcount, Some(hcount) if !hcount.is_zero()),
+                u16::from(map.as_ref().expect("center map"))
+            ),
+            _ => continue
+        };
+        let mut neighbors = if hydrogen { vec![ 0 ] } else { vec![ ] };
+
+        for bond in atom.bonds.iter() {
+            neighbors.push(map_of(&atoms[bond.tid]))
+        }
+
+        let inversions = (0..neighbors.len()).flat_map(|i| {
+            (i + 1..neighbors.len()).map(move |j| (i, j))
+        }).filter(|&(i, j)| neighbors[i] > neighbors[j]).count();
+
+        result.push((map, clockwise ^ (inversions % 2 == 1)))
+    }
+
+    result.sort_unstable();
+
+    result
+}
+
+fn map_of(atom: &Atom) -> u16 {
+    match &atom.kind {
+        AtomKind::Bracket { map: Some(map), .. } => u16::from(map),
+        _ => panic!("neighbor map")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::graph::Atom;
+
+/// Returns the implicit or virtual hydrogen count of each atom, indexed as
+/// `atoms`, for inspecting how valence rules resolved a structure.
+///
+/// ```
+/// use purr::graph::Builder;
+/// use purr::read::{ read, Error };
+/// use purr::valence::implicit_hydrogen_map;
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+///
+///     read("CC=O", &mut builder, None)?;
+///
+///     let atoms = builder.build().expect("atoms");
+///
+///     assert_eq!(implicit_hydrogen_map(&atoms), vec![ 3, 1, 0 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn implicit_hydrogen_map(atoms: &[Atom]) -> Vec<u8> {
+    atoms.iter().map(|atom| atom.suppressed_hydrogens()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    fn map(smiles: &str) -> Vec<u8> {
+        implicit_hydrogen_map(&build(smiles))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(implicit_hydrogen_map(&[ ]), vec![ ])
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(map("CCO"), vec![ 3, 2, 1 ])
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(map("c1cc[nH]c1"), vec![ 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn bracket_and_star() {
+        assert_eq!(map("*[CH2][Na]"), vec![ 0, 2, 0 ])
+    }
+
+    #[test]
+    fn overvalent() {
+        assert_eq!(map("C(C)(C)(C)(C)C"), vec![ 0, 3, 3, 3, 3, 3 ])
+    }
+}
\ No newline at end of file
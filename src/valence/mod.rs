@@ -0,0 +1,5 @@
+mod recompute_hydrogens;
+mod implicit_hydrogen_map;
+
+pub use recompute_hydrogens::recompute_hydrogens;
+pub use implicit_hydrogen_map::implicit_hydrogen_map;
\ No newline at end of file
@@ -0,0 +1,222 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use crate::feature::{ Aliphatic, AtomKind, BracketSymbol, VirtualHydrogen };
+use crate::graph::Atom;
+
+/// Recalculates the virtual hydrogens of aliphatic bracket atoms with
+/// organic subset symbols whose bond orders leave no room for them, then
+/// debrackets them where possible. Use after raising bond orders, as in
+/// kekulization, so hydrogen counts agree with the new bonds. The count
+/// becomes the one an unbracketed atom with the same bonds would carry.
+/// 
+/// Counts that fit a valence target, or fall short of one, are explicit
+/// choices that bond orders cannot decide, so they are kept. Aromatic
+/// bracket atoms are unchanged, as are unbracketed atoms, which already
+/// derive their hydrogens from bonds, and other bracket atoms, such as
+/// metals.
+pub fn recompute_hydrogens(atoms: &mut [Atom]) {
+    for atom in atoms.iter_mut() {
+        match &atom.kind {
+            AtomKind::Bracket {
+                symbol: BracketSymbol::Element(element), ..
+            } if Aliphatic::try_from(element).is_ok() => (),
+            _ => continue
+        }
+
+        let bond_order_sum = atom.bonds.iter()
+            .fold(0, |sum, bond| sum + bond.order());
+        let over = match &atom.kind {
+            AtomKind::Bracket { hcount: Some(hcount), .. } => {
+                let hcount: u8 = hcount.into();
+
+                atom.kind.targets().iter()
+                    .all(|&target| target < bond_order_sum + hcount)
+            },
+            _ => false
+        };
+
+        if over {
+            if let AtomKind::Bracket { hcount, .. } = &mut atom.kind {
+                hcount.take();
+            }
+
+            let count = atom.subvalence();
+
+            if let AtomKind::Bracket { hcount, .. } = &mut atom.kind {
+                if count > 0 {
+                    *hcount = VirtualHydrogen::try_from(count).ok()
+                }
+            }
+        }
+
+        atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+            .debracket(bond_order_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::feature::BondKind;
+    use crate::walk::walk;
+    use crate::write::Writer;
+    use crate::test_support::build;
+    use super::*;
+
+    fn hydrogens(atoms: &[Atom]) -> Vec<u8> {
+        atoms.iter().map(|atom| atom.suppressed_hydrogens()).collect()
+    }
+
+    fn smiles(atoms: Vec<Atom>) -> String {
+        let mut writer = Writer::new();
+
+        walk(atoms, &mut writer).unwrap();
+
+        writer.write()
+    }
+
+    // Replaces aromatic atoms with aliphatic ones, with double bonds
+    // between each even id and the next.
+    fn kekulize(atoms: &mut [Atom]) {
+        for (sid, atom) in atoms.iter_mut().enumerate() {
+            atom.kind = match mem::replace(&mut atom.kind, AtomKind::Star) {
+                AtomKind::Aromatic(aromatic) =>
+                    AtomKind::Aliphatic(Aliphatic::from(&aromatic)),
+                AtomKind::Bracket {
+                    isotope, symbol, configuration, hcount, charge, map
+                } => AtomKind::Bracket {
+                    isotope,
+                    symbol: match symbol {
+                        BracketSymbol::Aromatic(aromatic) =>
+                            BracketSymbol::Element((&aromatic).into()),
+                        symbol => symbol
+                    },
+                    configuration,
+                    hcount,
+                    charge,
+                    map
+                },
+                kind => kind
+            };
+
+            for bond in atom.bonds.iter_mut() {
+                let low = sid.min(bond.tid);
+
+                bond.kind = if low % 2 == 0 && sid.max(bond.tid) == low + 1 {
+                    BondKind::Double
+                } else {
+                    BondKind::Single
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn benzene() {
+        let mut atoms = build("c1ccccc1");
+
+        assert_eq!(hydrogens(&atoms), vec![ 1; 6 ]);
+
+        kekulize(&mut atoms);
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(hydrogens(&atoms), vec![ 1; 6 ])
+    }
+
+    #[test]
+    fn bracketed_benzene() {
+        let mut atoms = build("[cH]1[cH][cH][cH][cH][cH]1");
+
+        assert_eq!(hydrogens(&atoms), vec![ 1; 6 ]);
+
+        kekulize(&mut atoms);
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(hydrogens(&atoms), vec![ 1; 6 ]);
+        assert_eq!(smiles(atoms), "C(C=CC=CC=1)=1")
+    }
+
+    #[test]
+    fn aromatic_bracket_unchanged() {
+        let mut atoms = build("[c]1ccccc1");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(hydrogens(&atoms), vec![ 0, 1, 1, 1, 1, 1 ])
+    }
+
+    #[test]
+    fn pyrrole() {
+        for input in &[ "c1cc[nH]c1", "[nH]1cccc1", "[cH]1cccc[nH]1" ] {
+            let mut atoms = build(input);
+
+            recompute_hydrogens(&mut atoms);
+
+            assert_eq!(smiles(atoms), smiles(build(input)))
+        }
+    }
+
+    #[test]
+    fn under_valent_bracket() {
+        let mut atoms = build("[CH2]");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "[CH2]")
+    }
+
+    #[test]
+    fn under_valent_bracket_bonded() {
+        let mut atoms = build("[CH2]C");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "[CH2]C")
+    }
+
+    #[test]
+    fn saturated_bracket() {
+        let mut atoms = build("[CH3]C");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "CC")
+    }
+
+    #[test]
+    fn over_valent_bracket() {
+        let mut atoms = build("[CH3]=C");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "C=C")
+    }
+
+    #[test]
+    fn charged_bracket() {
+        let mut atoms = build("C[NH+]");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "C[NH+]")
+    }
+
+    #[test]
+    fn over_valent_charged_bracket() {
+        let mut atoms = build("C=[NH3+]");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "C=[NH2+]")
+    }
+
+    #[test]
+    fn metal_unchanged() {
+        let mut atoms = build("[FeH2]");
+
+        recompute_hydrogens(&mut atoms);
+
+        assert_eq!(smiles(atoms), "[FeH2]")
+    }
+}
\ No newline at end of file
@@ -1,10 +1,46 @@
+use std::fmt;
+
 /// An error resulting from depth-first traversal of a graph
 /// representation.
-#[derive(Debug,PartialEq)]
+#[derive(Debug,PartialEq,Eq)]
 pub enum Error {
     HalfBond(usize, usize),
     DuplicateBond(usize, usize),
     UnknownTarget(usize, usize),
     IncompatibleBond(usize, usize),
-    Loop(usize)
+    Loop(usize),
+    UnknownRoot(usize),
+    MissingRoot(usize)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::HalfBond(sid, tid) =>
+                write!(f, "bond {}-{} has no reverse", sid, tid),
+            Error::DuplicateBond(sid, tid) =>
+                write!(f, "duplicate bond {}-{}", sid, tid),
+            Error::UnknownTarget(sid, tid) =>
+                write!(f, "bond {}-{} to unknown atom", sid, tid),
+            Error::IncompatibleBond(sid, tid) =>
+                write!(f, "incompatible bond {}-{}", sid, tid),
+            Error::Loop(id) => write!(f, "bond from atom {} to itself", id),
+            Error::UnknownRoot(id) => write!(f, "unknown root {}", id),
+            Error::MissingRoot(id) =>
+                write!(f, "no root given for atom {}", id)
+        }
+    }
+}
+
+impl std::error::Error for Error { }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Error::UnknownRoot(3).to_string(), "unknown root 3")
+    }
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
 mod follower;
+#[allow(clippy::module_inception)]
 mod walk;
 mod error;
+mod neighbor_order;
 
 pub use follower::Follower;
-pub use walk::walk;
-pub use error::Error;
\ No newline at end of file
+pub use walk::{ walk, walk_from, walk_with };
+pub(crate) use walk::{ walk_ordered, walk_from_ordered };
+pub use error::Error;
+pub use neighbor_order::NeighborOrder;
\ No newline at end of file
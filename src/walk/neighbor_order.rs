@@ -0,0 +1,19 @@
+/// The order in which `walk_with` visits the neighbors of each atom. The
+/// first neighbors visited are written as branches, and the last continues
+/// the main chain. Every order gives a valid string for the same graph.
+/// Components are rooted at their lowest atom ids unless noted.
+#[derive(Debug,PartialEq,Eq,Clone,Default)]
+pub enum NeighborOrder {
+    /// The order of each atom's bonds, as used by `walk`.
+    #[default]
+    Input,
+    /// Ascending canonical rank of the neighbor, including atom maps.
+    CanonicalRank,
+    /// Descending number of bonds at the neighbor. Ties keep input order.
+    DescendingDegree,
+    /// Roots each connected component at one end of its longest shortest
+    /// path, then visits neighbors farthest from the other end first. The
+    /// main chain runs between the two ends, with shorter chains as
+    /// branches, as chemists tend to draw them.
+    LongestChain
+}
\ No newline at end of file
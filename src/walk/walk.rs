@@ -1,7 +1,8 @@
-use std::collections::HashMap;
-
-use crate::graph::{ Atom, JoinPool };
-use super::{ Follower, Error };
+use crate::feature::AtomKind;
+use crate::graph::{
+    Atom, Bond, JoinPool, breadth_first, canonical_ranks, reorder_bonds
+};
+use super::{ Follower, Error, NeighborOrder };
 
 /// Performans a depth-first traversal of `graph`.
 /// 
@@ -39,33 +40,226 @@ use super::{ Follower, Error };
 pub fn walk<F: Follower>(
     graph: Vec<Atom>, follower: &mut F
 ) -> Result<(), Error> {
+    walk_ordered(graph, follower).map(|_| ())
+}
+
+/// Performs `walk` after sorting the bonds of each atom by `order`, so the
+/// same graph can be written in different, deterministic layouts.
+/// Configurations are updated to match the new neighbor order. A graph
+/// with a bond to an atom outside of it is walked unsorted, reporting the
+/// same error as `walk`.
+///
+/// ```
+/// use purr::graph::Builder;
+/// use purr::read::read;
+/// use purr::write::Writer;
+/// use purr::walk::{ walk_with, NeighborOrder };
+///
+/// fn main() {
+///     let mut builder = Builder::new();
+///     let mut writer = Writer::new();
+///
+///     read("C(O)CCC", &mut builder, None).expect("read");
+///     walk_with(
+///         builder.build().expect("atoms"),
+///         &NeighborOrder::DescendingDegree,
+///         &mut writer
+///     ).expect("walk");
+///
+///     assert_eq!(writer.write(), "C(CCC)O")
+/// }
+/// ```
+pub fn walk_with<F: Follower>(
+    mut graph: Vec<Atom>, order: &NeighborOrder, follower: &mut F
+) -> Result<(), Error> {
+    let size = graph.len();
+    let valid = graph.iter()
+        .all(|atom| atom.bonds.iter().all(|bond| bond.tid < size));
+
+    if !valid {
+        return walk(graph, follower)
+    }
+
+    let mut roots = (0..size).collect::<Vec<_>>();
+    let keys = match order {
+        NeighborOrder::Input => return walk(graph, follower),
+        NeighborOrder::CanonicalRank => canonical_ranks(&graph, true),
+        NeighborOrder::DescendingDegree => graph.iter()
+            .map(|atom| usize::MAX - atom.bonds.len()).collect(),
+        NeighborOrder::LongestChain => {
+            let (ends, distances) = longest_chains(&graph);
+
+            roots = ends;
+
+            distances.into_iter()
+                .map(|distance| usize::MAX - distance).collect()
+        }
+    };
+
+    for atom in graph.iter_mut() {
+        let mut order = (0..atom.bonds.len()).collect::<Vec<_>>();
+
+        order.sort_by_key(|&index| keys[atom.bonds[index].tid]);
+        reorder_bonds(atom, &order);
+    }
+
+    walk_roots(graph, &roots, follower).map(|_| ())
+}
+
+// Returns one end of a longest shortest path in each component, preferring
+// the end with fewer bonds, and the distance of every atom from the other
+// end.
+fn longest_chains(graph: &[Atom]) -> (Vec<usize>, Vec<usize>) {
+    let mut ends = Vec::new();
+    let mut distances = vec![ 0; graph.len() ];
+    let mut visited = vec![ false; graph.len() ];
+
+    for start in 0..graph.len() {
+        if visited[start] {
+            continue
+        }
+
+        let mut first = farthest(
+            &breadth_first(graph, start, &mut vec![ false; graph.len() ])
+        );
+        let mut second = farthest(
+            &breadth_first(graph, first, &mut vec![ false; graph.len() ])
+        );
+
+        if graph[second].bonds.len() < graph[first].bonds.len() {
+            std::mem::swap(&mut first, &mut second)
+        }
+
+        for (id, _, distance) in breadth_first(graph, second, &mut visited) {
+            distances[id] = distance
+        }
+
+        ends.push(first)
+    }
+
+    (ends, distances)
+}
+
+fn farthest(visits: &[(usize, usize, usize)]) -> usize {
+    visits.iter()
+        .max_by_key(|(id, _, distance)| (distance, std::cmp::Reverse(id)))
+        .map(|(id, _, _)| *id).expect("root")
+}
+
+/// Performs `walk`, returning the ids of `graph` in the order the
+/// follower received them.
+pub(crate) fn walk_ordered<F: Follower>(
+    graph: Vec<Atom>, follower: &mut F
+) -> Result<Vec<usize>, Error> {
+    let roots = (0..graph.len()).collect::<Vec<_>>();
+
+    walk_roots(graph, &roots, follower)
+}
+
+/// Performs a depth-first traversal of `graph`, starting each connected
+/// component from the first of `roots` it contains. Roots are visited in
+/// the order given, and later roots within an already-visited component
+/// are skipped.
+/// 
+/// Returns `Error::UnknownRoot` given a root outside of `graph`, and
+/// `Error::MissingRoot` with the lowest atom id of any component lacking a
+/// root.
+/// 
+/// ```
+/// use purr::graph::Builder;
+/// use purr::read::read;
+/// use purr::write::Writer;
+/// use purr::walk::walk_from;
+///
+/// fn main() {
+///     let mut builder = Builder::new();
+///     let mut writer = Writer::new();
+///
+///     read("CCO", &mut builder, None).expect("read");
+///     walk_from(builder.build().expect("atoms"), &[ 2 ], &mut writer)
+///         .expect("walk");
+///
+///     assert_eq!(writer.write(), "OCC")
+/// }
+/// ```
+pub fn walk_from<F: Follower>(
+    graph: Vec<Atom>, roots: &[usize], follower: &mut F
+) -> Result<(), Error> {
+    walk_from_ordered(graph, roots, follower).map(|_| ())
+}
+
+/// Performs `walk_from`, returning the ids of `graph` in the order the
+/// follower received them.
+pub(crate) fn walk_from_ordered<F: Follower>(
+    graph: Vec<Atom>, roots: &[usize], follower: &mut F
+) -> Result<Vec<usize>, Error> {
     let size = graph.len();
-    let ids = 0..size;
-    let mut atoms = graph.into_iter().enumerate().collect::<HashMap<_,_>>();
+    let mut covered = vec![ false; size ];
+
+    if let Some(root) = roots.iter().find(|&&root| root >= size) {
+        return Err(Error::UnknownRoot(*root))
+    }
+
+    for (sid, atom) in graph.iter().enumerate() {
+        if let Some(bond) = atom.bonds.iter().find(|bond| bond.tid >= size) {
+            return Err(Error::UnknownTarget(sid, bond.tid))
+        }
+    }
+
+    for &root in roots {
+        if !covered[root] {
+            breadth_first(&graph, root, &mut covered);
+        }
+    }
+
+    if let Some(id) = covered.iter().position(|covered| !covered) {
+        return Err(Error::MissingRoot(id))
+    }
+
+    walk_roots(graph, roots, follower)
+}
+
+fn walk_roots<F: Follower>(
+    graph: Vec<Atom>, roots: &[usize], follower: &mut F
+) -> Result<Vec<usize>, Error> {
+    let size = graph.len();
+    let mut atoms = graph.into_iter().map(Some).collect::<Vec<_>>();
     let mut pool = JoinPool::new();
+    let mut order = Vec::with_capacity(size);
+    // Shared across roots. The chain never exceeds the atom count, and the
+    // stack rarely does.
+    let mut stack = Vec::with_capacity(size);
+    let mut chain = Vec::with_capacity(size);
 
-    for id in ids {
-        let root = match atoms.remove(&id) {
+    for id in roots {
+        let root = match atoms[*id].take() {
             Some(root) => root,
             None => continue
         };
 
-        walk_root(id, root, size, &mut atoms, follower, &mut pool)?;
+        walk_root(*id, root, size, &mut atoms, follower, &mut pool, Buffers {
+            stack: &mut stack,
+            chain: &mut chain,
+            order: &mut order
+        })?;
     }
 
-    Ok(())
+    Ok(order)
 }
 
 fn walk_root<F: Follower>(
     pid: usize,
     parent: Atom,
     size: usize,
-    atoms: &mut HashMap<usize, Atom>,
+    atoms: &mut [Option<Atom>],
     follower: &mut F,
-    pool: &mut JoinPool
+    pool: &mut JoinPool,
+    buffers: Buffers
 ) -> Result<(), Error> {
-    let mut stack = Vec::new();
-    let mut chain = Vec::new();
+    let Buffers { stack, chain, order } = buffers;
+
+    stack.clear();
+    chain.clear();
 
     for bond in parent.bonds.into_iter().rev() {
         stack.push((pid, bond))
@@ -73,6 +267,7 @@ fn walk_root<F: Follower>(
 
     follower.root(parent.kind);
     chain.push(pid);
+    order.push(pid);
 
     while let Some((sid, bond)) = stack.pop() {
         if bond.tid >= size {
@@ -99,18 +294,15 @@ fn walk_root<F: Follower>(
             follower.pop(popcount)
         }
 
-        match atoms.remove(&bond.tid) {
+        match atoms[bond.tid].take() {
             Some(mut child) => {
+                let degree = child.bonds.len();
                 let mut back = None;
 
-                for (out_index, out) in child.bonds.into_iter().enumerate().rev() {
+                for (index, out) in child.bonds.into_iter().enumerate().rev() {
                     if out.tid == sid {
-                        if out_index % 2 == 0 {
-                            child.kind.invert_configuration()
-                        }
-
                         if back.is_none() {
-                            back = Some(out);
+                            back = Some((index, out));
                         } else {
                             return Err(Error::DuplicateBond(sid, bond.tid))
                         }
@@ -121,7 +313,7 @@ fn walk_root<F: Follower>(
                     stack.push((bond.tid, out));
                 }
 
-                if let Some(back) = back {
+                if let Some((index, back)) = back {
                     if bond.is_directional() {
                         if bond.kind != back.kind.reverse() {
                             return Err(Error::IncompatibleBond(bond.tid, sid))
@@ -129,11 +321,14 @@ fn walk_root<F: Follower>(
                     } else if bond.kind != back.kind {
                         return Err(Error::IncompatibleBond(bond.tid, sid))
                     }
+
+                    lead_with(&mut child.kind, degree, index)
                 } else {
                     return Err(Error::HalfBond(sid, bond.tid))
                 }
 
                 chain.push(bond.tid);
+                order.push(bond.tid);
                 follower.extend(bond.kind, child.kind)
             },
             None => {
@@ -145,7 +340,30 @@ fn walk_root<F: Follower>(
     Ok(())
 }
 
+// Updates the configuration of a child written after its parent, which
+// leads its neighbors ahead of any virtual hydrogen and the remaining bonds.
+fn lead_with(kind: &mut AtomKind, degree: usize, index: usize) {
+    let hydrogens = match kind {
+        AtomKind::Bracket { hcount: Some(hcount), .. } =>
+            usize::from(!hcount.is_zero()),
+        _ => 0
+    };
+    let first = index + hydrogens;
+    let permutation = std::iter::once(first)
+        .chain((0..degree + hydrogens).filter(|&position| position != first))
+        .collect::<Vec<_>>();
+
+    kind.permute_neighbors(&permutation)
+}
+
+struct Buffers<'a> {
+    stack: &'a mut Vec<(usize, Bond)>,
+    chain: &'a mut Vec<usize>,
+    order: &'a mut Vec<usize>
+}
+
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod walk {
     use pretty_assertions::assert_eq;
     use crate::feature::{
@@ -153,9 +371,18 @@ mod walk {
         Aliphatic
     };
     use crate::graph::Bond;
+    use crate::test_support::build;
     use crate::write::Writer;
     use super::*;
 
+    fn write(smiles: &str) -> String {
+        let mut writer = Writer::new();
+
+        walk(build(smiles), &mut writer).unwrap();
+
+        writer.write()
+    }
+
     #[test]
     fn half_bond() {
         let mut writer = Writer::new();
@@ -248,6 +475,50 @@ mod walk {
         assert_eq!(walk(graph, &mut writer), Err(Error::IncompatibleBond(1, 0)))
     }
 
+    #[test]
+    fn unknown_root() {
+        let mut writer = Writer::new();
+        let graph = vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![ ]
+            }
+        ];
+
+        assert_eq!(
+            walk_from(graph, &[ 1 ], &mut writer),
+            Err(Error::UnknownRoot(1))
+        )
+    }
+
+    #[test]
+    fn missing_root() {
+        let mut writer = Writer::new();
+        let graph = vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 1)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![
+                    Bond::new(BondKind::Elided, 0)
+                ]
+            },
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![ ]
+            }
+        ];
+
+        assert_eq!(
+            walk_from(graph, &[ 1 ], &mut writer),
+            Err(Error::MissingRoot(2))
+        )
+    }
+
     #[test]
     fn p1() {
         let mut writer = Writer::new();
@@ -595,4 +866,438 @@ mod walk {
 
         assert_eq!(writer.write(), "*[*@H](*)(*)*")
     }
+
+    #[test]
+    fn star_stereocenter() {
+        assert_eq!(write("*[*@](*)(*)*"), "*[*@](*)(*)*")
+    }
+
+    #[test]
+    fn star_stereocenter_root() {
+        assert_eq!(write("[*@@](*)(*)(*)*"), "[*@@](*)(*)(*)*")
+    }
+
+    #[test]
+    fn star_stereocenter_hydrogen() {
+        assert_eq!(write("*[*@H](*)*"), "*[*@H](*)*")
+    }
+
+    #[test]
+    fn star_stereocenter_ring() {
+        assert_eq!(write("*[*@]1(*)**1"), "*[*@](**1)(*)1")
+    }
+
+    #[test]
+    fn aromatic_ring_closure_elided() {
+        assert_eq!(write("c1ccccc1"), "c(ccccc1)1")
+    }
+
+    #[test]
+    fn aromatic_ring_closure_explicit_single() {
+        assert_eq!(write("c-1ccccc1"), "c(-ccccc1)1");
+        assert_eq!(write("c1ccccc-1"), "c(-ccccc1)1")
+    }
+
+    #[test]
+    fn aromatic_ring_explicit_single_chain() {
+        assert_eq!(write("c1cccc-c1"), "c(c-cccc1)1")
+    }
+
+    #[test]
+    fn directional_ring_closure() {
+        assert_eq!(write("C/1=C/CCCC1"), "C(/CCCC\\C=1)=1")
+    }
+
+    #[test]
+    fn directional_ring_closure_both_ends() {
+        assert_eq!(write("C/1=C/CCCC\\1"), "C(/CCCC\\C=1)=1")
+    }
+
+    // A linear acene of `rings` fused benzene rings.
+    fn acene(rings: usize) -> String {
+        let rnum = |rnum: usize| if rnum < 10 {
+            rnum.to_string()
+        } else {
+            format!("%{}", rnum)
+        };
+        let mut result = "c1ccc2".to_string();
+
+        for ring in 3..=rings {
+            result.push_str(&format!("cc{}", rnum(ring)))
+        }
+
+        result.push_str(&format!("ccccc{}", rnum(rings)));
+
+        for ring in (2..rings).rev() {
+            result.push_str(&format!("cc{}", rnum(ring)))
+        }
+
+        result + "c1"
+    }
+
+    #[test]
+    fn large_fused_rings_stable() {
+        let smiles = acene(40);
+        let write = || {
+            let mut builder = crate::graph::Builder::new();
+            let mut writer = Writer::new();
+
+            crate::read::read(&smiles, &mut builder, None).unwrap();
+            walk(builder.build().unwrap(), &mut writer).unwrap();
+
+            writer.write()
+        };
+        let first = write();
+
+        for _ in 0..20 {
+            assert_eq!(write(), first)
+        }
+
+        assert_eq!(first.matches('c').count(), 162)
+    }
+}
+
+#[cfg(test)]
+mod walk_from {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ AtomKind, BondKind };
+    use crate::test_support::{ build, chirality };
+    use crate::write::Writer;
+    use super::*;
+
+    #[test]
+    fn ethanol_oxygen() {
+        let mut writer = Writer::new();
+
+        walk_from(build("CCO"), &[ 2 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "OCC")
+    }
+
+    #[test]
+    fn ethanol_middle() {
+        let mut writer = Writer::new();
+
+        walk_from(build("CCO"), &[ 1 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "C(C)O")
+    }
+
+    #[test]
+    fn redundant_root() {
+        let mut writer = Writer::new();
+
+        walk_from(build("CCO"), &[ 2, 0 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "OCC")
+    }
+
+    #[test]
+    fn components_in_root_order() {
+        let mut writer = Writer::new();
+
+        walk_from(build("CC.O"), &[ 2, 1 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "O.CC")
+    }
+
+    #[test]
+    fn ring() {
+        let mut writer = Writer::new();
+
+        walk_from(build("C1CCO1"), &[ 3 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "O(CCC1)1")
+    }
+
+    #[test]
+    fn rnum_reused_across_sequential_rings() {
+        let mut writer = Writer::new();
+
+        walk_from(build("C(C1CC1)C1CC1"), &[ 0 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "C(C(CC1)1)C(CC1)1")
+    }
+
+    #[test]
+    fn rnums_reused_after_fused_rings() {
+        let mut writer = Writer::new();
+
+        walk_from(build("C(C12CC1CC2)C12CC1CC2"), &[ 0 ], &mut writer)
+            .unwrap();
+
+        assert_eq!(writer.write(), "C(C(C(C1)CC2)21)C(C(C1)CC2)21")
+    }
+
+    #[test]
+    fn rnum_reused_across_components() {
+        let mut writer = Writer::new();
+
+        walk_from(build("C1CC1.C1CC1"), &[ 0, 3 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "C(CC1)1.C(CC1)1")
+    }
+
+    #[test]
+    fn rnums_reused_after_fused_component() {
+        let mut writer = Writer::new();
+
+        walk_from(build("C12CC1CC2.C1CC1"), &[ 0, 5 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "C(C(C1)CC2)21.C(CC1)1")
+    }
+
+    #[test]
+    fn unknown_target() {
+        let mut writer = Writer::new();
+        let graph = vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![ Bond::new(BondKind::Elided, 1) ]
+            }
+        ];
+
+        assert_eq!(
+            walk_from(graph, &[ 0 ], &mut writer),
+            Err(Error::UnknownTarget(0, 1))
+        )
+    }
+
+    #[test]
+    fn tetrahedral_non_default_root() {
+        let mut writer = Writer::new();
+
+        walk_from(build("F[C@](Cl)(Br)I"), &[ 2 ], &mut writer).unwrap();
+
+        assert_eq!(writer.write(), "Cl[C@@](F)(Br)I")
+    }
+
+    #[test]
+    fn chirality_from_every_root() {
+        let tests = vec![
+            "[F:1][C@:2]([Cl:3])([Br:4])[I:5]",
+            "[NH2:1][C@@H:2]([CH3:3])[C:4](=[O:5])[OH:6]",
+            "[F:1][C@:2]1([Cl:3])[CH2:4][CH2:5][NH:6][CH2:7]1",
+            "[CH3:1][C@H:2]1[CH2:3][CH2:4][C@@H:5]([OH:6])[O:7]1",
+            "[F:1][C@@:2]12[CH2:3][C@H:4]([Cl:5])[CH2:6][CH2:7]1[CH2:8]2"
+        ];
+
+        for smiles in tests {
+            let atoms = build(smiles);
+
+            for root in 0..atoms.len() {
+                let mut writer = Writer::new();
+
+                walk_from(atoms.clone(), &[ root ], &mut writer).unwrap();
+
+                let written = writer.write();
+
+                assert_eq!(
+                    chirality(&build(&written)), chirality(&atoms),
+                    "{} -> {}", smiles, written
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod walk_with {
+    use pretty_assertions::assert_eq;
+    use crate::feature::{ AtomKind, BondKind, Configuration };
+    use crate::test_support::{ build, chirality };
+    use crate::write::{ Writer, canonical };
+    use super::*;
+
+    fn write(smiles: &str, order: &NeighborOrder) -> String {
+        let mut writer = Writer::new();
+
+        walk_with(build(smiles), order, &mut writer).unwrap();
+
+        writer.write()
+    }
+
+    #[test]
+    fn unknown_target() {
+        let mut writer = Writer::new();
+        let graph = vec![
+            Atom {
+                kind: AtomKind::Star,
+                bonds: vec![ Bond::new(BondKind::Elided, 1) ]
+            }
+        ];
+
+        assert_eq!(
+            walk_with(graph, &NeighborOrder::DescendingDegree, &mut writer),
+            Err(Error::UnknownTarget(0, 1))
+        )
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(
+            write("CC(C)CC(=O)O", &NeighborOrder::Input),
+            "CC(C)CC(=O)O"
+        )
+    }
+
+    #[test]
+    fn descending_degree() {
+        assert_eq!(
+            write("CC(C)CC(=O)O", &NeighborOrder::DescendingDegree),
+            "CC(CC(=O)O)C"
+        )
+    }
+
+    #[test]
+    fn canonical_rank() {
+        assert_eq!(
+            write("C[C@H](O)CC", &NeighborOrder::CanonicalRank),
+            "C[C@@H](CC)O"
+        )
+    }
+
+    #[test]
+    fn longest_chain_isopentane() {
+        assert_eq!(write("CC(C)CC", &NeighborOrder::LongestChain), "CCC(C)C")
+    }
+
+    #[test]
+    fn longest_chain_isopentane_branch_first() {
+        assert_eq!(
+            write("C(C)(C)CC", &NeighborOrder::LongestChain),
+            "CCC(C)C"
+        )
+    }
+
+    #[test]
+    fn longest_chain_ethylbenzene() {
+        assert_eq!(
+            write("c1ccccc1CC", &NeighborOrder::LongestChain),
+            "CCc(ccccc1)1"
+        )
+    }
+
+    #[test]
+    fn longest_chain_components() {
+        assert_eq!(
+            write("OCC.CC(C)CC", &NeighborOrder::LongestChain),
+            "CCO.CCC(C)C"
+        )
+    }
+
+    #[test]
+    fn longest_chain_stereo() {
+        let smiles = "N[C@@H](C)C(=O)O";
+        let output = write(smiles, &NeighborOrder::LongestChain);
+
+        assert_eq!(output, "O=C(O)[C@H](C)N");
+        assert_eq!(canonical(&output), canonical(smiles))
+    }
+
+    #[test]
+    fn orders_same_molecule() {
+        let smiles = "N[C@@H](CC1CC1)C(=O)O";
+        let orders = [
+            NeighborOrder::Input,
+            NeighborOrder::CanonicalRank,
+            NeighborOrder::DescendingDegree
+        ];
+        let outputs = orders.iter()
+            .map(|order| write(smiles, order)).collect::<Vec<_>>();
+
+        assert_eq!(outputs, vec![
+            "N[C@@H](CC(CC1)1)C(=O)O",
+            "N[C@@H](CC(CC1)1)C(=O)O",
+            "N[C@H](C(=O)O)CC(CC1)1"
+        ]);
+
+        for output in outputs {
+            assert_eq!(canonical(&output), canonical(smiles))
+        }
+    }
+
+    #[test]
+    fn canonical_rank_stereo() {
+        assert_eq!(
+            write(
+                "[F:1][C@@:2]([Cl:3])([CH2:4][CH2:5][OH:6])[CH2:7][CH3:8]",
+                &NeighborOrder::CanonicalRank
+            ),
+            "[F:1][C@:2]([CH2:7][CH3:8])([CH2:4][CH2:5][OH:6])[Cl:3]"
+        )
+    }
+
+    #[test]
+    fn chirality_every_order() {
+        let tests = vec![
+            "[F:1][C@@:2]([Cl:3])([CH2:4][CH2:5][OH:6])[CH2:7][CH3:8]",
+            "[NH2:1][C@@H:2]([CH3:3])[C:4](=[O:5])[OH:6]",
+            "[F:1][C@:2]1([Cl:3])[CH2:4][CH2:5][NH:6][CH2:7]1",
+            "[CH3:1][C@H:2]1[CH2:3][CH2:4][C@@H:5]([OH:6])[O:7]1"
+        ];
+        let orders = [
+            NeighborOrder::Input,
+            NeighborOrder::CanonicalRank,
+            NeighborOrder::DescendingDegree,
+            NeighborOrder::LongestChain
+        ];
+
+        for smiles in tests {
+            for order in orders.iter() {
+                let written = write(smiles, order);
+
+                assert_eq!(
+                    chirality(&build(&written)), chirality(&build(smiles)),
+                    "{:?}: {} -> {}", order, smiles, written
+                )
+            }
+        }
+    }
+
+    // The configuration of the only stereocenter in `smiles`, relabeled to
+    // list its neighbors by element.
+    fn by_element(smiles: &str) -> Option<Configuration> {
+        let atoms = build(smiles);
+        let center = atoms.iter().find(|atom| matches!(
+            atom.kind, AtomKind::Bracket { configuration: Some(_), .. }
+        ))?;
+        let mut permutation = (0..center.bonds.len()).collect::<Vec<_>>();
+        let mut kind = center.kind.clone();
+
+        permutation.sort_by_key(|&i| atoms[center.bonds[i].tid].kind.element());
+        kind.permute_neighbors(&permutation);
+
+        match kind {
+            AtomKind::Bracket { configuration, .. } => configuration,
+            _ => None
+        }
+    }
+
+    #[test]
+    fn other_configurations() {
+        let tests = vec![
+            "F[Pt@SP1](Cl)(Br)I",
+            "F[As@TB1](Cl)(Br)(I)N",
+            "F[Fe@OH1](Cl)(Br)(I)(N)O"
+        ];
+        let orders = [
+            NeighborOrder::Input,
+            NeighborOrder::CanonicalRank,
+            NeighborOrder::DescendingDegree,
+            NeighborOrder::LongestChain
+        ];
+
+        for smiles in tests {
+            let expected = by_element(smiles);
+
+            assert!(expected.is_some());
+
+            for order in orders.iter() {
+                let output = write(smiles, order);
+
+                assert_eq!(by_element(&output), expected, "{}", output)
+            }
+        }
+    }
 }
\ No newline at end of file
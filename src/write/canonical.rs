@@ -0,0 +1,255 @@
+use crate::read::read;
+use crate::graph::{ Builder, canonical_smiles_with_order };
+use super::Error;
+
+/// Reads `smiles` into a graph, canonicalizes it, and writes it again.
+/// Equivalent inputs, including tetrahedral centers written with
+/// different neighbor orders and double bonds written with either set of
+/// bond directions, give the same string. Components are written in the
+/// order given by `graph::component_roots`.
+///
+/// Stereocenters related by symmetry, such as the two centers of
+/// cis-1,4-dimethylcyclohexane, are also normalized: where symmetric atoms
+/// could be ordered more than one way, the smallest string is kept.
+/// 
+/// ```
+/// use purr::write::{ canonical, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     assert_eq!(canonical("OCC")?, canonical("CCO")?);
+///     assert_eq!(
+///         canonical("[C@](F)(Cl)(Br)I")?,
+///         canonical("F[C@](Cl)(Br)I")?
+///     );
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn canonical(smiles: &str) -> Result<String, Error> {
+    let mut builder = Builder::new();
+
+    read(smiles, &mut builder, None)?;
+
+    let (result, _) = canonical_smiles_with_order(&builder.build()?)?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::{ build, chirality };
+    use crate::walk::walk_from;
+    use crate::write::Writer;
+    use super::*;
+
+    #[test]
+    fn read_error() {
+        assert_eq!(
+            canonical("C("),
+            Err(Error::Read(crate::read::Error::EndOfLine))
+        )
+    }
+
+    #[test]
+    fn idempotent() {
+        let corpus = vec![
+            "CCO",
+            "C1CC1",
+            "c1ccccc1",
+            "N[C@@H](C)C(=O)O",
+            "[C@](F)(Cl)(Br)I",
+            "[Na+].[Cl-]",
+            "[13CH3:1]C(=O)[O-]"
+        ];
+
+        for smiles in corpus {
+            let once = canonical(smiles).unwrap();
+
+            assert_eq!(canonical(&once).unwrap(), once, "input: {}", smiles)
+        }
+    }
+
+    #[test]
+    fn stable_across_repetitions() {
+        let corpus = vec![
+            "c1ccc2cc3ccccc3cc2c1",
+            "C12C3C4C1C5C2C3C45",
+            "OC(=O)C1CC2CCC1C2.[Na+].[Cl-]",
+            "N[C@@H](Cc1c[nH]c2ccccc12)C(=O)O"
+        ];
+
+        for smiles in corpus {
+            let first = canonical(smiles).unwrap();
+
+            for _ in 0..100 {
+                assert_eq!(canonical(smiles).unwrap(), first, "{}", smiles)
+            }
+        }
+    }
+
+    #[test]
+    fn tetrahedral() {
+        let expected = canonical("[C@](F)(Cl)(Br)I").unwrap();
+        let tests = vec![
+            "F[C@](Cl)(Br)I",
+            "Cl[C@](F)(I)Br",
+            "I[C@@](F)(Cl)Br",
+            "[C@@](Cl)(F)(Br)I"
+        ];
+
+        for smiles in tests {
+            assert_eq!(
+                canonical(smiles).unwrap(), expected, "input: {}", smiles
+            )
+        }
+    }
+
+    #[test]
+    fn salt_component_order() {
+        assert_eq!(canonical("[Cl-].[Na+]"), canonical("[Na+].[Cl-]"));
+        assert_eq!(canonical("[Na+].[Cl-]").unwrap(), "[Cl-].[Na+]")
+    }
+
+    #[test]
+    fn larger_component_first() {
+        assert_eq!(canonical("O.OCC").unwrap(), "CCO.O");
+        assert_eq!(canonical("CCO.O").unwrap(), "CCO.O")
+    }
+
+    #[test]
+    fn tetrahedral_enantiomer() {
+        assert_ne!(
+            canonical("[C@](F)(Cl)(Br)I").unwrap(),
+            canonical("[C@@](F)(Cl)(Br)I").unwrap()
+        )
+    }
+
+    #[test]
+    fn tetrahedral_hydrogen() {
+        assert_eq!(
+            canonical("N[C@@H](C)C(=O)O").unwrap(),
+            canonical("C[C@H](N)C(=O)O").unwrap()
+        );
+        assert_eq!(
+            canonical("OC(=O)[C@H](C)N").unwrap(),
+            canonical("N[C@@H](C)C(=O)O").unwrap()
+        )
+    }
+
+    #[test]
+    fn tetrahedral_without_hydrogen() {
+        let once = canonical("F[C@](Cl)(C)CC").unwrap();
+
+        assert_eq!(once, "CC[C@](C)(Cl)F");
+        assert_eq!(canonical(&once).unwrap(), once)
+    }
+
+    #[test]
+    fn tetrahedral_enantiomers_stable() {
+        let tests = vec![
+            ("F[C@](Cl)(C)CC", "F[C@@](Cl)(C)CC"),
+            ("N[C@@H](C)C(=O)O", "N[C@H](C)C(=O)O"),
+            ("F[C@]1(Cl)CCNC1", "F[C@@]1(Cl)CCNC1"),
+            ("OC[C@H]1CC[C@@H](C)O1", "OC[C@@H]1CC[C@H](C)O1")
+        ];
+
+        for (left, right) in tests {
+            let left = canonical(left).unwrap();
+            let right = canonical(right).unwrap();
+
+            assert_ne!(left, right);
+            assert_eq!(canonical(&left).unwrap(), left);
+            assert_eq!(canonical(&right).unwrap(), right)
+        }
+    }
+
+    #[test]
+    fn tetrahedral_chirality_kept() {
+        let tests = vec![
+            "[F:1][C@:2]([Cl:3])([CH3:4])[CH2:5][CH3:6]",
+            "[F:1][C@@:2]([Cl:3])([CH2:4][CH2:5][OH:6])[CH2:7][CH3:8]",
+            "[NH2:1][C@@H:2]([CH3:3])[C:4](=[O:5])[OH:6]",
+            "[F:1][C@:2]1([Cl:3])[CH2:4][CH2:5][NH:6][CH2:7]1",
+            "[CH3:1][C@H:2]1[CH2:3][CH2:4][C@@H:5]([OH:6])[O:7]1"
+        ];
+
+        for smiles in tests {
+            let written = canonical(smiles).unwrap();
+
+            assert_eq!(
+                chirality(&build(&written)), chirality(&build(smiles)),
+                "{} -> {}", smiles, written
+            )
+        }
+    }
+
+    #[test]
+    fn directional_bonds() {
+        assert_eq!(canonical("F/C=C/F"), canonical("F\\C=C\\F"));
+        assert_eq!(canonical("F/C=C\\F"), canonical("F\\C=C/F"));
+        assert_ne!(canonical("F/C=C/F"), canonical("F/C=C\\F"))
+    }
+
+    #[test]
+    fn symmetric_tetrahedral_centers() {
+        let cis = [ "C[C@H]1CC[C@@H](C)CC1", "C[C@@H]1CC[C@H](C)CC1" ];
+        let trans = [ "C[C@H]1CC[C@H](C)CC1", "C[C@@H]1CC[C@@H](C)CC1" ];
+
+        for left in cis.iter() {
+            let once = canonical(left).unwrap();
+
+            assert_eq!(canonical(&once).unwrap(), once);
+
+            for right in trans.iter() {
+                assert_ne!(canonical(right).unwrap(), once)
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_stereocenters() {
+        let tests = vec![
+            ("C[C@H](CC[C@@H](CC1)O)1", "C([C@H](C)CC[C@@H](C1)O)1"),
+            (
+                "C(CC[C@@H](CCCC[C@H](C1)2)2)1",
+                "C(CC[C@H](CCCC[C@@H](C1)2)2)1"
+            ),
+            ("[C@H]([C@H](Cl)F)(Cl)F", "[C@@H]([C@@H](Cl)F)(Cl)F")
+        ];
+
+        for (left, right) in tests {
+            assert_eq!(canonical(left), canonical(right), "{}", left)
+        }
+    }
+
+    #[test]
+    fn every_root() {
+        let corpus = vec![
+            "C[C@H](CC[C@@H](CC1)O)1",
+            "C(CC[C@@H](CCCC[C@H](C1)2)2)1",
+            "[C@H]([C@H](Cl)F)(Cl)F",
+            "C[C@H](Cl)[C@@H](C)Cl",
+            "O[C@H]1[C@H](O)[C@@H](O)[C@H](O)[C@@H](O)[C@@H]1O",
+            "F/C=C/C=C/F",
+            "N[C@@H](C)C(=O)O"
+        ];
+
+        for smiles in corpus {
+            let atoms = build(smiles);
+            let expected = canonical(smiles).unwrap();
+
+            for root in 0..atoms.len() {
+                let mut writer = Writer::new();
+
+                walk_from(atoms.clone(), &[ root ], &mut writer).unwrap();
+
+                let written = writer.write();
+
+                assert_eq!(
+                    canonical(&written).unwrap(), expected, "{}", written
+                )
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::{ read, graph, walk };
+
+/// An error resulting from a full read, build, walk, and write cycle, also
+/// exported as `molecule::PurrError`. `Read` errors come from a string
+/// that does not follow the SMILES grammar. `Graph` errors come from a
+/// well-formed string that does not describe a valid graph, such as one
+/// with an unbalanced ring closure. `Walk` errors come from writing a
+/// graph.
+#[derive(Debug,PartialEq,Eq)]
+pub enum Error {
+    Read(read::Error),
+    Graph(graph::Error),
+    Walk(walk::Error)
+}
+
+impl From<read::Error> for Error {
+    fn from(error: read::Error) -> Self {
+        Error::Read(error)
+    }
+}
+
+impl From<graph::Error> for Error {
+    fn from(error: graph::Error) -> Self {
+        Error::Graph(error)
+    }
+}
+
+impl From<walk::Error> for Error {
+    fn from(error: walk::Error) -> Self {
+        Error::Walk(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Read(error) => error.fmt(f),
+            Error::Graph(error) => error.fmt(f),
+            Error::Walk(error) => error.fmt(f)
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Read(error) => Some(error),
+            Error::Graph(error) => Some(error),
+            Error::Walk(error) => Some(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use super::*;
+
+    fn parse(smiles: &str) -> Result<String, Error> {
+        let mut builder = graph::Builder::new();
+
+        read::read(smiles, &mut builder, None)?;
+
+        Ok(graph::to_smiles(&builder.build()?)?)
+    }
+
+    #[test]
+    fn read() {
+        assert_eq!(parse("CC?"), Err(Error::Read(read::Error::Character(2))))
+    }
+
+    #[test]
+    fn graph_unbalanced_ring() {
+        assert_eq!(parse("C1CC"), Err(Error::Graph(graph::Error::Rnum(0))))
+    }
+
+    #[test]
+    fn graph_join() {
+        assert_eq!(
+            parse("C=1CC#1"),
+            Err(Error::Graph(graph::Error::Join(2, 0)))
+        )
+    }
+
+    #[test]
+    fn display_read() {
+        assert_eq!(
+            parse("CC?").unwrap_err().to_string(),
+            "unexpected character at 2"
+        )
+    }
+
+    #[test]
+    fn boxed() {
+        fn boxed() -> Result<String, Box<dyn std::error::Error>> {
+            Ok(parse("C1CC")?)
+        }
+
+        assert_eq!(boxed().unwrap_err().to_string(), "unclosed ring closure 0")
+    }
+}
\ No newline at end of file
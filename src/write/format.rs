@@ -0,0 +1,100 @@
+use std::mem;
+
+use crate::read::read;
+use crate::feature::AtomKind;
+use crate::graph::Builder;
+use crate::walk::walk;
+use super::{ Writer, Error };
+
+/// Rewrites `smiles` with normalized syntax, preserving the molecule and
+/// its atom order. Bracket atoms that can be written without brackets are
+/// unbracketed, ring closure digits are renumbered from the lowest free
+/// digit, and single bonds are elided wherever they would be read back as
+/// single. Unlike `canonical`, the output depends on the input's atom
+/// order.
+/// 
+/// ```
+/// use purr::write::{ format, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     assert_eq!(format("[CH3]-[CH2]-[OH]")?, "CCO");
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn format(smiles: &str) -> Result<String, Error> {
+    let mut builder = Builder::with_capacity(smiles.len() / 2);
+    let mut writer = Writer::new();
+
+    read(smiles, &mut builder, None)?;
+
+    let mut atoms = builder.build()?;
+
+    for atom in atoms.iter_mut() {
+        let bond_order_sum = atom.bonds.iter()
+            .fold(0, |sum, bond| sum + bond.order());
+
+        atom.kind = mem::replace(&mut atom.kind, AtomKind::Star)
+            .debracket(bond_order_sum)
+    }
+
+    walk(atoms, &mut writer)?;
+
+    Ok(writer.write())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::{ read, graph };
+    use super::*;
+
+    #[test]
+    fn read_error() {
+        assert_eq!(format("C("), Err(Error::Read(read::Error::EndOfLine)))
+    }
+
+    #[test]
+    fn graph_error() {
+        assert_eq!(format("C1CC"), Err(Error::Graph(graph::Error::Rnum(0))))
+    }
+
+    #[test]
+    fn ethane_brackets_and_single() {
+        assert_eq!(format("[CH3]-[CH3]"), Ok("CC".to_string()))
+    }
+
+    #[test]
+    fn ring_digits() {
+        assert_eq!(format("C7CC7"), Ok("C(CC1)1".to_string()))
+    }
+
+    #[test]
+    fn aromatic_bracket() {
+        assert_eq!(format("[cH]1cccc[cH]1"), format("c1ccccc1"))
+    }
+
+    #[test]
+    fn keeps_necessary_brackets() {
+        assert_eq!(
+            format("[NH4+].[CH2]=[13CH2]"),
+            Ok("[NH4+].C=[13CH2]".to_string())
+        )
+    }
+
+    #[test]
+    fn keeps_stereo() {
+        assert_eq!(
+            format("[C@@H](F)(Cl)Br"),
+            Ok("[C@@H](F)(Cl)Br".to_string())
+        )
+    }
+
+    #[test]
+    fn biphenyl_single() {
+        assert_eq!(
+            format("c1ccccc1-c1ccccc1"),
+            Ok("c(c(cccc1)-c(ccccc2)2)1".to_string())
+        )
+    }
+}
\ No newline at end of file
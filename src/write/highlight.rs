@@ -0,0 +1,113 @@
+use std::ops::Range;
+
+use crate::graph::Atom;
+use crate::read::{ read, Trace };
+use crate::walk::walk_ordered;
+use super::{ Writer, Error };
+
+/// Writes `atoms` as a SMILES string, returning it along with the output
+/// span of each atom in `highlights`, in the order given. Spans cover the
+/// full atom, including brackets. Callers can use the spans to
+/// post-process highlighted atoms.
+/// 
+/// ```
+/// use purr::graph::Builder;
+/// use purr::read::read;
+/// use purr::write::{ highlight, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     let mut builder = Builder::new();
+/// 
+///     read("CC[OH]", &mut builder, None)?;
+/// 
+///     let (smiles, spans) = highlight(builder.build()?, &[ 2 ])?;
+/// 
+///     assert_eq!(smiles, "CC[OH]");
+///     assert_eq!(spans, vec![ 2..6 ]);
+/// 
+///     Ok(())
+/// }
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics given a highlight outside of `atoms`.
+pub fn highlight(
+    atoms: Vec<Atom>, highlights: &[usize]
+) -> Result<(String, Vec<Range<usize>>), Error> {
+    let size = atoms.len();
+    let mut writer = Writer::new();
+    let order = walk_ordered(atoms, &mut writer)?;
+    let smiles = writer.write();
+    let mut trace = Trace::new();
+    let mut positions = vec![ 0; size ];
+
+    read(&smiles, &mut Writer::new(), Some(&mut trace))?;
+
+    for (position, id) in order.into_iter().enumerate() {
+        positions[id] = position
+    }
+
+    let spans = highlights.iter().map(|id| {
+        trace.atom(positions[*id]).expect("span")
+    }).collect();
+
+    Ok((smiles, spans))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::test_support::build;
+    use super::*;
+
+    #[test]
+    fn none() {
+        assert_eq!(
+            highlight(build("CCO"), &[ ]),
+            Ok(("CCO".to_string(), vec![ ]))
+        )
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(
+            highlight(build("CCO"), &[ 2, 0 ]),
+            Ok(("CCO".to_string(), vec![ 2..3, 0..1 ]))
+        )
+    }
+
+    #[test]
+    fn two_letter_symbols() {
+        assert_eq!(
+            highlight(build("ClCBr"), &[ 0, 1, 2 ]),
+            Ok(("ClCBr".to_string(), vec![ 0..2, 2..3, 3..5 ]))
+        )
+    }
+
+    #[test]
+    fn branch() {
+        assert_eq!(
+            highlight(build("CC(=O)N"), &[ 2, 3 ]),
+            Ok(("CC(=O)N".to_string(), vec![ 4..5, 6..7 ]))
+        )
+    }
+
+    #[test]
+    fn reordered_output() {
+        let (smiles, spans) = highlight(build("C(.N)O"), &[ 1 ]).unwrap();
+
+        assert_eq!(smiles, "CO.N");
+        assert_eq!(&smiles[spans[0].clone()], "N")
+    }
+
+    #[test]
+    fn ring() {
+        let (smiles, spans) = highlight(
+            build("c1ccccc1[N+](=O)[O-]"), &[ 6, 8 ]
+        ).unwrap();
+
+        assert_eq!(&smiles[spans[0].clone()], "[N+]");
+        assert_eq!(&smiles[spans[1].clone()], "[O-]")
+    }
+}
\ No newline at end of file
@@ -1,3 +1,13 @@
 mod writer;
+mod error;
+mod roundtrip;
+mod canonical;
+mod highlight;
+mod format;
 
-pub use writer::Writer;
\ No newline at end of file
+pub use writer::Writer;
+pub use error::Error;
+pub use roundtrip::roundtrip;
+pub use canonical::canonical;
+pub use highlight::highlight;
+pub use format::format;
\ No newline at end of file
@@ -0,0 +1,90 @@
+use crate::read::read;
+use crate::graph::Builder;
+use crate::walk::walk;
+use super::{ Writer, Error };
+
+/// Reads `smiles` into a graph, then walks the graph to write it again.
+/// Applying `roundtrip` to its own output returns the same string, which
+/// makes it a useful primitive for fuzzing.
+/// 
+/// ```
+/// use purr::write::{ roundtrip, Error };
+/// 
+/// fn main() -> Result<(), Error> {
+///     assert_eq!(roundtrip("C(C)O")?, "C(C)O");
+///     assert_eq!(roundtrip("C(.C)O")?, "CO.C");
+/// 
+///     Ok(())
+/// }
+/// ```
+pub fn roundtrip(smiles: &str) -> Result<String, Error> {
+    let mut builder = Builder::with_capacity(smiles.len() / 2);
+    let mut writer = Writer::new();
+
+    read(smiles, &mut builder, None)?;
+    walk(builder.build()?, &mut writer)?;
+
+    Ok(writer.write())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use crate::{ read, graph };
+    use super::*;
+
+    #[test]
+    fn read_error() {
+        assert_eq!(
+            roundtrip("C(C"),
+            Err(Error::Read(read::Error::EndOfLine))
+        )
+    }
+
+    #[test]
+    fn graph_error() {
+        assert_eq!(roundtrip("C1CC"), Err(Error::Graph(graph::Error::Rnum(0))))
+    }
+
+    #[test]
+    fn idempotent() {
+        let corpus = vec![
+            "*",
+            "C",
+            "CCO",
+            "CC(=O)N",
+            "C(F)(Cl)Br",
+            "C1CC1",
+            "C1CCCCC1",
+            "c1ccccc1",
+            "c1c([37Cl])cccc1",
+            "c1ccc2ccccc2c1",
+            "C1CC2CCC1CC2",
+            "F/C=C/F",
+            "F/C=C\\F",
+            "N[C@@H](C)C(=O)O",
+            "N[C@](C)(F)C(=O)O",
+            "[Na+].[Cl-]",
+            "C(.N)O",
+            "CC(C)(C)C#N",
+            "O=C=O",
+            "[Re]$[Re]",
+            "C%10CCCCC%10",
+            "c1cc[nH]c1",
+            "b1ccccc1",
+            "[Cs+].[Cl-]",
+            "c1ccccc1-c1ccccc1",
+            "C/1=C/CCCC1",
+            "C1CCCC/C=C/1",
+            "F/C=C/1CCCC1",
+            "c-1ccccc1",
+            "C1CC1.C1CC1"
+        ];
+
+        for smiles in corpus {
+            let once = roundtrip(smiles).unwrap();
+
+            assert_eq!(roundtrip(&once).unwrap(), once, "input: {}", smiles)
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,15 @@
-use crate::feature::{ AtomKind, BondKind, Rnum };
+use std::fmt::Write;
+
+use crate::feature::{ AtomKind, BondKind, BracketSymbol, Rnum };
 use crate::walk::Follower;
 
 /// A `Follower` that builds a string SMILEs representation.
 /// 
+/// Single bonds reached by `extend` are elided when at least one of their
+/// atoms is aliphatic, where an elided bond reads back as single. They
+/// stay explicit between two aromatic atoms, as in biphenyl, and at stars,
+/// whose aromaticity is unknown. Ring closure bonds are written as given.
+/// 
 /// ```
 /// use purr::walk::Follower;
 /// use purr::write::Writer;
@@ -19,13 +26,15 @@ use crate::walk::Follower;
 /// ```
 #[derive(Debug,PartialEq)]
 pub struct Writer {
-    stack: Vec<String>
+    stack: Vec<String>,
+    aromatic: Vec<Option<bool>>
 }
 
 impl Writer {
     pub fn new() -> Self {
         Writer {
-            stack: Vec::new()
+            stack: Vec::new(),
+            aromatic: Vec::new()
         }
     }
 
@@ -34,23 +43,46 @@ impl Writer {
     }
 }
 
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Follower for Writer {
     fn root(&mut self, root: AtomKind) {
-        if self.stack.is_empty() {
-            self.stack.push(root.to_string())
-        } else {
-            self.stack.push(".".to_string() + &root.to_string())
+        self.aromatic.push(aromaticity(&root));
+
+        let mut entry = String::new();
+
+        if !self.stack.is_empty() {
+            entry.push('.')
         }
+
+        write!(entry, "{}", root).expect("write");
+        self.stack.push(entry)
     }
 
     fn extend(&mut self, bond_kind: BondKind, atom_kind: AtomKind) {
-        self.stack.push(bond_kind.to_string() + &atom_kind.to_string())
+        let head = self.aromatic.last().cloned().flatten();
+        let aromatic = aromaticity(&atom_kind);
+        let bond_kind = match (bond_kind, head, aromatic) {
+            (BondKind::Single, Some(false), Some(_)) |
+            (BondKind::Single, Some(_), Some(false)) => BondKind::Elided,
+            (bond_kind, _, _) => bond_kind
+        };
+
+        let mut entry = String::new();
+
+        write!(entry, "{}{}", bond_kind, atom_kind).expect("write");
+        self.aromatic.push(aromatic);
+        self.stack.push(entry)
     }
 
     fn join(&mut self, bond_kind: BondKind, rnum: Rnum) {
         let last = self.stack.last_mut().expect("last");
 
-        last.push_str(&(bond_kind.to_string() + &rnum.to_string()))
+        write!(last, "{}{}", bond_kind, rnum).expect("write")
     }
 
     fn pop(&mut self, depth: usize) {
@@ -59,16 +91,36 @@ impl Follower for Writer {
         }
 
         let chain = self.stack.split_off(self.stack.len() - depth);
+
+        self.aromatic.truncate(self.stack.len());
+
         let last = self.stack.last_mut().expect("last");
 
-        last.push_str(&("(".to_string() + &chain.join("") + ")"))
+        last.push('(');
+
+        for entry in chain {
+            last.push_str(&entry)
+        }
+
+        last.push(')')
+    }
+}
+
+fn aromaticity(kind: &AtomKind) -> Option<bool> {
+    match kind {
+        AtomKind::Star => None,
+        AtomKind::Bracket { symbol: BracketSymbol::Star, .. } => None,
+        _ => Some(kind.is_aromatic())
     }
 }
 
 #[cfg(test)]
 mod write {
     use pretty_assertions::assert_eq;
-    use crate::feature::Aliphatic;
+    use std::convert::TryInto;
+    use crate::feature::{
+        Aliphatic, Aromatic, Charge, Element, VirtualHydrogen
+    };
     use super::*;
 
     #[test]
@@ -164,4 +216,138 @@ mod write {
 
         assert_eq!(writer.write(), "*(*(-*)*)=*")
     }
+
+    #[test]
+    fn aliphatic_single_aliphatic() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Aliphatic(Aliphatic::C));
+        writer.extend(BondKind::Single, AtomKind::Aliphatic(Aliphatic::C));
+
+        assert_eq!(writer.write(), "CC")
+    }
+
+    #[test]
+    fn aromatic_single_aliphatic() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Aromatic(Aromatic::C));
+        writer.extend(BondKind::Single, AtomKind::Aliphatic(Aliphatic::C));
+
+        assert_eq!(writer.write(), "cC")
+    }
+
+    #[test]
+    fn aromatic_single_aromatic() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Aromatic(Aromatic::C));
+        writer.extend(BondKind::Single, AtomKind::Aromatic(Aromatic::C));
+
+        assert_eq!(writer.write(), "c-c")
+    }
+
+    #[test]
+    fn aromatic_single_aromatic_after_branch() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Aromatic(Aromatic::C));
+        writer.extend(BondKind::Single, AtomKind::Aliphatic(Aliphatic::C));
+        writer.pop(1);
+        writer.extend(BondKind::Single, AtomKind::Aromatic(Aromatic::C));
+
+        assert_eq!(writer.write(), "c(C)-c")
+    }
+
+    #[test]
+    fn read_explicit_single_between_aliphatics() {
+        let mut writer = Writer::new();
+
+        crate::read::read("C-C-O", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "CCO")
+    }
+
+    #[test]
+    fn read_explicit_single_to_aromatic() {
+        let mut writer = Writer::new();
+
+        crate::read::read("c1ccccc1-C", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "c1ccccc1C")
+    }
+
+    #[test]
+    fn read_biphenyl() {
+        let mut writer = Writer::new();
+
+        crate::read::read("c1ccccc1-c1ccccc1", &mut writer, None).unwrap();
+
+        assert_eq!(writer.write(), "c1ccccc1-c1ccccc1")
+    }
+
+    #[test]
+    fn isotope_and_map() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Bracket {
+            isotope: Some(13u16.try_into().unwrap()),
+            symbol: BracketSymbol::Element(Element::C),
+            configuration: None,
+            hcount: Some(VirtualHydrogen::H3),
+            charge: Some(Charge::MinusTwo),
+            map: Some(999u16.try_into().unwrap())
+        });
+        writer.join(BondKind::Elided, Rnum::R42);
+
+        assert_eq!(writer.write(), "[13CH3-2:999]%42")
+    }
+
+    #[test]
+    fn cesium() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::Cs),
+            configuration: None,
+            hcount: None,
+            charge: Some(Charge::One),
+            map: None
+        });
+
+        assert_eq!(writer.write(), "[Cs+]")
+    }
+
+    #[test]
+    fn map_zero() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Bracket {
+            isotope: None,
+            symbol: BracketSymbol::Element(Element::C),
+            configuration: None,
+            hcount: None,
+            charge: None,
+            map: Some(0u16.try_into().unwrap())
+        });
+
+        assert_eq!(writer.write(), "[C:0]")
+    }
+
+    #[test]
+    fn chain_10k() {
+        let mut writer = Writer::new();
+
+        writer.root(AtomKind::Aliphatic(Aliphatic::C));
+
+        for _ in 1..10_000 {
+            writer.extend(BondKind::Elided, AtomKind::Aliphatic(Aliphatic::C))
+        }
+
+        let smiles = writer.write();
+
+        assert_eq!(smiles.len(), 10_000);
+        assert!(smiles.chars().all(|character| character == 'C'))
+    }
 }
\ No newline at end of file